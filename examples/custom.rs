@@ -1,4 +1,5 @@
 use proteus::action::Action;
+use proteus::context::Context;
 use proteus::parser::Error;
 use proteus::{actions, Parser, TransformBuilder};
 use serde::{Deserialize, Serialize};
@@ -40,8 +41,10 @@ impl Action for CustomAction {
         &self,
         _source: &'a Value,
         _destination: &mut Value,
+        ctx: &Context,
     ) -> Result<Option<Cow<'a, Value>>, proteus::Error> {
-        match self.action.apply(_source, _destination) {
+        ctx.evaluate()?;
+        match self.action.apply(_source, _destination, ctx) {
             Ok(v) => match v {
                 None => Ok(None),
                 Some(v) => match v.deref() {
@@ -56,6 +59,10 @@ impl Action for CustomAction {
             Err(e) => Err(e),
         }
     }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        vec![self.action.as_ref()]
+    }
 }
 
 fn parse_custom(val: &str) -> Result<Box<dyn Action>, Error> {
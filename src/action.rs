@@ -1,16 +1,39 @@
 //! Action trait and definitions.
 
+use crate::context::Context;
 use crate::errors::Error;
 use serde_json::Value;
 use std::borrow::Cow;
 use std::fmt::Debug;
 
 /// An action represents an operation to be carried out on a serde_json::Value object.
+///
+/// The [Context](../context/struct.Context.html) contains the state of the current application of
+/// the transformation and MUST be passed along when applying any child actions.
 #[typetag::serde(tag = "type")]
 pub trait Action: Send + Sync + Debug {
     fn apply<'a>(
         &'a self,
         source: &'a Value,
         destination: &mut Value,
+        ctx: &Context,
     ) -> Result<Option<Cow<'a, Value>>, Error>;
+
+    /// returns the child actions, used to introspect a transformation eg. for its
+    /// [Lineage](../lineage/struct.Lineage.html).
+    ///
+    /// Actions which contain child actions SHOULD return them.
+    fn children(&self) -> Vec<&dyn Action> {
+        Vec::new()
+    }
+
+    /// returns the source paths read directly by the action, eg. by a Getter.
+    fn source_paths(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// returns the destination paths written by the action, eg. by a Setter.
+    fn destination_paths(&self) -> Vec<String> {
+        Vec::new()
+    }
 }
@@ -0,0 +1,60 @@
+use crate::action::Action;
+use crate::context::Context;
+use crate::errors::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+
+/// This represents the affix predicate type
+#[derive(Debug, Serialize, Deserialize)]
+pub enum Type {
+    StartsWith,
+    EndsWith,
+}
+
+/// This type represents an [Action](../action/trait.Action.html) which returns if a string starts
+/// or ends with the affix, eg. for deriving flags without a regular expression.
+///
+/// `false` is returned when either Value is not a string.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Affix {
+    r#type: Type,
+    action: Box<dyn Action>,
+    affix: Box<dyn Action>,
+}
+
+impl Affix {
+    pub fn new(r#type: Type, action: Box<dyn Action>, affix: Box<dyn Action>) -> Self {
+        Self {
+            r#type,
+            action,
+            affix,
+        }
+    }
+}
+
+#[typetag::serde]
+impl Action for Affix {
+    fn apply<'a>(
+        &'a self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        let value = self.action.apply(source, destination, ctx)?;
+        let affix = self.affix.apply(source, destination, ctx)?;
+        let result = match (value.as_deref(), affix.as_deref()) {
+            (Some(Value::String(s)), Some(Value::String(affix))) => match self.r#type {
+                Type::StartsWith => s.starts_with(affix.as_str()),
+                Type::EndsWith => s.ends_with(affix.as_str()),
+            },
+            _ => false,
+        };
+        Ok(Some(Cow::Owned(Value::Bool(result))))
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        vec![self.action.as_ref(), self.affix.as_ref()]
+    }
+}
@@ -0,0 +1,80 @@
+use crate::action::Action;
+use crate::actions::number;
+use crate::context::Context;
+use crate::errors::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::{Number, Value};
+use std::borrow::Cow;
+use std::cmp::Ordering;
+
+/// This represents the aggregation operation type
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum Type {
+    Min,
+    Max,
+    Avg,
+}
+
+/// This type represents an [Action](../action/trait.Action.html) which returns the minimum,
+/// maximum or average of one or more Value's as a Value::Number.
+///
+/// As with [Sum](struct.Sum.html) the numbers within Arrays are included and all other values
+/// ignored. No value is returned when there are no numbers or the average cannot be represented,
+/// eg. when the sum overflows.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Aggregate {
+    r#type: Type,
+    values: Vec<Box<dyn Action>>,
+}
+
+impl Aggregate {
+    pub fn new(r#type: Type, values: Vec<Box<dyn Action>>) -> Self {
+        Self { r#type, values }
+    }
+}
+
+#[typetag::serde]
+impl Action for Aggregate {
+    fn apply<'a>(
+        &self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        let mut result: Option<Number> = None;
+        let mut count = 0_u64;
+
+        for v in self.values.iter() {
+            if let Some(v) = v.apply(source, destination, ctx)? {
+                for num in number::numbers(&v) {
+                    count += 1;
+                    result = match (result, self.r#type) {
+                        (None, _) => Some(num.clone()),
+                        (Some(r), Type::Avg) => match number::add(&r, num) {
+                            Some(n) => Some(n),
+                            None => return Ok(None),
+                        },
+                        (Some(r), Type::Min) => match number::cmp(num, &r) {
+                            Some(Ordering::Less) => Some(num.clone()),
+                            _ => Some(r),
+                        },
+                        (Some(r), Type::Max) => match number::cmp(num, &r) {
+                            Some(Ordering::Greater) => Some(num.clone()),
+                            _ => Some(r),
+                        },
+                    };
+                }
+            }
+        }
+        let result = match (result, self.r#type) {
+            (Some(sum), Type::Avg) => number::div(&sum, &Number::from(count)),
+            (result, _) => result,
+        };
+        Ok(result.map(|n| Cow::Owned(Value::Number(n))))
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        self.values.iter().map(|a| a.as_ref()).collect()
+    }
+}
@@ -0,0 +1,78 @@
+use crate::action::Action;
+use crate::actions::number;
+use crate::context::Context;
+use crate::errors::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::{Number, Value};
+use std::borrow::Cow;
+
+/// This represents the arithmetic operation type
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum Type {
+    Sub,
+    Mul,
+    Div,
+    Mod,
+}
+
+impl Type {
+    fn apply(self, a: &Number, b: &Number) -> Option<Number> {
+        match self {
+            Type::Sub => number::sub(a, b),
+            Type::Mul => number::mul(a, b),
+            Type::Div => number::div(a, b),
+            Type::Mod => number::rem(a, b),
+        }
+    }
+}
+
+/// This type represents an [Action](../action/trait.Action.html) which subtracts, multiplies,
+/// divides or takes the modulus of two or more Value's, in order from left to right, and returns
+/// a Value::Number.
+///
+/// As with [Sum](struct.Sum.html) the numbers within Arrays are included and all other values
+/// ignored. No value is returned when there are no numbers or the result cannot be represented,
+/// eg. when dividing by zero.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Arithmetic {
+    r#type: Type,
+    values: Vec<Box<dyn Action>>,
+}
+
+impl Arithmetic {
+    pub fn new(r#type: Type, values: Vec<Box<dyn Action>>) -> Self {
+        Self { r#type, values }
+    }
+}
+
+#[typetag::serde]
+impl Action for Arithmetic {
+    fn apply<'a>(
+        &self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        let mut result = None;
+
+        for v in self.values.iter() {
+            if let Some(v) = v.apply(source, destination, ctx)? {
+                for num in number::numbers(&v) {
+                    result = match result {
+                        Some(result) => match self.r#type.apply(&result, num) {
+                            Some(n) => Some(n),
+                            None => return Ok(None),
+                        },
+                        None => Some(num.clone()),
+                    };
+                }
+            }
+        }
+        Ok(result.map(|n| Cow::Owned(Value::Number(n))))
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        self.values.iter().map(|a| a.as_ref()).collect()
+    }
+}
@@ -0,0 +1,57 @@
+use crate::action::Action;
+use crate::context::Context;
+use crate::errors::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+use std::ops::Deref;
+
+/// This represents the case change operation type
+#[derive(Debug, Serialize, Deserialize)]
+pub enum Type {
+    Upper,
+    Lower,
+}
+
+/// This type represents an [Action](../action/trait.Action.html) which converts a string to upper
+/// or lower case.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChangeCase {
+    r#type: Type,
+    action: Box<dyn Action>,
+}
+
+impl ChangeCase {
+    pub fn new(r#type: Type, action: Box<dyn Action>) -> Self {
+        Self { r#type, action }
+    }
+}
+
+#[typetag::serde]
+impl Action for ChangeCase {
+    fn apply<'a>(
+        &'a self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        match self.action.apply(source, destination, ctx)? {
+            Some(v) => match v.deref() {
+                Value::String(s) => {
+                    let s = match self.r#type {
+                        Type::Upper => s.to_uppercase(),
+                        Type::Lower => s.to_lowercase(),
+                    };
+                    Ok(Some(Cow::Owned(Value::String(s))))
+                }
+                _ => Ok(None),
+            },
+            None => Ok(None),
+        }
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        vec![self.action.as_ref()]
+    }
+}
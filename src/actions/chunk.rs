@@ -0,0 +1,50 @@
+use crate::action::Action;
+use crate::context::Context;
+use crate::errors::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+
+/// This type represents an [Action](../action/trait.Action.html) which splits an Array into an
+/// Array of Arrays of up to size elements each, eg. for preparing batched payloads.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Chunk {
+    size: usize,
+    action: Box<dyn Action>,
+}
+
+impl Chunk {
+    pub fn new(size: usize, action: Box<dyn Action>) -> Self {
+        Self { size, action }
+    }
+}
+
+#[typetag::serde]
+impl Action for Chunk {
+    fn apply<'a>(
+        &'a self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        if self.size == 0 {
+            return Ok(None);
+        }
+        let chunks = match self.action.apply(source, destination, ctx)? {
+            Some(v) => match v.as_ref() {
+                Value::Array(arr) => arr
+                    .chunks(self.size)
+                    .map(|chunk| Value::Array(chunk.to_vec()))
+                    .collect(),
+                _ => return Ok(None),
+            },
+            None => return Ok(None),
+        };
+        Ok(Some(Cow::Owned(Value::Array(chunks))))
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        vec![self.action.as_ref()]
+    }
+}
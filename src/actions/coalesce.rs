@@ -0,0 +1,42 @@
+use crate::action::Action;
+use crate::context::Context;
+use crate::errors::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+
+/// This type represents an [Action](../action/trait.Action.html) which returns the first result of
+/// its actions, evaluated in order, that is not missing or `null`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Coalesce {
+    values: Vec<Box<dyn Action>>,
+}
+
+impl Coalesce {
+    pub fn new(values: Vec<Box<dyn Action>>) -> Self {
+        Self { values }
+    }
+}
+
+#[typetag::serde]
+impl Action for Coalesce {
+    fn apply<'a>(
+        &'a self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        for v in self.values.iter() {
+            match ctx.guarded(|| v.apply(source, destination, ctx))? {
+                Some(v) if !v.is_null() => return Ok(Some(v)),
+                _ => continue,
+            }
+        }
+        Ok(None)
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        self.values.iter().map(|a| a.as_ref()).collect()
+    }
+}
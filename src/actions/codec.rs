@@ -0,0 +1,65 @@
+use crate::action::Action;
+use crate::context::Context;
+use crate::errors::Error;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+use std::ops::Deref;
+
+/// This represents the base64 operation type
+#[derive(Debug, Serialize, Deserialize)]
+pub enum Type {
+    Encode,
+    Decode,
+}
+
+/// This type represents an [Action](../action/trait.Action.html) which base64 encodes a string, or
+/// decodes a base64 string.
+///
+/// The standard alphabet with padding is used and decoding returns no value when the input is not
+/// valid base64 or does not decode to valid UTF-8.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Base64 {
+    r#type: Type,
+    action: Box<dyn Action>,
+}
+
+impl Base64 {
+    pub fn new(r#type: Type, action: Box<dyn Action>) -> Self {
+        Self { r#type, action }
+    }
+}
+
+#[typetag::serde]
+impl Action for Base64 {
+    fn apply<'a>(
+        &'a self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        match self.action.apply(source, destination, ctx)? {
+            Some(v) => match v.deref() {
+                Value::String(s) => {
+                    let s = match self.r#type {
+                        Type::Encode => Some(STANDARD.encode(s)),
+                        Type::Decode => STANDARD
+                            .decode(s.trim())
+                            .ok()
+                            .and_then(|b| String::from_utf8(b).ok()),
+                    };
+                    Ok(s.map(|s| Cow::Owned(Value::String(s))))
+                }
+                _ => Ok(None),
+            },
+            None => Ok(None),
+        }
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        vec![self.action.as_ref()]
+    }
+}
@@ -0,0 +1,114 @@
+use crate::action::Action;
+use crate::actions::number;
+use crate::context::Context;
+use crate::errors::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+use std::cmp::Ordering;
+
+/// This represents the comparison operation type
+#[derive(Debug, Serialize, Deserialize)]
+pub enum Type {
+    Eq,
+    Ne,
+    Gt,
+    Gte,
+    Lt,
+    Lte,
+}
+
+/// This type represents an [Action](../action/trait.Action.html) which compares the results of two
+/// actions and returns a boolean.
+///
+/// Numbers are compared by value, strings lexicographically and `false` is less than `true`. Values
+/// of differing types are never ordered and only equal when identical; a missing Value is treated
+/// as `null`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Compare {
+    r#type: Type,
+    left: Box<dyn Action>,
+    right: Box<dyn Action>,
+}
+
+impl Compare {
+    pub fn new(r#type: Type, left: Box<dyn Action>, right: Box<dyn Action>) -> Self {
+        Self {
+            r#type,
+            left,
+            right,
+        }
+    }
+}
+
+#[typetag::serde]
+impl Action for Compare {
+    fn apply<'a>(
+        &'a self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        let left = self.left.apply(source, destination, ctx)?;
+        let right = self.right.apply(source, destination, ctx)?;
+        let ordering = compare(
+            left.as_deref().unwrap_or(&Value::Null),
+            right.as_deref().unwrap_or(&Value::Null),
+        );
+        let result = match self.r#type {
+            Type::Eq => ordering == Some(Ordering::Equal),
+            Type::Ne => ordering != Some(Ordering::Equal),
+            Type::Gt => ordering == Some(Ordering::Greater),
+            Type::Gte => matches!(ordering, Some(Ordering::Greater | Ordering::Equal)),
+            Type::Lt => ordering == Some(Ordering::Less),
+            Type::Lte => matches!(ordering, Some(Ordering::Less | Ordering::Equal)),
+        };
+        Ok(Some(Cow::Owned(Value::Bool(result))))
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        vec![self.left.as_ref(), self.right.as_ref()]
+    }
+}
+
+/// compares two Values of any type, ordering differing types as null < bool < number < string
+/// < array < object. Arrays are ordered by their elements and Objects are considered equal.
+pub(crate) fn total_cmp(a: &Value, b: &Value) -> Ordering {
+    match (a, b) {
+        (Value::Array(a), Value::Array(b)) => a
+            .iter()
+            .zip(b.iter())
+            .map(|(a, b)| total_cmp(a, b))
+            .find(|o| *o != Ordering::Equal)
+            .unwrap_or_else(|| a.len().cmp(&b.len())),
+        (Value::Object(_), Value::Object(_)) => Ordering::Equal,
+        _ => match rank(a).cmp(&rank(b)) {
+            Ordering::Equal => compare(a, b).unwrap_or(Ordering::Equal),
+            o => o,
+        },
+    }
+}
+
+#[inline]
+fn rank(value: &Value) -> u8 {
+    match value {
+        Value::Null => 0,
+        Value::Bool(_) => 1,
+        Value::Number(_) => 2,
+        Value::String(_) => 3,
+        Value::Array(_) => 4,
+        Value::Object(_) => 5,
+    }
+}
+
+/// compares two Values, returning None when they cannot be ordered.
+pub(crate) fn compare(a: &Value, b: &Value) -> Option<Ordering> {
+    match (a, b) {
+        (Value::Number(a), Value::Number(b)) => number::cmp(a, b),
+        (Value::String(a), Value::String(b)) => Some(a.cmp(b)),
+        (Value::Bool(a), Value::Bool(b)) => Some(a.cmp(b)),
+        _ if a == b => Some(Ordering::Equal),
+        _ => None,
+    }
+}
@@ -0,0 +1,48 @@
+use crate::action::Action;
+use crate::context::Context;
+use crate::errors::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+
+/// This type represents an [Action](../action/trait.Action.html) which combines the results of its
+/// actions into a single Array, eg. to feed into `unique()` or `sort()`.
+///
+/// The elements of Arrays are appended in order, any other Value is appended as is and missing
+/// Values are skipped.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Concat {
+    values: Vec<Box<dyn Action>>,
+}
+
+impl Concat {
+    pub fn new(values: Vec<Box<dyn Action>>) -> Self {
+        Self { values }
+    }
+}
+
+#[typetag::serde]
+impl Action for Concat {
+    fn apply<'a>(
+        &'a self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        let mut combined = Vec::new();
+        for v in self.values.iter() {
+            match v.apply(source, destination, ctx)? {
+                Some(Cow::Owned(Value::Array(arr))) => combined.extend(arr),
+                Some(Cow::Borrowed(Value::Array(arr))) => combined.extend(arr.iter().cloned()),
+                Some(v) => combined.push(v.into_owned()),
+                None => continue,
+            }
+        }
+        Ok(Some(Cow::Owned(Value::Array(combined))))
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        self.values.iter().map(|a| a.as_ref()).collect()
+    }
+}
@@ -1,4 +1,5 @@
 use crate::action::Action;
+use crate::context::Context;
 use crate::errors::Error;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -23,7 +24,9 @@ impl Action for Constant {
         &'a self,
         _source: &'a Value,
         _destination: &mut Value,
+        ctx: &Context,
     ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
         Ok(Some(Cow::Borrowed(&self.value)))
     }
 }
@@ -0,0 +1,78 @@
+use crate::action::Action;
+use crate::context::Context;
+use crate::errors::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::{Number, Value};
+use std::borrow::Cow;
+use std::ops::Deref;
+
+/// This represents the type to convert to
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum Type {
+    Number,
+    String,
+    Bool,
+    Json,
+}
+
+/// This type represents an [Action](../action/trait.Action.html) which converts a Value to another
+/// type, returning no value when it cannot be converted.
+///
+/// - `Number` parses strings containing a JSON number, eg. `"42"` or `"3.14"`, ignoring any
+///   surrounding whitespace.
+/// - `String` converts numbers and booleans to their string form.
+/// - `Bool` converts `"true"`, `"false"`, `1` and `0`, as either strings or numbers, to booleans.
+/// - `Json` serializes any Value to a compact JSON string.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Convert {
+    r#type: Type,
+    action: Box<dyn Action>,
+}
+
+impl Convert {
+    pub fn new(r#type: Type, action: Box<dyn Action>) -> Self {
+        Self { r#type, action }
+    }
+}
+
+#[typetag::serde]
+impl Action for Convert {
+    fn apply<'a>(
+        &'a self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        let v = match self.action.apply(source, destination, ctx)? {
+            Some(v) => v,
+            None => return Ok(None),
+        };
+        let converted = match (self.r#type, v.deref()) {
+            (Type::Number, Value::Number(_)) => return Ok(Some(v)),
+            (Type::Number, Value::String(s)) => serde_json::from_str::<Number>(s.trim())
+                .ok()
+                .map(Value::Number),
+            (Type::String, Value::String(_)) | (Type::Bool, Value::Bool(_)) => return Ok(Some(v)),
+            (Type::String, Value::Number(n)) => Some(Value::String(n.to_string())),
+            (Type::String, Value::Bool(b)) => Some(Value::String(b.to_string())),
+            (Type::Bool, Value::String(s)) => match s.trim() {
+                "true" | "1" => Some(Value::Bool(true)),
+                "false" | "0" => Some(Value::Bool(false)),
+                _ => None,
+            },
+            (Type::Bool, Value::Number(n)) => match n.as_u64() {
+                Some(1) => Some(Value::Bool(true)),
+                Some(0) => Some(Value::Bool(false)),
+                _ => None,
+            },
+            (Type::Json, v) => Some(Value::String(v.to_string())),
+            _ => None,
+        };
+        Ok(converted.map(Cow::Owned))
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        vec![self.action.as_ref()]
+    }
+}
@@ -0,0 +1,214 @@
+use crate::action::Action;
+use crate::context::Context;
+use crate::errors::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::borrow::Cow;
+use std::ops::Deref;
+
+/// This represents the identifier naming convention to convert to
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum Type {
+    Snake,
+    Camel,
+    Kebab,
+    Pascal,
+}
+
+impl Type {
+    /// converts the identifier to this naming convention, splitting words on any non-alphanumeric
+    /// characters and changes of case eg. `userID`, `user_id` and `UserId` are all the words
+    /// `user` and `id`.
+    pub fn convert(self, s: &str) -> String {
+        let words = words(s);
+        match self {
+            Type::Snake => join_lower(&words, "_"),
+            Type::Kebab => join_lower(&words, "-"),
+            Type::Camel => {
+                let mut out = String::with_capacity(s.len());
+                for (i, w) in words.iter().enumerate() {
+                    if i == 0 {
+                        out.push_str(&w.to_lowercase());
+                    } else {
+                        push_capitalized(&mut out, w);
+                    }
+                }
+                out
+            }
+            Type::Pascal => {
+                let mut out = String::with_capacity(s.len());
+                for w in &words {
+                    push_capitalized(&mut out, w);
+                }
+                out
+            }
+        }
+    }
+}
+
+fn join_lower(words: &[&str], sep: &str) -> String {
+    words
+        .iter()
+        .map(|w| w.to_lowercase())
+        .collect::<Vec<_>>()
+        .join(sep)
+}
+
+fn push_capitalized(out: &mut String, word: &str) {
+    let mut chars = word.chars();
+    if let Some(first) = chars.next() {
+        out.extend(first.to_uppercase());
+        out.push_str(&chars.as_str().to_lowercase());
+    }
+}
+
+fn words(s: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+    for part in s.split(|c: char| !c.is_alphanumeric()) {
+        let chars: Vec<(usize, char)> = part.char_indices().collect();
+        let mut start = 0;
+        for i in 1..chars.len() {
+            let (idx, c) = chars[i];
+            let prev = chars[i - 1].1;
+            let next_lower = chars.get(i + 1).is_some_and(|(_, n)| n.is_lowercase());
+            // split before `aB`, `1B` and the last upper case char of an acronym followed by lower
+            // case eg. `HTTPServer` becomes `HTTP` and `Server`.
+            if c.is_uppercase()
+                && (prev.is_lowercase() || prev.is_numeric() || (prev.is_uppercase() && next_lower))
+            {
+                words.push(&part[start..idx]);
+                start = idx;
+            }
+        }
+        if start < part.len() {
+            words.push(&part[start..]);
+        }
+    }
+    words
+}
+
+/// This type represents an [Action](../action/trait.Action.html) which converts a string
+/// identifier between naming conventions eg. `user_id` to `userId`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ConvertCase {
+    r#type: Type,
+    action: Box<dyn Action>,
+}
+
+impl ConvertCase {
+    pub fn new(r#type: Type, action: Box<dyn Action>) -> Self {
+        Self { r#type, action }
+    }
+}
+
+#[typetag::serde]
+impl Action for ConvertCase {
+    fn apply<'a>(
+        &'a self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        match self.action.apply(source, destination, ctx)? {
+            Some(v) => match v.deref() {
+                Value::String(s) => Ok(Some(Cow::Owned(Value::String(self.r#type.convert(s))))),
+                _ => Ok(None),
+            },
+            None => Ok(None),
+        }
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        vec![self.action.as_ref()]
+    }
+}
+
+/// This type represents an [Action](../action/trait.Action.html) which converts the keys of an
+/// Object, and any Objects nested within it, between naming conventions.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ConvertKeys {
+    r#type: Type,
+    action: Box<dyn Action>,
+}
+
+impl ConvertKeys {
+    pub fn new(r#type: Type, action: Box<dyn Action>) -> Self {
+        Self { r#type, action }
+    }
+
+    fn convert(&self, value: &Value) -> Value {
+        match value {
+            Value::Object(o) => Value::Object(
+                o.iter()
+                    .map(|(k, v)| (self.r#type.convert(k), self.convert(v)))
+                    .collect::<Map<_, _>>(),
+            ),
+            Value::Array(arr) => Value::Array(arr.iter().map(|v| self.convert(v)).collect()),
+            _ => value.clone(),
+        }
+    }
+}
+
+#[typetag::serde]
+impl Action for ConvertKeys {
+    fn apply<'a>(
+        &'a self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        match self.action.apply(source, destination, ctx)? {
+            Some(v) => match v.deref() {
+                Value::Object(_) => Ok(Some(Cow::Owned(self.convert(&v)))),
+                _ => Ok(None),
+            },
+            None => Ok(None),
+        }
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        vec![self.action.as_ref()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversions() {
+        for (input, snake, camel, kebab, pascal) in &[
+            ("user_id", "user_id", "userId", "user-id", "UserId"),
+            ("userID", "user_id", "userId", "user-id", "UserId"),
+            (
+                "HTTPServer",
+                "http_server",
+                "httpServer",
+                "http-server",
+                "HttpServer",
+            ),
+            (
+                "first-name 2",
+                "first_name_2",
+                "firstName2",
+                "first-name-2",
+                "FirstName2",
+            ),
+            (
+                "address2Line",
+                "address2_line",
+                "address2Line",
+                "address2-line",
+                "Address2Line",
+            ),
+            ("__a__b", "a_b", "aB", "a-b", "AB"),
+        ] {
+            assert_eq!(Type::Snake.convert(input), *snake);
+            assert_eq!(Type::Camel.convert(input), *camel);
+            assert_eq!(Type::Kebab.convert(input), *kebab);
+            assert_eq!(Type::Pascal.convert(input), *pascal);
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use crate::action::Action;
+use crate::actions::datetime;
+use crate::context::Context;
+use crate::errors::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+use std::ops::Deref;
+
+/// This type represents an [Action](../action/trait.Action.html) which shifts an RFC 3339
+/// timestamp by a duration, which may be negative, returning the result as an RFC 3339 UTC string.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DateAdd {
+    millis: i64,
+    action: Box<dyn Action>,
+}
+
+impl DateAdd {
+    pub fn new(millis: i64, action: Box<dyn Action>) -> Self {
+        Self { millis, action }
+    }
+}
+
+#[typetag::serde]
+impl Action for DateAdd {
+    fn apply<'a>(
+        &'a self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        match self.action.apply(source, destination, ctx)? {
+            Some(v) => match v.deref() {
+                Value::String(s) => Ok(datetime::parse_rfc3339(s)
+                    .and_then(|ts| ts.add_nanos(i128::from(self.millis) * 1_000_000))
+                    .map(|ts| Cow::Owned(Value::String(datetime::format_rfc3339(ts))))),
+                _ => Ok(None),
+            },
+            None => Ok(None),
+        }
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        vec![self.action.as_ref()]
+    }
+}
+
+/// parses a duration, eg. `-7d` or `2h30m`, into milliseconds returning None if invalid.
+///
+/// The supported units are `w`eeks, `d`ays, `h`ours, `m`inutes, `s`econds and `ms`.
+pub fn parse_duration(s: &str) -> Option<i64> {
+    let s = s.trim();
+    let (sign, mut rest) = match s.strip_prefix('-') {
+        Some(rest) => (-1, rest),
+        None => (1, s.strip_prefix('+').unwrap_or(s)),
+    };
+    if rest.is_empty() {
+        return None;
+    }
+    let mut total = 0_i64;
+    while !rest.is_empty() {
+        let len = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let n: i64 = rest[..len].parse().ok()?;
+        rest = &rest[len..];
+        let unit_len = rest
+            .find(|c: char| c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let millis = match &rest[..unit_len] {
+            "w" => 604_800_000,
+            "d" => 86_400_000,
+            "h" => 3_600_000,
+            "m" => 60_000,
+            "s" => 1_000,
+            "ms" => 1,
+            _ => return None,
+        };
+        rest = &rest[unit_len..];
+        total = total.checked_add(n.checked_mul(millis)?)?;
+    }
+    Some(sign * total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn durations() {
+        assert_eq!(Some(-7 * 86_400_000), parse_duration("-7d"));
+        assert_eq!(Some(9_000_000), parse_duration("2h30m"));
+        assert_eq!(Some(604_801_500), parse_duration("+1w1s500ms"));
+        for s in &["", "-", "7", "d", "7x", "1h-30m", "1.5h"] {
+            assert_eq!(None, parse_duration(s), "{}", s);
+        }
+    }
+}
@@ -0,0 +1,271 @@
+//! Date and time helpers shared by time based Actions.
+//!
+//! Timestamps are represented as whole seconds since the Unix epoch plus a nanosecond fraction and
+//! are always formatted in UTC.
+
+use std::convert::TryFrom;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// This type represents a point in time as seconds, and nanoseconds within that second, since the
+/// Unix epoch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct Timestamp {
+    pub secs: i64,
+    pub nanos: u32,
+}
+
+impl Timestamp {
+    pub fn from_millis(ms: i64) -> Self {
+        Self {
+            secs: ms.div_euclid(1000),
+            nanos: (ms.rem_euclid(1000) * 1_000_000) as u32,
+        }
+    }
+
+    pub fn as_millis(&self) -> i64 {
+        self.secs * 1000 + i64::from(self.nanos / 1_000_000)
+    }
+
+    /// returns the timestamp shifted by the number of nanoseconds, which may be negative.
+    pub fn add_nanos(&self, nanos: i128) -> Option<Self> {
+        let total = i128::from(self.secs) * 1_000_000_000 + i128::from(self.nanos) + nanos;
+        Some(Self {
+            secs: i64::try_from(total.div_euclid(1_000_000_000)).ok()?,
+            nanos: total.rem_euclid(1_000_000_000) as u32,
+        })
+    }
+}
+
+impl From<SystemTime> for Timestamp {
+    fn from(time: SystemTime) -> Self {
+        match time.duration_since(UNIX_EPOCH) {
+            Ok(d) => Self {
+                secs: d.as_secs() as i64,
+                nanos: d.subsec_nanos(),
+            },
+            Err(e) => {
+                let d: Duration = e.duration();
+                let ts = Self {
+                    secs: -(d.as_secs() as i64),
+                    nanos: 0,
+                };
+                ts.add_nanos(-i128::from(d.subsec_nanos())).unwrap_or(ts)
+            }
+        }
+    }
+}
+
+/// formats the timestamp as an RFC 3339 UTC string, eg. `2020-01-02T03:04:05Z`, including only as
+/// many fractional second digits, in groups of 3, as are required.
+pub(crate) fn format_rfc3339(ts: Timestamp) -> String {
+    let days = ts.secs.div_euclid(86_400);
+    let secs = ts.secs.rem_euclid(86_400);
+    let (year, month, day) = civil_from_days(days);
+    let mut s = format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+        year,
+        month,
+        day,
+        secs / 3600,
+        secs % 3600 / 60,
+        secs % 60
+    );
+    if ts.nanos != 0 {
+        let fraction = format!("{:09}", ts.nanos);
+        let digits = if ts.nanos.is_multiple_of(1_000_000) {
+            3
+        } else if ts.nanos.is_multiple_of(1_000) {
+            6
+        } else {
+            9
+        };
+        s.push('.');
+        s.push_str(&fraction[..digits]);
+    }
+    s.push('Z');
+    s
+}
+
+/// parses an RFC 3339 string, eg. `2020-01-02T03:04:05.123+02:00`, returning None if invalid.
+pub(crate) fn parse_rfc3339(s: &str) -> Option<Timestamp> {
+    let b = s.as_bytes();
+    if b.len() < 20 || !b[..19].is_ascii() {
+        return None;
+    }
+    if b[4] != b'-' || b[7] != b'-' || b[13] != b':' || b[16] != b':' {
+        return None;
+    }
+    if !matches!(b[10], b'T' | b't' | b' ') {
+        return None;
+    }
+    let year = digits(&s[0..4])?;
+    let month = digits(&s[5..7])?;
+    let day = digits(&s[8..10])?;
+    let hour = digits(&s[11..13])?;
+    let minute = digits(&s[14..16])?;
+    let second = digits(&s[17..19])?;
+    if !(1..=12).contains(&month)
+        || day == 0
+        || day > days_in_month(year, month)
+        || hour > 23
+        || minute > 59
+        || second > 60
+    {
+        return None;
+    }
+
+    let mut rest = &s[19..];
+    let mut nanos = 0_u32;
+    if let Some(fraction) = rest.strip_prefix('.') {
+        let len = fraction
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(fraction.len());
+        if len == 0 {
+            return None;
+        }
+        for (i, c) in fraction[..len].bytes().take(9).enumerate() {
+            nanos += u32::from(c - b'0') * 10_u32.pow(8 - i as u32);
+        }
+        rest = &fraction[len..];
+    }
+    let offset = match rest {
+        "Z" | "z" => 0,
+        _ if rest.len() == 6 && rest.as_bytes()[3] == b':' => {
+            let sign = match rest.as_bytes()[0] {
+                b'+' => 1,
+                b'-' => -1,
+                _ => return None,
+            };
+            let hours = digits(&rest[1..3])?;
+            let minutes = digits(&rest[4..6])?;
+            if hours > 23 || minutes > 59 {
+                return None;
+            }
+            sign * (hours * 3600 + minutes * 60)
+        }
+        _ => return None,
+    };
+    // leap seconds are folded into the following second.
+    let secs =
+        days_from_civil(year, month, day) * 86_400 + hour * 3600 + minute * 60 + second - offset;
+    Some(Timestamp { secs, nanos })
+}
+
+fn digits(s: &str) -> Option<i64> {
+    if s.bytes().all(|b| b.is_ascii_digit()) {
+        s.parse().ok()
+    } else {
+        None
+    }
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// see http://howardhinnant.github.io/date_algorithms.html
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+pub(crate) fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rfc3339() {
+        for (secs, nanos, formatted) in &[
+            (0, 0, "1970-01-01T00:00:00Z"),
+            (1_582_979_445, 500_000_000, "2020-02-29T12:30:45.500Z"),
+            (1_582_979_445, 1_000, "2020-02-29T12:30:45.000001Z"),
+            (1_582_979_445, 1, "2020-02-29T12:30:45.000000001Z"),
+            (-1, 0, "1969-12-31T23:59:59Z"),
+        ] {
+            let ts = Timestamp {
+                secs: *secs,
+                nanos: *nanos,
+            };
+            assert_eq!(*formatted, format_rfc3339(ts));
+        }
+    }
+
+    #[test]
+    fn parse() {
+        for (s, secs, nanos) in &[
+            ("1970-01-01T00:00:00Z", 0, 0),
+            ("2020-02-29T12:30:45.5Z", 1_582_979_445, 500_000_000),
+            ("2020-02-29T14:30:45.000001+02:00", 1_582_979_445, 1_000),
+            (
+                "2020-02-29 10:30:45.1234567891-02:00",
+                1_582_979_445,
+                123_456_789,
+            ),
+            ("1969-12-31T23:59:59z", -1, 0),
+        ] {
+            let expected = Timestamp {
+                secs: *secs,
+                nanos: *nanos,
+            };
+            assert_eq!(Some(expected), parse_rfc3339(s), "{}", s);
+        }
+        for s in &[
+            "2020-02-30T00:00:00Z",
+            "2020-01-01T00:00:00",
+            "2020-01-01T24:00:00Z",
+            "2020-01-01",
+            "2020-01-01T00:00:00.Z",
+            "2020-01-01T00:00:00+2:00",
+            "2020-01-01T00:00:0é",
+        ] {
+            assert_eq!(None, parse_rfc3339(s), "{}", s);
+        }
+    }
+
+    #[test]
+    fn millis() {
+        let ts = Timestamp::from_millis(-1);
+        assert_eq!(
+            Timestamp {
+                secs: -1,
+                nanos: 999_000_000
+            },
+            ts
+        );
+        assert_eq!(-1, ts.as_millis());
+    }
+
+    #[test]
+    fn system_time() {
+        assert_eq!(
+            Timestamp {
+                secs: -1,
+                nanos: 500_000_000
+            },
+            Timestamp::from(UNIX_EPOCH - Duration::from_millis(500))
+        );
+    }
+}
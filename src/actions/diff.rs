@@ -0,0 +1,106 @@
+use crate::action::Action;
+use crate::context::Context;
+use crate::errors::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::borrow::Cow;
+
+/// This type represents an [Action](../action/trait.Action.html) which compares the results of two
+/// Actions and returns the differences between them, keyed by JSON Pointer, eg.
+/// `{"added":{"/tags/1":"b"},"removed":{"/age":30},"changed":{"/name":{"from":"Dean","to":"Joey"}}}`
+///
+/// Objects are compared by key and Arrays by index, a missing Value is treated as `null`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Diff {
+    from: Box<dyn Action>,
+    to: Box<dyn Action>,
+}
+
+impl Diff {
+    pub fn new(from: Box<dyn Action>, to: Box<dyn Action>) -> Self {
+        Self { from, to }
+    }
+}
+
+#[typetag::serde]
+impl Action for Diff {
+    fn apply<'a>(
+        &'a self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        let from = self.from.apply(source, destination, ctx)?;
+        let to = self.to.apply(source, destination, ctx)?;
+        let mut differences = Differences::default();
+        differences.compare(
+            String::new(),
+            from.as_deref().unwrap_or(&Value::Null),
+            to.as_deref().unwrap_or(&Value::Null),
+        );
+
+        let mut result = Map::new();
+        result.insert("added".to_owned(), Value::Object(differences.added));
+        result.insert("removed".to_owned(), Value::Object(differences.removed));
+        result.insert("changed".to_owned(), Value::Object(differences.changed));
+        Ok(Some(Cow::Owned(Value::Object(result))))
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        vec![self.from.as_ref(), self.to.as_ref()]
+    }
+}
+
+#[derive(Default)]
+struct Differences {
+    added: Map<String, Value>,
+    removed: Map<String, Value>,
+    changed: Map<String, Value>,
+}
+
+impl Differences {
+    fn compare(&mut self, pointer: String, from: &Value, to: &Value) {
+        match (from, to) {
+            (Value::Object(f), Value::Object(t)) => {
+                for (k, fv) in f {
+                    let p = format!("{}/{}", pointer, k.replace('~', "~0").replace('/', "~1"));
+                    match t.get(k) {
+                        Some(tv) => self.compare(p, fv, tv),
+                        None => {
+                            self.removed.insert(p, fv.clone());
+                        }
+                    }
+                }
+                for (k, tv) in t {
+                    if !f.contains_key(k) {
+                        let p = format!("{}/{}", pointer, k.replace('~', "~0").replace('/', "~1"));
+                        self.added.insert(p, tv.clone());
+                    }
+                }
+            }
+            (Value::Array(f), Value::Array(t)) => {
+                for (i, fv) in f.iter().enumerate() {
+                    let p = format!("{}/{}", pointer, i);
+                    match t.get(i) {
+                        Some(tv) => self.compare(p, fv, tv),
+                        None => {
+                            self.removed.insert(p, fv.clone());
+                        }
+                    }
+                }
+                for (i, tv) in t.iter().enumerate().skip(f.len()) {
+                    self.added.insert(format!("{}/{}", pointer, i), tv.clone());
+                }
+            }
+            _ => {
+                if from != to {
+                    let mut change = Map::new();
+                    change.insert("from".to_owned(), from.clone());
+                    change.insert("to".to_owned(), to.clone());
+                    self.changed.insert(pointer, Value::Object(change));
+                }
+            }
+        }
+    }
+}
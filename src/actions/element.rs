@@ -0,0 +1,60 @@
+use crate::action::Action;
+use crate::context::Context;
+use crate::errors::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+
+/// This represents which element of an Array to return
+#[derive(Debug, Serialize, Deserialize)]
+pub enum Type {
+    First,
+    Last,
+}
+
+/// This type represents an [Action](../action/trait.Action.html) which returns the first or last
+/// element of an Array, and no Value when the Array is empty.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Element {
+    r#type: Type,
+    action: Box<dyn Action>,
+}
+
+impl Element {
+    pub fn new(r#type: Type, action: Box<dyn Action>) -> Self {
+        Self { r#type, action }
+    }
+}
+
+#[typetag::serde]
+impl Action for Element {
+    fn apply<'a>(
+        &'a self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        match self.action.apply(source, destination, ctx)? {
+            Some(Cow::Owned(Value::Array(arr))) => {
+                let element = match self.r#type {
+                    Type::First => arr.into_iter().next(),
+                    Type::Last => arr.into_iter().next_back(),
+                };
+                Ok(element.map(Cow::Owned))
+            }
+            Some(Cow::Borrowed(Value::Array(arr))) => {
+                let element = match self.r#type {
+                    Type::First => arr.first(),
+                    Type::Last => arr.last(),
+                };
+                Ok(element.map(Cow::Borrowed))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        vec![self.action.as_ref()]
+    }
+}
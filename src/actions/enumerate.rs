@@ -0,0 +1,67 @@
+use crate::action::Action;
+use crate::context::Context;
+use crate::errors::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::borrow::Cow;
+
+/// This type represents an [Action](../action/trait.Action.html) which adds the position to each
+/// element of an Array.
+///
+/// Without a key each element is wrapped as `{"index": 0, "value": element}`, with a key the index
+/// is added to Object elements under the key and other elements are wrapped as
+/// `{"<key>": 0, "value": element}`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Enumerate {
+    key: Option<String>,
+    action: Box<dyn Action>,
+}
+
+impl Enumerate {
+    pub fn new(key: Option<String>, action: Box<dyn Action>) -> Self {
+        Self { key, action }
+    }
+
+    fn enumerate(&self, index: usize, element: Value) -> Value {
+        let index = Value::Number(index.into());
+        match (&self.key, element) {
+            (Some(key), Value::Object(mut o)) => {
+                o.insert(key.clone(), index);
+                Value::Object(o)
+            }
+            (key, element) => {
+                let mut o = Map::new();
+                o.insert(key.clone().unwrap_or_else(|| "index".to_owned()), index);
+                o.insert("value".to_owned(), element);
+                Value::Object(o)
+            }
+        }
+    }
+}
+
+#[typetag::serde]
+impl Action for Enumerate {
+    fn apply<'a>(
+        &'a self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        let arr = match self.action.apply(source, destination, ctx)? {
+            Some(Cow::Owned(Value::Array(arr))) => arr,
+            Some(Cow::Borrowed(Value::Array(arr))) => arr.clone(),
+            _ => return Ok(None),
+        };
+        let enumerated = arr
+            .into_iter()
+            .enumerate()
+            .map(|(i, element)| self.enumerate(i, element))
+            .collect();
+        Ok(Some(Cow::Owned(Value::Array(enumerated))))
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        vec![self.action.as_ref()]
+    }
+}
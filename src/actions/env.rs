@@ -0,0 +1,39 @@
+use crate::action::Action;
+use crate::context::{Capability, Context};
+use crate::errors::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+
+/// This type represents an [Action](../action/trait.Action.html) which returns the value of an
+/// environment variable, or the default when it isn't set, eg. for deployment specific constants.
+///
+/// Requires the [Environment](../context/enum.Capability.html) capability.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Env {
+    name: String,
+    default: Option<Value>,
+}
+
+impl Env {
+    pub const fn new(name: String, default: Option<Value>) -> Self {
+        Self { name, default }
+    }
+}
+
+#[typetag::serde]
+impl Action for Env {
+    fn apply<'a>(
+        &'a self,
+        _source: &'a Value,
+        _destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        ctx.require(Capability::Environment)?;
+        match std::env::var(&self.name) {
+            Ok(v) => Ok(Some(Cow::Owned(Value::String(v)))),
+            Err(_) => Ok(self.default.as_ref().map(Cow::Borrowed)),
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use crate::action::Action;
+use crate::actions::datetime::{self, Timestamp};
+use crate::context::Context;
+use crate::errors::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::{Number, Value};
+use std::borrow::Cow;
+use std::ops::Deref;
+
+/// This represents the direction of the conversion
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum Type {
+    /// converts an RFC 3339 string to a Unix timestamp.
+    To,
+    /// converts a Unix timestamp to an RFC 3339 string.
+    From,
+}
+
+/// This represents the unit of a Unix timestamp
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum Unit {
+    Seconds,
+    Milliseconds,
+}
+
+/// This type represents an [Action](../action/trait.Action.html) which converts between RFC 3339
+/// strings and Unix timestamps in seconds or milliseconds.
+///
+/// Converting to a Unix timestamp truncates any fraction smaller than the unit while fractional
+/// Unix timestamps are supported when converting from one. No value is returned for invalid input.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Epoch {
+    r#type: Type,
+    unit: Unit,
+    action: Box<dyn Action>,
+}
+
+impl Epoch {
+    pub fn new(r#type: Type, unit: Unit, action: Box<dyn Action>) -> Self {
+        Self {
+            r#type,
+            unit,
+            action,
+        }
+    }
+
+    fn timestamp(&self, s: &str) -> Option<Value> {
+        let ts = datetime::parse_rfc3339(s)?;
+        let n = match self.unit {
+            Unit::Seconds => ts.secs,
+            Unit::Milliseconds => ts.secs.checked_mul(1000)? + i64::from(ts.nanos / 1_000_000),
+        };
+        Some(Value::Number(n.into()))
+    }
+
+    fn rfc3339(&self, n: &Number) -> Option<Value> {
+        let ts = match (n.as_i64(), self.unit) {
+            (Some(secs), Unit::Seconds) => Timestamp { secs, nanos: 0 },
+            (Some(ms), Unit::Milliseconds) => Timestamp::from_millis(ms),
+            (None, unit) => {
+                let scale = match unit {
+                    Unit::Seconds => 1e9,
+                    Unit::Milliseconds => 1e6,
+                };
+                let nanos = (n.as_f64()? * scale).round();
+                if !nanos.is_finite() || nanos.abs() >= i128::MAX as f64 {
+                    return None;
+                }
+                Timestamp { secs: 0, nanos: 0 }.add_nanos(nanos as i128)?
+            }
+        };
+        Some(Value::String(datetime::format_rfc3339(ts)))
+    }
+}
+
+#[typetag::serde]
+impl Action for Epoch {
+    fn apply<'a>(
+        &'a self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        let converted = match self.action.apply(source, destination, ctx)? {
+            Some(v) => match (self.r#type, v.deref()) {
+                (Type::To, Value::String(s)) => self.timestamp(s),
+                (Type::From, Value::Number(n)) => self.rfc3339(n),
+                _ => None,
+            },
+            None => None,
+        };
+        Ok(converted.map(Cow::Owned))
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        vec![self.action.as_ref()]
+    }
+}
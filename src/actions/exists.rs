@@ -0,0 +1,39 @@
+use crate::action::Action;
+use crate::context::Context;
+use crate::errors::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+
+/// This type represents an [Action](../action/trait.Action.html) which returns whether its action
+/// returns a Value, including `null`, eg. whether a source path exists.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Exists {
+    action: Box<dyn Action>,
+}
+
+impl Exists {
+    pub fn new(action: Box<dyn Action>) -> Self {
+        Self { action }
+    }
+}
+
+#[typetag::serde]
+impl Action for Exists {
+    fn apply<'a>(
+        &'a self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        let exists = ctx
+            .guarded(|| self.action.apply(source, destination, ctx))?
+            .is_some();
+        Ok(Some(Cow::Owned(Value::Bool(exists))))
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        vec![self.action.as_ref()]
+    }
+}
@@ -0,0 +1,69 @@
+use crate::action::Action;
+use crate::actions::when::is_truthy;
+use crate::context::Context;
+use crate::errors::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+
+/// This type represents an [Action](../action/trait.Action.html) which returns the elements of an
+/// Array for which the predicate, evaluated with the element as the source root, is truthy.
+///
+/// A predicate is falsy when it returns no Value, `null` or `false`; anything else is truthy.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Filter {
+    action: Box<dyn Action>,
+    predicate: Box<dyn Action>,
+}
+
+impl Filter {
+    pub fn new(action: Box<dyn Action>, predicate: Box<dyn Action>) -> Self {
+        Self { action, predicate }
+    }
+
+    fn matches(&self, i: usize, element: &Value, ctx: &Context) -> Result<bool, Error> {
+        ctx.scoped(i, || {
+            let mut destination = Value::Null;
+            let v = self.predicate.apply(element, &mut destination, ctx)?;
+            Ok(is_truthy(v.as_deref()))
+        })
+    }
+}
+
+#[typetag::serde]
+impl Action for Filter {
+    fn apply<'a>(
+        &'a self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        let filtered = match self.action.apply(source, destination, ctx)? {
+            Some(Cow::Owned(Value::Array(arr))) => {
+                let mut filtered = Vec::with_capacity(arr.len());
+                for (i, element) in arr.into_iter().enumerate() {
+                    if self.matches(i, &element, ctx)? {
+                        filtered.push(element);
+                    }
+                }
+                filtered
+            }
+            Some(Cow::Borrowed(Value::Array(arr))) => {
+                let mut filtered = Vec::with_capacity(arr.len());
+                for (i, element) in arr.iter().enumerate() {
+                    if self.matches(i, element, ctx)? {
+                        filtered.push(element.clone());
+                    }
+                }
+                filtered
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(Cow::Owned(Value::Array(filtered))))
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        vec![self.action.as_ref(), self.predicate.as_ref()]
+    }
+}
@@ -0,0 +1,53 @@
+use crate::action::Action;
+use crate::context::Context;
+use crate::errors::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+
+/// This type represents an [Action](../action/trait.Action.html) which flattens nested Arrays, up
+/// to the depth, into a single Array eg. `[[1,2],[3]]` into `[1,2,3]`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Flatten {
+    depth: usize,
+    action: Box<dyn Action>,
+}
+
+impl Flatten {
+    pub fn new(depth: usize, action: Box<dyn Action>) -> Self {
+        Self { depth, action }
+    }
+}
+
+#[typetag::serde]
+impl Action for Flatten {
+    fn apply<'a>(
+        &'a self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        let arr = match self.action.apply(source, destination, ctx)? {
+            Some(Cow::Owned(Value::Array(arr))) => arr,
+            Some(Cow::Borrowed(Value::Array(arr))) => arr.clone(),
+            _ => return Ok(None),
+        };
+        let mut flattened = Vec::with_capacity(arr.len());
+        flatten(arr, self.depth, &mut flattened);
+        Ok(Some(Cow::Owned(Value::Array(flattened))))
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        vec![self.action.as_ref()]
+    }
+}
+
+fn flatten(arr: Vec<Value>, depth: usize, flattened: &mut Vec<Value>) {
+    for v in arr {
+        match v {
+            Value::Array(nested) if depth > 0 => flatten(nested, depth - 1, flattened),
+            v => flattened.push(v),
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use crate::action::Action;
+use crate::context::Context;
+use crate::errors::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::borrow::Cow;
+use std::ops::Deref;
+
+/// This represents the key flattening operation type
+#[derive(Debug, Serialize, Deserialize)]
+pub enum Type {
+    Flatten,
+    Unflatten,
+}
+
+/// This type represents an [Action](../action/trait.Action.html) which flattens nested Objects
+/// into a single Object whose keys are the paths joined by the separator, eg.
+/// `{"a":{"b":1}}` into `{"a.b":1}`, for feeding flat key/value sinks, or the inverse.
+///
+/// When flattening Arrays and empty Objects are kept as values. When unflattening a later key
+/// replaces any conflicting Value set by an earlier one.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FlattenKeys {
+    r#type: Type,
+    separator: String,
+    action: Box<dyn Action>,
+}
+
+impl FlattenKeys {
+    pub fn new(r#type: Type, separator: String, action: Box<dyn Action>) -> Self {
+        Self {
+            r#type,
+            separator,
+            action,
+        }
+    }
+
+    fn flatten(&self, prefix: Option<&str>, o: &Map<String, Value>, flat: &mut Map<String, Value>) {
+        for (k, v) in o {
+            let key = match prefix {
+                Some(prefix) => format!("{}{}{}", prefix, self.separator, k),
+                None => k.clone(),
+            };
+            match v {
+                Value::Object(nested) if !nested.is_empty() => {
+                    self.flatten(Some(&key), nested, flat)
+                }
+                _ => {
+                    flat.insert(key, v.clone());
+                }
+            }
+        }
+    }
+
+    fn unflatten(&self, flat: &Map<String, Value>) -> Map<String, Value> {
+        let mut nested = Map::new();
+        for (k, v) in flat {
+            let mut parts = k.split(self.separator.as_str()).peekable();
+            let mut current = &mut nested;
+            while let Some(part) = parts.next() {
+                if parts.peek().is_none() {
+                    current.insert(part.to_owned(), v.clone());
+                    break;
+                }
+                let entry = current
+                    .entry(part)
+                    .or_insert_with(|| Value::Object(Map::new()));
+                if !entry.is_object() {
+                    *entry = Value::Object(Map::new());
+                }
+                current = match entry {
+                    Value::Object(o) => o,
+                    _ => unreachable!(),
+                };
+            }
+        }
+        nested
+    }
+}
+
+#[typetag::serde]
+impl Action for FlattenKeys {
+    fn apply<'a>(
+        &'a self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        match self.action.apply(source, destination, ctx)? {
+            Some(v) => match (v.deref(), &self.r#type) {
+                (Value::Object(o), Type::Flatten) => {
+                    let mut flat = Map::new();
+                    self.flatten(None, o, &mut flat);
+                    Ok(Some(Cow::Owned(Value::Object(flat))))
+                }
+                (Value::Object(o), Type::Unflatten) => {
+                    Ok(Some(Cow::Owned(Value::Object(self.unflatten(o)))))
+                }
+                _ => Ok(None),
+            },
+            None => Ok(None),
+        }
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        vec![self.action.as_ref()]
+    }
+}
@@ -0,0 +1,155 @@
+use crate::action::Action;
+use crate::context::Context;
+use crate::errors::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+
+/// This represents a parsed segment of a format template.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Segment {
+    /// literal text copied as is.
+    Literal(String),
+    /// the index of the argument whose value is substituted.
+    Arg(usize),
+}
+
+/// This type represents an [Action](../action/trait.Action.html) which substitutes the results of
+/// its arguments into a template string.
+///
+/// String values are substituted as is, missing values as an empty string and all other values as
+/// their JSON representation.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Format {
+    segments: Vec<Segment>,
+    args: Vec<Box<dyn Action>>,
+}
+
+impl Format {
+    pub fn new(segments: Vec<Segment>, args: Vec<Box<dyn Action>>) -> Self {
+        Self { segments, args }
+    }
+}
+
+#[typetag::serde]
+impl Action for Format {
+    fn apply<'a>(
+        &'a self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        let mut values = Vec::with_capacity(self.args.len());
+        for arg in &self.args {
+            values.push(arg.apply(source, destination, ctx)?);
+        }
+        let mut result = String::new();
+        for segment in &self.segments {
+            match segment {
+                Segment::Literal(s) => result.push_str(s),
+                Segment::Arg(i) => match values.get(*i).and_then(|v| v.as_deref()) {
+                    Some(Value::String(s)) => result.push_str(s),
+                    Some(v) => result.push_str(&v.to_string()),
+                    None => {}
+                },
+            }
+        }
+        Ok(Some(Cow::Owned(Value::String(result))))
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        self.args.iter().map(|a| a.as_ref()).collect()
+    }
+}
+
+/// parses the template into its [Segment](enum.Segment.html)'s.
+///
+/// Placeholders are either empty `{}`, taking the next argument, a numeric index `{0}` or a name
+/// `{first}` which takes the next argument the first time it's seen and the same argument every
+/// time thereafter. Literal braces are escaped by doubling them `{{` and `}}`.
+///
+/// Returns None if the template contains unbalanced braces.
+pub fn parse_template(template: &str) -> Option<Vec<Segment>> {
+    let mut segments = Vec::new();
+    let mut names: Vec<(&str, usize)> = Vec::new();
+    let mut next = 0;
+    let mut literal = String::new();
+    let mut chars = template.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '{' if chars.peek().map(|(_, c)| *c) == Some('{') => {
+                chars.next();
+                literal.push('{');
+            }
+            '}' if chars.peek().map(|(_, c)| *c) == Some('}') => {
+                chars.next();
+                literal.push('}');
+            }
+            '{' => {
+                let end = template[i..].find('}')? + i;
+                let name = template[i + 1..end].trim();
+                if name.contains('{') {
+                    return None;
+                }
+                let index = if name.is_empty() {
+                    next += 1;
+                    next - 1
+                } else if let Ok(index) = name.parse::<usize>() {
+                    index
+                } else if let Some((_, index)) = names.iter().find(|(n, _)| *n == name) {
+                    *index
+                } else {
+                    names.push((name, next));
+                    next += 1;
+                    next - 1
+                };
+                if !literal.is_empty() {
+                    segments.push(Segment::Literal(std::mem::take(&mut literal)));
+                }
+                segments.push(Segment::Arg(index));
+                while let Some((j, _)) = chars.peek() {
+                    if *j > end {
+                        break;
+                    }
+                    chars.next();
+                }
+            }
+            '}' => return None,
+            _ => literal.push(c),
+        }
+    }
+    if !literal.is_empty() {
+        segments.push(Segment::Literal(literal));
+    }
+    Some(segments)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn templates() {
+        use Segment::*;
+        assert_eq!(
+            Some(vec![
+                Literal("Hello ".into()),
+                Arg(0),
+                Literal(" ".into()),
+                Arg(1),
+                Literal(", ".into()),
+                Arg(0),
+            ]),
+            parse_template("Hello {first} {last}, {first}")
+        );
+        assert_eq!(
+            Some(vec![Arg(0), Literal("-".into()), Arg(1), Arg(0)]),
+            parse_template("{}-{}{0}")
+        );
+        assert_eq!(Some(vec![Literal("{a}".into())]), parse_template("{{a}}"));
+        assert_eq!(None, parse_template("{a"));
+        assert_eq!(None, parse_template("a}"));
+        assert_eq!(None, parse_template("{{a}"));
+    }
+}
@@ -0,0 +1,23 @@
+use thiserror::Error;
+
+/// This type represents all possible errors that an occur while applying a strict
+/// [Getter](struct.Getter.html).
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("Source is missing '{}' of getter namespace '{}'.", at, namespace)]
+    MissingPath { namespace: String, at: String },
+
+    #[error(
+        "Invalid source type at '{}' of getter namespace '{}', expected {} but found {}.",
+        at,
+        namespace,
+        expected,
+        found
+    )]
+    InvalidSourceType {
+        namespace: String,
+        at: String,
+        expected: String,
+        found: String,
+    },
+}
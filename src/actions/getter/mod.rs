@@ -1,22 +1,42 @@
+mod errors;
 pub mod namespace;
 
+pub use errors::Error;
+
 use crate::action::Action;
-use crate::errors::Error;
+use crate::actions::getter::Error as GetterError;
+use crate::context::Context;
+use crate::errors::Error as CrateErr;
 use namespace::Namespace;
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use serde_json::{Map, Value};
 use std::borrow::Cow;
 
 /// This type represents an [Action](../action/trait.Action.html) which extracts data from the
 /// source JSON Value.
+///
+/// By default a missing path returns no Value. A strict Getter, or any Getter when strict mode is
+/// enabled in the [Context](../context/struct.Context.html), instead returns a descriptive error
+/// distinguishing a missing path from a source of the wrong type.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Getter {
     namespace: Vec<Namespace>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    strict: bool,
 }
 
 impl Getter {
     pub fn new(namespace: Vec<Namespace>) -> Self {
-        Self { namespace }
+        Self {
+            namespace,
+            strict: false,
+        }
+    }
+
+    /// sets whether a missing path or source of the wrong type returns an error.
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
     }
 }
 
@@ -26,27 +46,182 @@ impl Action for Getter {
         &self,
         source: &'a Value,
         _destination: &mut Value,
-    ) -> Result<Option<Cow<'a, Value>>, Error> {
-        let mut current = source;
-        for ns in &self.namespace {
-            current = match expand(ns, current)? {
-                Some(value) => value,
-                None => return Ok(None),
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, CrateErr> {
+        ctx.evaluate()?;
+        ctx.traverse(self.namespace.len())?;
+        if self.strict || ctx.strict() {
+            let namespace = Namespace::join(&self.namespace);
+            Ok(Some(get_strict(
+                &self.namespace,
+                source,
+                &namespace,
+                String::new(),
+            )?))
+        } else {
+            get(&self.namespace, source)
+        }
+    }
+
+    fn source_paths(&self) -> Vec<String> {
+        vec![Namespace::join(&self.namespace)]
+    }
+}
+
+#[inline]
+fn get<'a>(namespace: &[Namespace], source: &'a Value) -> Result<Option<Cow<'a, Value>>, CrateErr> {
+    let mut current = source;
+    for (i, ns) in namespace.iter().enumerate() {
+        if let Namespace::WildcardArray = ns {
+            return match current {
+                Value::Array(arr) => {
+                    let remaining = &namespace[i + 1..];
+                    let mut values = Vec::with_capacity(arr.len());
+                    for v in arr {
+                        values.push(match get(remaining, v)? {
+                            Some(v) => v.into_owned(),
+                            None => Value::Null,
+                        });
+                    }
+                    Ok(Some(Cow::Owned(Value::Array(values))))
+                }
+                _ => Ok(None),
+            };
+        }
+        if let Namespace::MultiKey { ids } = ns {
+            return match current {
+                Value::Object(o) => {
+                    let remaining = &namespace[i + 1..];
+                    let mut map = Map::with_capacity(ids.len());
+                    for id in ids {
+                        if let Some(v) = o.get(id) {
+                            if let Some(v) = get(remaining, v)? {
+                                map.insert(id.clone(), v.into_owned());
+                            }
+                        }
+                    }
+                    if map.is_empty() {
+                        return Ok(None);
+                    }
+                    Ok(Some(Cow::Owned(Value::Object(map))))
+                }
+                _ => Ok(None),
             };
         }
-        Ok(Some(Cow::Borrowed(current)))
+        current = match expand(ns, current)? {
+            Some(value) => value,
+            None => return Ok(None),
+        };
+    }
+    Ok(Some(Cow::Borrowed(current)))
+}
+
+/// gets the Value like `get` but returns an error, including the full namespace and the location
+/// within it, when the path is missing or the source is of the wrong type.
+fn get_strict<'a>(
+    namespace: &[Namespace],
+    source: &'a Value,
+    full: &str,
+    mut at: String,
+) -> Result<Cow<'a, Value>, GetterError> {
+    let mut current = source;
+    for (i, ns) in namespace.iter().enumerate() {
+        if let Namespace::Object { .. } = ns {
+            if !at.is_empty() {
+                at.push('.');
+            }
+        }
+        match (ns, current) {
+            (Namespace::WildcardArray, Value::Array(arr)) => {
+                let remaining = &namespace[i + 1..];
+                let mut values = Vec::with_capacity(arr.len());
+                for (index, v) in arr.iter().enumerate() {
+                    let at = format!("{}[{}]", at, index);
+                    values.push(get_strict(remaining, v, full, at)?.into_owned());
+                }
+                return Ok(Cow::Owned(Value::Array(values)));
+            }
+            (Namespace::MultiKey { ids }, Value::Object(o)) => {
+                let remaining = &namespace[i + 1..];
+                let mut map = Map::with_capacity(ids.len());
+                for id in ids {
+                    let at = if at.is_empty() {
+                        id.clone()
+                    } else {
+                        format!("{}.{}", at, id)
+                    };
+                    let v = o.get(id).ok_or_else(|| GetterError::MissingPath {
+                        namespace: full.to_owned(),
+                        at: at.clone(),
+                    })?;
+                    map.insert(id.clone(), get_strict(remaining, v, full, at)?.into_owned());
+                }
+                return Ok(Cow::Owned(Value::Object(map)));
+            }
+            (Namespace::Object { id }, Value::Object(o)) => {
+                at.push_str(id);
+                current = o.get(id).ok_or_else(|| GetterError::MissingPath {
+                    namespace: full.to_owned(),
+                    at: at.clone(),
+                })?;
+            }
+            (Namespace::PointerIndex { index }, Value::Object(o)) => {
+                at.push_str(&ns.to_string());
+                current = o
+                    .get(&index.to_string())
+                    .ok_or_else(|| GetterError::MissingPath {
+                        namespace: full.to_owned(),
+                        at: at.clone(),
+                    })?;
+            }
+            (Namespace::Array { index }, Value::Array(arr))
+            | (Namespace::PointerIndex { index }, Value::Array(arr)) => {
+                at.push_str(&ns.to_string());
+                current = arr.get(*index).ok_or_else(|| GetterError::MissingPath {
+                    namespace: full.to_owned(),
+                    at: at.clone(),
+                })?;
+            }
+            (ns, v) => {
+                at.push_str(&ns.to_string());
+                let expected = match ns {
+                    Namespace::Object { .. } | Namespace::MultiKey { .. } => "an Object",
+                    Namespace::PointerIndex { .. } => "an Object or an Array",
+                    _ => "an Array",
+                };
+                return Err(GetterError::InvalidSourceType {
+                    namespace: full.to_owned(),
+                    at,
+                    expected: expected.to_owned(),
+                    found: type_name(v).to_owned(),
+                });
+            }
+        }
+    }
+    Ok(Cow::Borrowed(current))
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "a Bool",
+        Value::Number(_) => "a Number",
+        Value::String(_) => "a String",
+        Value::Array(_) => "an Array",
+        Value::Object(_) => "an Object",
     }
 }
 
 #[inline]
-fn expand<'a>(ns: &Namespace, current: &'a Value) -> Result<Option<&'a Value>, Error> {
+fn expand<'a>(ns: &Namespace, current: &'a Value) -> Result<Option<&'a Value>, CrateErr> {
     match current {
         Value::Object(o) => match ns {
             Namespace::Object { id } => Ok(o.get(id)),
+            Namespace::PointerIndex { index } => Ok(o.get(&index.to_string())),
             _ => Ok(None),
         },
         Value::Array(arr) => match ns {
-            Namespace::Array { index } => Ok(arr.get(*index)),
+            Namespace::Array { index } | Namespace::PointerIndex { index } => Ok(arr.get(*index)),
             _ => Ok(None),
         },
         _ => Ok(None),
@@ -64,7 +239,7 @@ mod tests {
         let input = json!({"key":"value"});
         let mut output = Value::Object(Map::new());
         let getter = Getter::new(ns);
-        let res = getter.apply(&input, &mut output)?;
+        let res = getter.apply(&input, &mut output, &Context::default())?;
         assert_eq!(res, Some(Cow::Owned(Value::String("value".into()))));
         Ok(())
     }
@@ -78,7 +253,7 @@ mod tests {
         });
         let mut output = Value::Object(Map::new());
         let getter = Getter::new(ns);
-        let res = getter.apply(&input, &mut output)?;
+        let res = getter.apply(&input, &mut output, &Context::default())?;
         assert_eq!(res, Some(Cow::Owned(Value::String("value".into()))));
         Ok(())
     }
@@ -89,7 +264,7 @@ mod tests {
         let input = json!([["value"]]);
         let mut output = Value::Object(Map::new());
         let getter = Getter::new(ns);
-        let res = getter.apply(&input, &mut output)?;
+        let res = getter.apply(&input, &mut output, &Context::default())?;
         assert_eq!(res, Some(Cow::Owned(Value::String("value".into()))));
         Ok(())
     }
@@ -100,7 +275,7 @@ mod tests {
         let input = json!([["value"]]);
         let mut output = Value::Object(Map::new());
         let getter = Getter::new(ns);
-        let res = getter.apply(&input, &mut output)?;
+        let res = getter.apply(&input, &mut output, &Context::default())?;
         assert_eq!(res, Some(Cow::Owned(json!(["value"]))));
         Ok(())
     }
@@ -111,18 +286,115 @@ mod tests {
         let input = json!([{"key":"value"}]);
         let mut output = Value::Object(Map::new());
         let getter = Getter::new(ns);
-        let res = getter.apply(&input, &mut output)?;
+        let res = getter.apply(&input, &mut output, &Context::default())?;
         assert_eq!(res, Some(Cow::Owned(json!("value"))));
         Ok(())
     }
 
+    #[test]
+    fn wildcard_array() -> Result<(), Box<dyn std::error::Error>> {
+        let ns = Namespace::parse("addresses[*].street")?;
+        let input = json!({"addresses":[{"street":"26 Here Blvd"},{"postal":"123456"},{"street":"26 Lakeside"}]});
+        let mut output = Value::Object(Map::new());
+        let getter = Getter::new(ns);
+        let res = getter.apply(&input, &mut output, &Context::default())?;
+        assert_eq!(
+            res,
+            Some(Cow::Owned(json!(["26 Here Blvd", null, "26 Lakeside"])))
+        );
+
+        let ns = Namespace::parse("addresses[*].street")?;
+        let input = json!({"addresses":{"street":"26 Here Blvd"}});
+        let getter = Getter::new(ns);
+        let res = getter.apply(&input, &mut output, &Context::default())?;
+        assert_eq!(res, None);
+        Ok(())
+    }
+
+    #[test]
+    fn multi_key() -> Result<(), Box<dyn std::error::Error>> {
+        let input = json!({"a": 1, "b": 2, "c": {"d": 3}, "e": {"d": 4}});
+        let mut output = Value::Null;
+
+        let getter = Getter::new(Namespace::parse(r#"["a","c","x"]"#)?);
+        let res = getter.apply(&input, &mut output, &Context::default())?;
+        assert_eq!(res, Some(Cow::Owned(json!({"a": 1, "c": {"d": 3}}))));
+
+        let getter = Getter::new(Namespace::parse(r#"["a","c","e"].d"#)?);
+        let res = getter.apply(&input, &mut output, &Context::default())?;
+        assert_eq!(res, Some(Cow::Owned(json!({"c": 3, "e": 4}))));
+
+        let getter = Getter::new(Namespace::parse(r#"a["b","c"]"#)?);
+        let res = getter.apply(&input, &mut output, &Context::default())?;
+        assert_eq!(res, None);
+
+        let getter = Getter::new(Namespace::parse(r#"["q","r"]"#)?);
+        let res = getter.apply(&input, &mut output, &Context::default())?;
+        assert_eq!(res, None);
+
+        let getter = Getter::new(Namespace::parse(r#"["a","b"].d"#)?);
+        let res = getter.apply(&input, &mut output, &Context::default())?;
+        assert_eq!(res, None);
+
+        let getter = Getter::new(Namespace::parse(r#"["a","c","e"].d"#)?).with_strict(true);
+        let err = getter.apply(&input, &mut output, &Context::default());
+        assert!(matches!(
+            err.unwrap_err(),
+            CrateErr::Getter(GetterError::InvalidSourceType { at, .. }) if at == "a.d"
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn strict() -> Result<(), Box<dyn std::error::Error>> {
+        let input = json!({"profile": {"name": "Dean", "tags": ["a"]}, "items": [{"id": 1}, {}]});
+        let mut output = Value::Null;
+        let ctx = Context::default();
+
+        let getter = Getter::new(Namespace::parse("profile.name")?).with_strict(true);
+        let res = getter.apply(&input, &mut output, &ctx)?;
+        assert_eq!(res, Some(Cow::Borrowed(&json!("Dean"))));
+
+        let getter = Getter::new(Namespace::parse("profile.email")?).with_strict(true);
+        let err = getter.apply(&input, &mut output, &ctx).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Source is missing 'profile.email' of getter namespace 'profile.email'."
+        );
+
+        let getter = Getter::new(Namespace::parse("profile[0].name")?).with_strict(true);
+        let err = getter.apply(&input, &mut output, &ctx).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid source type at 'profile[0]' of getter namespace 'profile[0].name', expected an Array but found an Object."
+        );
+
+        let getter = Getter::new(Namespace::parse("profile.tags[1]")?).with_strict(true);
+        let err = getter.apply(&input, &mut output, &ctx).unwrap_err();
+        assert!(matches!(
+            err,
+            CrateErr::Getter(GetterError::MissingPath { at, .. }) if at == "profile.tags[1]"
+        ));
+
+        let getter = Getter::new(Namespace::parse("items[*].id")?);
+        let res = getter.apply(&input, &mut output, &ctx)?;
+        assert_eq!(res, Some(Cow::Owned(json!([1, null]))));
+        let ctx = Context::default().with_strict(true);
+        let err = getter.apply(&input, &mut output, &ctx).unwrap_err();
+        assert!(matches!(
+            err,
+            CrateErr::Getter(GetterError::MissingPath { at, .. }) if at == "items[1].id"
+        ));
+        Ok(())
+    }
+
     #[test]
     fn array_value_in_object_in_array() -> Result<(), Box<dyn std::error::Error>> {
         let ns = Namespace::parse("[0].key[1]")?;
         let input = json!([{"key":[null,"value"]}]);
         let mut output = Value::Object(Map::new());
         let getter = Getter::new(ns);
-        let res = getter.apply(&input, &mut output)?;
+        let res = getter.apply(&input, &mut output, &Context::default())?;
         assert_eq!(res, Some(Cow::Owned(json!("value"))));
         Ok(())
     }
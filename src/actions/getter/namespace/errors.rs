@@ -13,6 +13,12 @@ pub enum Error {
     #[error("Missing end bracket ']' in array index for namespace: {0}")]
     MissingArrayIndexBracket(String),
 
+    #[error("Invalid Wildcard Array Syntax for namespace: {0}. Wildcard Array Syntax must be exactly '[*]'.")]
+    InvalidWildcardArraySyntax(String),
+
     #[error("Invalid Explicit Key Syntax for namespace {0}. Explicit Key Syntax must start with '[\"' and end with '\"]' with any enclosed '\"' escaped.")]
     InvalidExplicitKeySyntax(String),
+
+    #[error("Invalid JSON Pointer: {0}. A JSON Pointer must be empty or start with '/', with any '~' escaped as '~0' and '/' as '~1'.")]
+    InvalidJsonPointer(String),
 }
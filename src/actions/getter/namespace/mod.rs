@@ -22,22 +22,61 @@ pub enum Namespace {
 
     /// Represents an index/location within the source data's JSON Array.
     Array { index: usize },
+
+    /// Represents every element within the source data's JSON Array, the remaining namespace is
+    /// applied to each element and the results returned as a JSON Array aligned with the source.
+    WildcardArray,
+
+    /// Represents multiple ids/locations within the source data's Object, the remaining namespace
+    /// is applied to each and the results returned as a JSON Object of only those keys found, or
+    /// no value when none are found.
+    MultiKey { ids: Vec<String> },
+
+    /// Represents a numeric JSON Pointer reference token, which resolves to the Object id of the
+    /// same digits when the source data is an Object, or to the index when it is an Array.
+    PointerIndex { index: usize },
 }
 
 impl Display for Namespace {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
         match self {
             Namespace::Object { id } => write!(f, "{}", id),
-            Namespace::Array { index } => write!(f, "[{}]", index),
+            Namespace::Array { index } | Namespace::PointerIndex { index } => {
+                write!(f, "[{}]", index)
+            }
+            Namespace::WildcardArray => write!(f, "[*]"),
+            Namespace::MultiKey { ids } => {
+                let ids: Vec<String> = ids
+                    .iter()
+                    .map(|id| format!(r#""{}""#, id.replace('"', r#"\""#)))
+                    .collect();
+                write!(f, "[{}]", ids.join(","))
+            }
         }
     }
 }
 
 impl Namespace {
+    /// formats a set of [Namespace](enum.Namespace.html)'s back into the transformation syntax.
+    pub fn join(namespace: &[Namespace]) -> String {
+        let mut s = String::new();
+        for ns in namespace {
+            if let Namespace::Object { .. } = ns {
+                if !s.is_empty() {
+                    s.push('.');
+                }
+            }
+            s.push_str(&ns.to_string());
+        }
+        s
+    }
+
     /// parses a transformation syntax string into an Vec of [Namespace](enum.Namespace.html)'s for
     /// use in the [Getter](../struct.Getter.html).
     ///
-    /// The transformation syntax is very similar to access JSON data in Javascript.
+    /// The transformation syntax is very similar to access JSON data in Javascript with a few additions:
+    /// * `[*]` eg. addresses[*].street which denotes that the remaining namespace should be applied to every element of the Array, returning an Array of the results with `null` for any element the remainder could not be found in.
+    /// * `["a","c","e"]` eg. user["name","email"] which denotes that only the listed keys of the Object should be returned as an Object, omitting any key not found, or no value when none are found.
     ///
     /// To handle special characters such as ``(blank), `[`, `]`, `"` and `.` you can use the explicit
     /// key syntax `["example[].blah"]` which would represent the key in the following JSON:
@@ -95,24 +134,44 @@ impl Namespace {
                     }
                     return match bytes[idx] {
                         b'"' => {
-                            // parse explicit key
+                            // parse explicit key, or keys when comma separated
+                            let mut ids = Vec::new();
                             idx += 1;
                             while idx < bytes.len() {
                                 let b = bytes[idx];
                                 match b {
                                     b'"' if bytes[idx - 1] != b'\\' => {
                                         idx += 1;
-                                        if bytes[idx] != b']' {
+                                        let id = unsafe { String::from_utf8_unchecked(s.clone()) }
+                                            .replace("\\", ""); // unescape required escaped double quotes
+                                        s.clear();
+                                        if idx < bytes.len() && bytes[idx] == b',' {
+                                            // another key must follow
+                                            ids.push(id);
+                                            idx += 1;
+                                            while idx < bytes.len() && bytes[idx] == b' ' {
+                                                idx += 1;
+                                            }
+                                            if idx >= bytes.len() || bytes[idx] != b'"' {
+                                                return Err(Error::InvalidExplicitKeySyntax(
+                                                    input.to_owned(),
+                                                ));
+                                            }
+                                            idx += 1;
+                                            continue;
+                                        }
+                                        if idx >= bytes.len() || bytes[idx] != b']' {
                                             // error invalid explicit key syntax
                                             return Err(Error::InvalidExplicitKeySyntax(
                                                 input.to_owned(),
                                             ));
                                         }
-                                        namespaces.push(Namespace::Object {
-                                            id: unsafe { String::from_utf8_unchecked(s.clone()) }
-                                                .replace("\\", ""), // unescape required escaped double quotes
-                                        });
-                                        s.clear();
+                                        if ids.is_empty() {
+                                            namespaces.push(Namespace::Object { id });
+                                        } else {
+                                            ids.push(id);
+                                            namespaces.push(Namespace::MultiKey { ids });
+                                        }
                                         idx += 1;
                                         continue 'outer;
                                     }
@@ -125,6 +184,16 @@ impl Namespace {
                             // error never reached the end bracket of explicit key
                             Err(Error::InvalidExplicitKeySyntax(input.to_owned()))
                         }
+                        b'*' => {
+                            // wildcard array
+                            idx += 1;
+                            if idx >= bytes.len() || bytes[idx] != b']' {
+                                return Err(Error::InvalidWildcardArraySyntax(input.to_owned()));
+                            }
+                            namespaces.push(Namespace::WildcardArray);
+                            idx += 1;
+                            continue 'outer;
+                        }
                         _ => {
                             // parse array index
                             while idx < bytes.len() {
@@ -166,6 +235,62 @@ impl Namespace {
         }
         Ok(namespaces)
     }
+
+    /// parses a JSON Pointer (RFC 6901) eg. `/nested/inner/key` into an Vec of
+    /// [Namespace](enum.Namespace.html)'s for use in the [Getter](../struct.Getter.html).
+    ///
+    /// `~1` and `~0` are unescaped to `/` and `~` respectively. As a JSON Pointer does not
+    /// distinguish Object keys from Array indexes, tokens which are a valid Array index eg. `0` or
+    /// `12`, are resolved against whichever the source data contains and all others are an Object
+    /// key.
+    pub fn parse_pointer(input: &str) -> Result<Vec<Namespace>, Error> {
+        let tokens =
+            pointer_tokens(input).ok_or_else(|| Error::InvalidJsonPointer(input.to_owned()))?;
+        Ok(tokens
+            .into_iter()
+            .map(|token| match pointer_index(&token) {
+                Some(index) => Namespace::PointerIndex { index },
+                None => Namespace::Object { id: token },
+            })
+            .collect())
+    }
+}
+
+/// splits a JSON Pointer into its unescaped reference tokens, returning None if it is invalid.
+pub(crate) fn pointer_tokens(input: &str) -> Option<Vec<String>> {
+    if input.is_empty() {
+        return Some(Vec::new());
+    }
+    input
+        .strip_prefix('/')?
+        .split('/')
+        .map(|token| {
+            let mut s = String::with_capacity(token.len());
+            let mut chars = token.chars();
+            while let Some(c) = chars.next() {
+                match c {
+                    '~' => match chars.next()? {
+                        '0' => s.push('~'),
+                        '1' => s.push('/'),
+                        _ => return None,
+                    },
+                    c => s.push(c),
+                }
+            }
+            Some(s)
+        })
+        .collect()
+}
+
+/// returns the Array index of a JSON Pointer reference token, which may not have leading zeros.
+pub(crate) fn pointer_index(token: &str) -> Option<usize> {
+    if token.is_empty()
+        || (token.len() > 1 && token.starts_with('0'))
+        || !token.bytes().all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+    token.parse().ok()
 }
 
 #[cfg(test)]
@@ -313,6 +438,86 @@ mod tests {
         assert_eq!(expected, results);
     }
 
+    #[test]
+    fn test_wildcard_array() {
+        let ns = "addresses[*].street";
+        let results = Namespace::parse(ns).unwrap();
+        let expected = vec![
+            Namespace::Object {
+                id: "addresses".to_owned(),
+            },
+            Namespace::WildcardArray,
+            Namespace::Object {
+                id: "street".to_owned(),
+            },
+        ];
+        assert_eq!(expected, results);
+
+        let ns = "addresses[*1]";
+        let results = Namespace::parse(ns);
+        assert!(results.is_err());
+        let actual = matches!(
+            results.err().unwrap(),
+            Error::InvalidWildcardArraySyntax { .. }
+        );
+        assert!(actual);
+    }
+
+    #[test]
+    fn test_pointer() {
+        let results = Namespace::parse_pointer("/a~1b/m~0n/0/01/").unwrap();
+        let expected = vec![
+            Namespace::Object {
+                id: "a/b".to_owned(),
+            },
+            Namespace::Object {
+                id: "m~n".to_owned(),
+            },
+            Namespace::PointerIndex { index: 0 },
+            Namespace::Object {
+                id: "01".to_owned(),
+            },
+            Namespace::Object { id: "".to_owned() },
+        ];
+        assert_eq!(expected, results);
+
+        assert_eq!(Namespace::parse_pointer("").unwrap(), Vec::new());
+
+        for ns in &["a/b", "/a~2", "/a~"] {
+            let results = Namespace::parse_pointer(ns);
+            let actual = matches!(results.err().unwrap(), Error::InvalidJsonPointer { .. });
+            assert!(actual);
+        }
+    }
+
+    #[test]
+    fn test_multi_key() {
+        let ns = r#"user["name", "e\"mail"].first"#;
+        let results = Namespace::parse(ns).unwrap();
+        let expected = vec![
+            Namespace::Object {
+                id: "user".to_owned(),
+            },
+            Namespace::MultiKey {
+                ids: vec!["name".to_owned(), r#"e"mail"#.to_owned()],
+            },
+            Namespace::Object {
+                id: "first".to_owned(),
+            },
+        ];
+        assert_eq!(expected, results);
+        assert_eq!(r#"user["name","e\"mail"].first"#, Namespace::join(&results));
+
+        for ns in &[r#"["a","#, r#"["a",b]"#, r#"["a""#] {
+            let results = Namespace::parse(ns);
+            let actual = matches!(
+                results.err().unwrap(),
+                Error::InvalidExplicitKeySyntax { .. }
+            );
+            assert!(actual);
+        }
+    }
+
     #[test]
     fn test_explicit_key() {
         let ns = r#"["embedded.array[0][1]"]"#;
@@ -429,7 +634,10 @@ mod tests {
         let ns = r#"["""]"#;
         let results = Namespace::parse(ns);
         assert!(results.is_err());
-        let actual = matches!(results.err().unwrap(), Error::InvalidExplicitKeySyntax { .. });
+        let actual = matches!(
+            results.err().unwrap(),
+            Error::InvalidExplicitKeySyntax { .. }
+        );
         assert!(actual);
 
         let ns = r#"["\""]"#;
@@ -0,0 +1,70 @@
+use crate::action::Action;
+use crate::context::Context;
+use crate::errors::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::borrow::Cow;
+
+/// This type represents an [Action](../action/trait.Action.html) which groups the elements of an
+/// Array into an Object keyed by the result of the key action, evaluated with each element as the
+/// source root, with Arrays of the matching elements in their original order.
+///
+/// String keys are used as is and numbers and booleans by their string form; elements with any
+/// other, or no, key are skipped.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GroupBy {
+    action: Box<dyn Action>,
+    key: Box<dyn Action>,
+}
+
+impl GroupBy {
+    pub fn new(action: Box<dyn Action>, key: Box<dyn Action>) -> Self {
+        Self { action, key }
+    }
+
+    fn key(&self, i: usize, element: &Value, ctx: &Context) -> Result<Option<String>, Error> {
+        ctx.scoped(i, || {
+            let mut destination = Value::Null;
+            let key = self.key.apply(element, &mut destination, ctx)?;
+            Ok(match key.as_deref() {
+                Some(Value::String(s)) => Some(s.clone()),
+                Some(Value::Number(n)) => Some(n.to_string()),
+                Some(Value::Bool(b)) => Some(b.to_string()),
+                _ => None,
+            })
+        })
+    }
+}
+
+#[typetag::serde]
+impl Action for GroupBy {
+    fn apply<'a>(
+        &'a self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        let arr = match self.action.apply(source, destination, ctx)? {
+            Some(Cow::Owned(Value::Array(arr))) => arr,
+            Some(Cow::Borrowed(Value::Array(arr))) => arr.clone(),
+            _ => return Ok(None),
+        };
+        let mut groups = Map::new();
+        for (i, element) in arr.into_iter().enumerate() {
+            if let Some(key) = self.key(i, &element, ctx)? {
+                if let Value::Array(group) = groups
+                    .entry(key)
+                    .or_insert_with(|| Value::Array(Vec::new()))
+                {
+                    group.push(element);
+                }
+            }
+        }
+        Ok(Some(Cow::Owned(Value::Object(groups))))
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        vec![self.action.as_ref(), self.key.as_ref()]
+    }
+}
@@ -0,0 +1,119 @@
+use crate::action::Action;
+use crate::context::Context;
+use crate::errors::Error;
+use hmac::Mac;
+use md5::Md5;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest as _, Sha256};
+use std::borrow::Cow;
+use std::fmt::Write;
+use std::ops::Deref;
+
+/// This represents the hashing algorithm
+#[derive(Debug, Serialize, Deserialize)]
+pub enum Type {
+    Sha256,
+    Md5,
+}
+
+/// This type represents an [Action](../action/trait.Action.html) which hashes a string and returns
+/// the digest as lower case hex.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Digest {
+    r#type: Type,
+    action: Box<dyn Action>,
+}
+
+impl Digest {
+    pub fn new(r#type: Type, action: Box<dyn Action>) -> Self {
+        Self { r#type, action }
+    }
+}
+
+#[typetag::serde]
+impl Action for Digest {
+    fn apply<'a>(
+        &'a self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        match self.action.apply(source, destination, ctx)? {
+            Some(v) => match v.deref() {
+                Value::String(s) => {
+                    let hex = match self.r#type {
+                        Type::Sha256 => to_hex(&Sha256::digest(s.as_bytes())),
+                        Type::Md5 => to_hex(&Md5::digest(s.as_bytes())),
+                    };
+                    Ok(Some(Cow::Owned(Value::String(hex))))
+                }
+                _ => Ok(None),
+            },
+            None => Ok(None),
+        }
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        vec![self.action.as_ref()]
+    }
+}
+
+/// This type represents an [Action](../action/trait.Action.html) which signs a string using
+/// HMAC-SHA256 and returns the signature as lower case hex, eg. for consistently pseudonymizing
+/// PII across documents.
+///
+/// The secret is looked up by id from the [Keys](../context/struct.Keys.html) registered on the
+/// [TransformBuilder](../transformer/struct.TransformBuilder.html), returning an error if missing.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Hmac {
+    key: String,
+    action: Box<dyn Action>,
+}
+
+impl Hmac {
+    pub fn new(key: String, action: Box<dyn Action>) -> Self {
+        Self { key, action }
+    }
+}
+
+#[typetag::serde]
+impl Action for Hmac {
+    fn apply<'a>(
+        &'a self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        let key = ctx.key(&self.key)?;
+        match self.action.apply(source, destination, ctx)? {
+            Some(v) => match v.deref() {
+                Value::String(s) => {
+                    // HMAC accepts keys of any length so this can never fail.
+                    let mut mac = hmac::Hmac::<Sha256>::new_from_slice(key)
+                        .expect("HMAC can take a key of any size");
+                    mac.update(s.as_bytes());
+                    let hex = to_hex(&mac.finalize().into_bytes());
+                    Ok(Some(Cow::Owned(Value::String(hex))))
+                }
+                _ => Ok(None),
+            },
+            None => Ok(None),
+        }
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        vec![self.action.as_ref()]
+    }
+}
+
+/// returns the bytes as lower case hex.
+pub(crate) fn to_hex(bytes: &[u8]) -> String {
+    let mut s = String::with_capacity(bytes.len() * 2);
+    for b in bytes {
+        let _ = write!(s, "{:02x}", b);
+    }
+    s
+}
@@ -0,0 +1,60 @@
+use crate::action::Action;
+use crate::actions::when::is_truthy;
+use crate::context::Context;
+use crate::errors::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+
+/// This type represents an [Action](../action/trait.Action.html) which returns the result of the
+/// `then` action when the condition evaluates to a truthy Value, otherwise the result of the
+/// optional `else` action.
+///
+/// A condition is falsy when it returns no Value, `null` or `false`; anything else is truthy.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct If {
+    condition: Box<dyn Action>,
+    then: Box<dyn Action>,
+    r#else: Option<Box<dyn Action>>,
+}
+
+impl If {
+    pub fn new(
+        condition: Box<dyn Action>,
+        then: Box<dyn Action>,
+        r#else: Option<Box<dyn Action>>,
+    ) -> Self {
+        Self {
+            condition,
+            then,
+            r#else,
+        }
+    }
+}
+
+#[typetag::serde]
+impl Action for If {
+    fn apply<'a>(
+        &'a self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        let condition = ctx.guarded(|| self.condition.apply(source, destination, ctx))?;
+        if is_truthy(condition.as_deref()) {
+            self.then.apply(source, destination, ctx)
+        } else {
+            match &self.r#else {
+                Some(action) => action.apply(source, destination, ctx),
+                None => Ok(None),
+            }
+        }
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        let mut children = vec![self.condition.as_ref(), self.then.as_ref()];
+        children.extend(self.r#else.iter().map(|a| a.as_ref()));
+        children
+    }
+}
@@ -0,0 +1,50 @@
+use crate::action::Action;
+use crate::context::Context;
+use crate::errors::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+
+/// This type represents an [Action](../action/trait.Action.html) which returns the index of the
+/// Array element currently being iterated over, eg. within `map()`, and no Value outside of an
+/// iteration.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Index {}
+
+impl Index {
+    pub const fn new() -> Self {
+        Self {}
+    }
+}
+
+#[typetag::serde]
+impl Action for Index {
+    fn apply<'a>(
+        &'a self,
+        _source: &'a Value,
+        _destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        Ok(ctx
+            .index()
+            .map(|index| Cow::Owned(Value::Number(index.into()))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn index() -> Result<(), Box<dyn std::error::Error>> {
+        let ctx = Context::default();
+        let action = Index::new();
+        let mut destination = Value::Null;
+        assert_eq!(None, action.apply(&Value::Null, &mut destination, &ctx)?);
+
+        let res = ctx.scoped(3, || action.apply(&Value::Null, &mut destination, &ctx))?;
+        assert_eq!(Some(Cow::Owned(Value::from(3))), res);
+        Ok(())
+    }
+}
@@ -0,0 +1,55 @@
+use crate::action::Action;
+use crate::actions::compare::compare;
+use crate::context::Context;
+use crate::errors::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+use std::cmp::Ordering;
+
+/// This type represents an [Action](../action/trait.Action.html) which returns the position of the
+/// first element of an Array equal to the Value, or `null` when there is none.
+///
+/// Numbers are compared by value.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IndexOf {
+    action: Box<dyn Action>,
+    value: Box<dyn Action>,
+}
+
+impl IndexOf {
+    pub fn new(action: Box<dyn Action>, value: Box<dyn Action>) -> Self {
+        Self { action, value }
+    }
+}
+
+#[typetag::serde]
+impl Action for IndexOf {
+    fn apply<'a>(
+        &'a self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        let arr = match self.action.apply(source, destination, ctx)? {
+            Some(v) => v,
+            None => return Ok(None),
+        };
+        let arr = match arr.as_ref() {
+            Value::Array(arr) => arr,
+            _ => return Ok(None),
+        };
+        let value = self.value.apply(source, destination, ctx)?;
+        let value = value.as_deref().unwrap_or(&Value::Null);
+        let index = arr
+            .iter()
+            .position(|v| compare(v, value) == Some(Ordering::Equal))
+            .map_or(Value::Null, |i| Value::Number(i.into()));
+        Ok(Some(Cow::Owned(index)))
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        vec![self.action.as_ref(), self.value.as_ref()]
+    }
+}
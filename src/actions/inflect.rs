@@ -0,0 +1,78 @@
+use crate::action::Action;
+use crate::context::Context;
+use crate::errors::Error;
+use inflector::string::pluralize::to_plural;
+use inflector::string::singularize::to_singular;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+use std::ops::Deref;
+
+/// This represents the inflection operation type
+#[derive(Debug, Serialize, Deserialize)]
+pub enum Type {
+    Pluralize,
+    Singularize,
+}
+
+/// This type represents an [Action](../action/trait.Action.html) which converts an English word
+/// into its plural or singular form.
+///
+/// When a `count` is supplied the singular form is returned if the count is 1 or -1, otherwise the
+/// plural form, eg. for generating labels such as "1 item" and "3 items".
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Inflect {
+    r#type: Type,
+    action: Box<dyn Action>,
+    count: Option<Box<dyn Action>>,
+}
+
+impl Inflect {
+    pub fn new(r#type: Type, action: Box<dyn Action>, count: Option<Box<dyn Action>>) -> Self {
+        Self {
+            r#type,
+            action,
+            count,
+        }
+    }
+}
+
+#[typetag::serde]
+impl Action for Inflect {
+    fn apply<'a>(
+        &'a self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        let singular = match &self.count {
+            Some(count) => match count.apply(source, destination, ctx)? {
+                Some(v) => matches!(v.deref().as_f64(), Some(n) if n.abs() == 1.0),
+                None => false,
+            },
+            None => matches!(self.r#type, Type::Singularize),
+        };
+        match self.action.apply(source, destination, ctx)? {
+            Some(v) => match v.deref() {
+                Value::String(s) => {
+                    let s = if singular {
+                        to_singular(s)
+                    } else {
+                        to_plural(s)
+                    };
+                    Ok(Some(Cow::Owned(Value::String(s))))
+                }
+                _ => Ok(None),
+            },
+            None => Ok(None),
+        }
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        std::iter::once(&self.action)
+            .chain(self.count.iter())
+            .map(|a| a.as_ref())
+            .collect()
+    }
+}
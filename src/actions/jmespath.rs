@@ -0,0 +1,78 @@
+use crate::action::Action;
+use crate::context::Context;
+use crate::errors::Error;
+use serde::de::Error as DeError;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+use std::borrow::Cow;
+
+/// This type represents a compiled JMESPath expression, serialized as the expression itself.
+#[derive(Debug)]
+pub struct Expression(jmespath::Expression<'static>);
+
+impl Expression {
+    pub fn new(expression: &str) -> Result<Self, jmespath::JmespathError> {
+        Ok(Self(jmespath::compile(expression)?))
+    }
+}
+
+impl Serialize for Expression {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.0.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Expression {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let expression = String::deserialize(deserializer)?;
+        Expression::new(&expression).map_err(DeError::custom)
+    }
+}
+
+/// This type represents an [Action](../action/trait.Action.html) which searches the Value with a
+/// JMESPath expression eg. `locations[?state=='WA'].name | sort(@)`.
+///
+/// A result of `null`, which JMESPath returns when nothing matches, returns no value.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct JmesPath {
+    expression: Expression,
+    action: Box<dyn Action>,
+}
+
+impl JmesPath {
+    pub fn new(expression: Expression, action: Box<dyn Action>) -> Self {
+        Self { expression, action }
+    }
+}
+
+#[typetag::serde]
+impl Action for JmesPath {
+    fn apply<'a>(
+        &'a self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        match self.action.apply(source, destination, ctx)? {
+            Some(v) => {
+                let result = self.expression.0.search(v.as_ref())?;
+                match serde_json::to_value(&*result)? {
+                    Value::Null => Ok(None),
+                    value => Ok(Some(Cow::Owned(value))),
+                }
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        vec![self.action.as_ref()]
+    }
+}
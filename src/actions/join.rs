@@ -1,4 +1,5 @@
 use crate::action::Action;
+use crate::context::Context;
 use crate::errors::Error;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -9,6 +10,8 @@ use std::ops::Deref;
 /// separated by the provided `sep` and returns a Value::String(String).
 ///
 /// This also works with non-string types but they will be converted into a string prior to joining.
+/// When a value is an Array its elements are joined individually, eg. `join(", ", tags)` turns
+/// `["a","b","c"]` into `"a, b, c"`.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Join {
     sep: String,
@@ -27,38 +30,36 @@ impl Action for Join {
         &self,
         source: &'a Value,
         destination: &mut Value,
+        ctx: &Context,
     ) -> Result<Option<Cow<'a, Value>>, Error> {
-        let l = self.values.len() - 1;
-        let mut result = String::new();
-        for (i, v) in self.values.iter().enumerate() {
-            match v.apply(source, destination)? {
-                Some(v) => {
-                    match v.deref() {
-                        Value::String(s) => {
-                            if s.is_empty() {
-                                continue;
-                            }
-                            result.push_str(s);
-                        }
-                        _ => {
-                            let s = v.to_string();
-                            if s.is_empty() {
-                                continue;
-                            }
-                            result.push_str(&s);
-                        }
-                    };
-                    if i != l {
-                        result.push_str(&self.sep);
-                    }
+        ctx.evaluate()?;
+        let mut parts = Vec::new();
+        for v in self.values.iter() {
+            if let Some(v) = v.apply(source, destination, ctx)? {
+                match v.deref() {
+                    Value::Array(arr) => arr.iter().for_each(|v| push_part(&mut parts, v)),
+                    v => push_part(&mut parts, v),
                 }
-                None => continue,
-            };
+            }
         }
 
-        if result.is_empty() {
+        if parts.is_empty() {
             return Ok(None);
         }
-        Ok(Some(Cow::Owned(Value::String(result))))
+        Ok(Some(Cow::Owned(Value::String(parts.join(&self.sep)))))
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        self.values.iter().map(|a| a.as_ref()).collect()
+    }
+}
+
+fn push_part(parts: &mut Vec<String>, value: &Value) {
+    let s = match value {
+        Value::String(s) => s.clone(),
+        _ => value.to_string(),
+    };
+    if !s.is_empty() {
+        parts.push(s);
     }
 }
@@ -1,20 +1,43 @@
 use crate::action::Action;
+use crate::context::Context;
 use crate::errors::Error;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::borrow::Cow;
 use std::ops::Deref;
+#[cfg(feature = "graphemes")]
+use unicode_segmentation::UnicodeSegmentation;
+
+/// This represents the unit in which the length of a String is measured.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub enum Type {
+    #[default]
+    Bytes,
+    Chars,
+    #[cfg(feature = "graphemes")]
+    Graphemes,
+}
 
 /// This type represents an [Action](../action/trait.Action.html) which returns the length of a
 /// String, Array or Object..
+///
+/// The length of a String is measured in bytes, chars or, with the `graphemes` feature, extended
+/// grapheme clusters; the latter matching the human-visible length of UTF-8 text.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Len {
+    #[serde(default)]
+    r#type: Type,
     action: Box<dyn Action>,
 }
 
 impl Len {
     pub fn new(action: Box<dyn Action>) -> Self {
-        Len { action }
+        Len::with_type(Type::Bytes, action)
+    }
+
+    /// creates a Len measuring the length of Strings in the given unit.
+    pub fn with_type(r#type: Type, action: Box<dyn Action>) -> Self {
+        Len { r#type, action }
     }
 }
 
@@ -24,10 +47,20 @@ impl Action for Len {
         &'a self,
         source: &'a Value,
         destination: &mut Value,
+        ctx: &Context,
     ) -> Result<Option<Cow<'a, Value>>, Error> {
-        match self.action.apply(source, destination)? {
+        ctx.evaluate()?;
+        match self.action.apply(source, destination, ctx)? {
             Some(v) => match v.deref() {
-                Value::String(s) => Ok(Some(Cow::Owned(Value::Number(s.len().into())))),
+                Value::String(s) => {
+                    let len = match self.r#type {
+                        Type::Bytes => s.len(),
+                        Type::Chars => s.chars().count(),
+                        #[cfg(feature = "graphemes")]
+                        Type::Graphemes => s.graphemes(true).count(),
+                    };
+                    Ok(Some(Cow::Owned(Value::Number(len.into()))))
+                }
                 Value::Array(arr) => Ok(Some(Cow::Owned(Value::Number(arr.len().into())))),
                 Value::Object(o) => Ok(Some(Cow::Owned(Value::Number(o.len().into())))),
                 _ => Ok(None),
@@ -35,4 +68,8 @@ impl Action for Len {
             None => Ok(None),
         }
     }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        vec![self.action.as_ref()]
+    }
 }
@@ -0,0 +1,75 @@
+use crate::action::Action;
+use crate::actions::datetime;
+use crate::context::Context;
+use crate::errors::Error;
+use crate::i18n::Locale;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+use std::ops::Deref;
+
+/// This represents the type of Value being formatted
+#[derive(Debug, Serialize, Deserialize)]
+pub enum Type {
+    Number,
+    /// formats a number as an amount of the ISO 4217 currency, eg. `CAD`.
+    Currency(String),
+    /// formats the date of an RFC 3339 string.
+    Date,
+}
+
+/// This type represents an [Action](../action/trait.Action.html) which formats a number, currency
+/// amount or date according to the conventions of a locale, eg. `1,234.5` vs `1.234,5`.
+///
+/// When no locale is supplied the locale of the [Context](../context/struct.Context.html) is used,
+/// see the [i18n](../i18n/index.html) module. No value is returned for input of the wrong type.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Localize {
+    r#type: Type,
+    locale: Option<String>,
+    action: Box<dyn Action>,
+}
+
+impl Localize {
+    pub fn new(r#type: Type, locale: Option<String>, action: Box<dyn Action>) -> Self {
+        Self {
+            r#type,
+            locale,
+            action,
+        }
+    }
+}
+
+#[typetag::serde]
+impl Action for Localize {
+    fn apply<'a>(
+        &'a self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        let tag = match &self.locale {
+            Some(locale) => locale.as_str(),
+            None => ctx.locale(),
+        };
+        let locale = Locale::get(tag).ok_or_else(|| Error::UnknownLocale(tag.to_owned()))?;
+        let formatted = match self.action.apply(source, destination, ctx)? {
+            Some(v) => match (&self.r#type, v.deref()) {
+                (Type::Number, Value::Number(n)) => locale.format_number(n),
+                (Type::Currency(code), Value::Number(n)) => locale.format_currency(code, n),
+                (Type::Date, Value::String(s)) => match datetime::parse_rfc3339(s) {
+                    Some(ts) => locale.format_date(ts),
+                    None => return Ok(None),
+                },
+                _ => return Ok(None),
+            },
+            None => return Ok(None),
+        };
+        Ok(Some(Cow::Owned(Value::String(formatted))))
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        vec![self.action.as_ref()]
+    }
+}
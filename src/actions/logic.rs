@@ -0,0 +1,85 @@
+use crate::action::Action;
+use crate::actions::when::is_truthy;
+use crate::context::Context;
+use crate::errors::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+
+/// This represents the boolean combinator type
+#[derive(Debug, Serialize, Deserialize)]
+pub enum Type {
+    And,
+    Or,
+}
+
+/// This type represents an [Action](../action/trait.Action.html) which combines the truthiness of
+/// its actions, evaluated in order, into a boolean; stopping as soon as the result is known.
+///
+/// A result is falsy when it returns no Value, `null` or `false`; anything else is truthy.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Logic {
+    r#type: Type,
+    actions: Vec<Box<dyn Action>>,
+}
+
+impl Logic {
+    pub fn new(r#type: Type, actions: Vec<Box<dyn Action>>) -> Self {
+        Self { r#type, actions }
+    }
+}
+
+#[typetag::serde]
+impl Action for Logic {
+    fn apply<'a>(
+        &'a self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        let short_circuit = matches!(self.r#type, Type::Or);
+        for a in self.actions.iter() {
+            let v = a.apply(source, destination, ctx)?;
+            if is_truthy(v.as_deref()) == short_circuit {
+                return Ok(Some(Cow::Owned(Value::Bool(short_circuit))));
+            }
+        }
+        Ok(Some(Cow::Owned(Value::Bool(!short_circuit))))
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        self.actions.iter().map(|a| a.as_ref()).collect()
+    }
+}
+
+/// This type represents an [Action](../action/trait.Action.html) which returns the boolean
+/// negation of its action's truthiness.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Not {
+    action: Box<dyn Action>,
+}
+
+impl Not {
+    pub fn new(action: Box<dyn Action>) -> Self {
+        Self { action }
+    }
+}
+
+#[typetag::serde]
+impl Action for Not {
+    fn apply<'a>(
+        &'a self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        let v = self.action.apply(source, destination, ctx)?;
+        Ok(Some(Cow::Owned(Value::Bool(!is_truthy(v.as_deref())))))
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        vec![self.action.as_ref()]
+    }
+}
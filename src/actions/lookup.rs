@@ -0,0 +1,53 @@
+use crate::action::Action;
+use crate::context::Context;
+use crate::errors::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+use std::ops::Deref;
+
+/// This type represents an [Action](../action/trait.Action.html) which looks up the Value, by key,
+/// in a named lookup table registered on the
+/// [TransformBuilder](../transformer/struct.TransformBuilder.html).
+///
+/// String keys are used as is and numbers and booleans by their string form. No value is returned
+/// when the key isn't in the table and an error is returned if the table isn't registered.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Lookup {
+    table: String,
+    action: Box<dyn Action>,
+}
+
+impl Lookup {
+    pub fn new(table: String, action: Box<dyn Action>) -> Self {
+        Self { table, action }
+    }
+}
+
+#[typetag::serde]
+impl Action for Lookup {
+    fn apply<'a>(
+        &'a self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        let table = ctx.lookup_table(&self.table)?;
+        let v = match self.action.apply(source, destination, ctx)? {
+            Some(v) => v,
+            None => return Ok(None),
+        };
+        let value = match v.deref() {
+            Value::String(s) => table.get(s),
+            Value::Number(n) => table.get(&n.to_string()),
+            Value::Bool(b) => table.get(&b.to_string()),
+            _ => None,
+        };
+        Ok(value.map(|v| Cow::Owned(v.clone())))
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        vec![self.action.as_ref()]
+    }
+}
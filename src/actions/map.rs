@@ -0,0 +1,62 @@
+use crate::action::Action;
+use crate::context::Context;
+use crate::errors::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+
+/// This type represents an [Action](../action/trait.Action.html) which applies a sub-transformation
+/// to each element of an Array, producing a new Array.
+///
+/// Each element is the source root of the sub-transformation's actions, which are applied in
+/// order to a new destination for that element, eg. reshaping every address Object.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Map {
+    action: Box<dyn Action>,
+    actions: Vec<Box<dyn Action>>,
+}
+
+impl Map {
+    pub fn new(action: Box<dyn Action>, actions: Vec<Box<dyn Action>>) -> Self {
+        Self { action, actions }
+    }
+}
+
+#[typetag::serde]
+impl Action for Map {
+    fn apply<'a>(
+        &'a self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        let v = match self.action.apply(source, destination, ctx)? {
+            Some(v) => v,
+            None => return Ok(None),
+        };
+        let arr = match v.as_ref() {
+            Value::Array(arr) => arr,
+            _ => return Ok(None),
+        };
+        let mut mapped = Vec::with_capacity(arr.len());
+        for (i, element) in arr.iter().enumerate() {
+            let mut output = Value::Null;
+            ctx.scoped(i, || {
+                for a in self.actions.iter() {
+                    a.apply(element, &mut output, ctx)?;
+                }
+                Ok::<_, Error>(())
+            })?;
+            mapped.push(output);
+        }
+        Ok(Some(Cow::Owned(Value::Array(mapped))))
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        std::iter::once(&self.action)
+            .chain(self.actions.iter())
+            .map(|a| a.as_ref())
+            .collect()
+    }
+}
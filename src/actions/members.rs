@@ -0,0 +1,57 @@
+use crate::action::Action;
+use crate::context::Context;
+use crate::errors::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+use std::ops::Deref;
+
+/// This represents the Object members to return
+#[derive(Debug, Serialize, Deserialize)]
+pub enum Type {
+    Keys,
+    Values,
+}
+
+/// This type represents an [Action](../action/trait.Action.html) which returns the keys or values
+/// of an Object as an Array, eg. for producing index or summary documents or reshaping maps into
+/// Arrays.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Members {
+    r#type: Type,
+    action: Box<dyn Action>,
+}
+
+impl Members {
+    pub fn new(r#type: Type, action: Box<dyn Action>) -> Self {
+        Self { r#type, action }
+    }
+}
+
+#[typetag::serde]
+impl Action for Members {
+    fn apply<'a>(
+        &'a self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        let members = match (self.action.apply(source, destination, ctx)?, &self.r#type) {
+            (Some(v), Type::Keys) => match v.deref() {
+                Value::Object(o) => o.keys().cloned().map(Value::String).collect(),
+                _ => return Ok(None),
+            },
+            (Some(Cow::Owned(Value::Object(o))), Type::Values) => {
+                o.into_iter().map(|(_, v)| v).collect()
+            }
+            (Some(Cow::Borrowed(Value::Object(o))), Type::Values) => o.values().cloned().collect(),
+            _ => return Ok(None),
+        };
+        Ok(Some(Cow::Owned(Value::Array(members))))
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        vec![self.action.as_ref()]
+    }
+}
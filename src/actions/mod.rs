@@ -1,34 +1,278 @@
 //! Actions that impl the [Action](action/trait.Action.html) trait.
 
+mod affix;
+mod aggregate;
+mod arithmetic;
+mod change_case;
+mod chunk;
+mod coalesce;
+#[cfg(feature = "base64")]
+mod codec;
+mod compare;
+mod concat;
 mod constant;
+mod convert;
+mod convert_case;
+mod date_add;
+pub(crate) mod datetime;
+mod diff;
+mod element;
+mod enumerate;
+mod env;
+mod epoch;
+mod exists;
+mod filter;
+mod flatten;
+mod flatten_keys;
+mod format;
 pub mod getter;
+mod group_by;
+#[cfg(feature = "hash")]
+mod hash;
+mod if_else;
+mod index;
+mod index_of;
+#[cfg(feature = "inflector")]
+mod inflect;
+#[cfg(feature = "jmespath")]
+mod jmespath;
 mod join;
 mod len;
+#[cfg(feature = "i18n")]
+mod localize;
+mod logic;
+mod lookup;
+mod map;
+mod members;
+mod now;
+mod number;
+mod pad;
+mod pattern;
+mod prune;
+#[cfg(feature = "rand")]
+mod random;
+mod reduce;
+mod regex_extract;
+mod regex_replace;
+mod rename_keys;
+mod replace;
+mod reverse;
+mod round;
+mod select_keys;
+mod seq;
 pub mod setter;
+mod slice;
+mod sort;
 mod strip;
+mod substring;
 mod sum;
+mod switch;
 mod trim;
+mod truncate;
+mod unique;
+#[cfg(feature = "uuid")]
+mod uuid_v4;
+mod var;
+mod when;
+
+#[doc(inline)]
+pub use affix::{Affix, Type as AffixType};
+
+#[doc(inline)]
+pub use aggregate::{Aggregate, Type as AggregateType};
+
+#[doc(inline)]
+pub use arithmetic::{Arithmetic, Type as ArithmeticType};
+
+#[doc(inline)]
+pub use change_case::{ChangeCase, Type as ChangeCaseType};
+
+#[cfg(feature = "base64")]
+#[doc(inline)]
+pub use codec::{Base64, Type as Base64Type};
+
+#[doc(inline)]
+pub use chunk::Chunk;
+
+#[doc(inline)]
+pub use coalesce::Coalesce;
+
+#[doc(inline)]
+pub use compare::{Compare, Type as CompareType};
+
+#[doc(inline)]
+pub use concat::Concat;
 
 #[doc(inline)]
 pub use constant::Constant;
 
+#[doc(inline)]
+pub use convert::{Convert, Type as ConvertType};
+
+#[doc(inline)]
+pub use convert_case::{ConvertCase, ConvertKeys, Type as ConvertCaseType};
+
+#[doc(inline)]
+pub use date_add::{parse_duration, DateAdd};
+
+#[doc(inline)]
+pub use diff::Diff;
+
+#[doc(inline)]
+pub use element::{Element, Type as ElementType};
+
+#[doc(inline)]
+pub use enumerate::Enumerate;
+
+#[doc(inline)]
+pub use env::Env;
+
+#[doc(inline)]
+pub use epoch::{Epoch, Type as EpochType, Unit as EpochUnit};
+
+#[doc(inline)]
+pub use exists::Exists;
+
+#[doc(inline)]
+pub use filter::Filter;
+
+#[doc(inline)]
+pub use flatten::Flatten;
+
+#[doc(inline)]
+pub use flatten_keys::{FlattenKeys, Type as FlattenKeysType};
+
+#[doc(inline)]
+pub use format::{parse_template, Format, Segment as FormatSegment};
+
 #[doc(inline)]
 pub use getter::Getter;
 
+#[doc(inline)]
+pub use group_by::GroupBy;
+
+#[cfg(feature = "hash")]
+#[doc(inline)]
+pub use hash::{Digest, Hmac, Type as DigestType};
+
+#[cfg(feature = "inflector")]
+#[doc(inline)]
+pub use inflect::{Inflect, Type as InflectType};
+
+#[doc(inline)]
+pub use if_else::If;
+
+#[doc(inline)]
+pub use index::Index;
+
+#[doc(inline)]
+pub use index_of::IndexOf;
+
 #[doc(inline)]
 pub use join::Join;
 
+#[cfg(feature = "jmespath")]
+#[doc(inline)]
+pub use self::jmespath::{Expression as JmesPathExpression, JmesPath};
+
+#[doc(inline)]
+pub use len::{Len, Type as LenType};
+
+#[cfg(feature = "i18n")]
+#[doc(inline)]
+pub use localize::{Localize, Type as LocalizeType};
+
+#[doc(inline)]
+pub use logic::{Logic, Not, Type as LogicType};
+
+#[doc(inline)]
+pub use lookup::Lookup;
+
+#[doc(inline)]
+pub use map::Map;
+
+#[doc(inline)]
+pub use members::{Members, Type as MembersType};
+
+#[doc(inline)]
+pub use now::{Format as TimeFormat, Now};
+
+#[doc(inline)]
+pub use pad::{Pad, Type as PadType};
+
+#[doc(inline)]
+pub use pattern::Pattern;
+
+#[doc(inline)]
+pub use prune::{Options as PruneOptions, Prune};
+
+#[cfg(feature = "rand")]
+#[doc(inline)]
+pub use random::{RandomChoice, RandomInt};
+
+#[doc(inline)]
+pub use reduce::Reduce;
+
+#[doc(inline)]
+pub use regex_extract::{Group as RegexGroup, RegexExtract};
+
+#[doc(inline)]
+pub use regex_replace::RegexReplace;
+
 #[doc(inline)]
-pub use len::Len;
+pub use rename_keys::RenameKeys;
+
+#[doc(inline)]
+pub use replace::Replace;
+
+#[doc(inline)]
+pub use reverse::Reverse;
+
+#[doc(inline)]
+pub use round::{Round, Type as RoundType};
+
+#[doc(inline)]
+pub use select_keys::{SelectKeys, Type as SelectKeysType};
+
+#[doc(inline)]
+pub use seq::Seq;
+
+#[doc(inline)]
+pub use slice::Slice;
+
+#[doc(inline)]
+pub use sort::{Order as SortOrder, Sort};
+
+#[doc(inline)]
+pub use substring::Substring;
 
 #[doc(inline)]
 pub use sum::Sum;
 
+#[doc(inline)]
+pub use switch::{Case, Switch};
+
 #[doc(inline)]
 pub use trim::{Trim, Type as TrimType};
 
+#[doc(inline)]
+pub use truncate::Truncate;
+
+#[doc(inline)]
+pub use unique::Unique;
+
+#[cfg(feature = "uuid")]
+#[doc(inline)]
+pub use uuid_v4::Uuid;
+
 #[doc(inline)]
 pub use strip::{Strip, Type as StripType};
 
 #[doc(inline)]
-pub use setter::Setter;
+pub use setter::{MultiSetter, Setter};
+
+#[doc(inline)]
+pub use var::Var;
+
+#[doc(inline)]
+pub use when::When;
@@ -0,0 +1,51 @@
+use crate::action::Action;
+use crate::actions::datetime::{self, Timestamp};
+use crate::context::Context;
+use crate::errors::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+
+/// This represents the format of a timestamp
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum Format {
+    /// an RFC 3339 UTC string eg. `2020-01-02T03:04:05.678Z`.
+    Rfc3339,
+    /// the number of milliseconds since the Unix epoch.
+    UnixMs,
+    /// the number of seconds since the Unix epoch.
+    Unix,
+}
+
+/// This type represents an [Action](../action/trait.Action.html) which returns the current time,
+/// as determined by the [Clock](../context/trait.Clock.html), eg. for stamping documents with an
+/// ingestion time.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Now {
+    format: Format,
+}
+
+impl Now {
+    pub const fn new(format: Format) -> Self {
+        Self { format }
+    }
+}
+
+#[typetag::serde]
+impl Action for Now {
+    fn apply<'a>(
+        &'a self,
+        _source: &'a Value,
+        _destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        let now = Timestamp::from(ctx.clock().now());
+        let value = match self.format {
+            Format::Rfc3339 => Value::String(datetime::format_rfc3339(now)),
+            Format::UnixMs => Value::Number(now.as_millis().into()),
+            Format::Unix => Value::Number(now.secs.into()),
+        };
+        Ok(Some(Cow::Owned(value)))
+    }
+}
@@ -0,0 +1,348 @@
+//! Numeric helpers shared by arithmetic Actions.
+//!
+//! Integers are kept as integers for as long as they fit, falling back to f64. When the
+//! `arbitrary_precision` feature is enabled all arithmetic is instead exact, preserving big
+//! integers and decimals end-to-end.
+
+pub(crate) use crate::actions::round::Type as Rounding;
+use serde_json::{Number, Value};
+use std::cmp::Ordering;
+
+/// returns the Number, or the Numbers within an Array, ignoring all other values.
+pub(crate) fn numbers(value: &Value) -> Box<dyn Iterator<Item = &Number> + '_> {
+    match value {
+        Value::Number(n) => Box::new(std::iter::once(n)),
+        Value::Array(arr) => Box::new(arr.iter().filter_map(|v| match v {
+            Value::Number(n) => Some(n),
+            _ => None,
+        })),
+        _ => Box::new(std::iter::empty()),
+    }
+}
+
+/// compares two Numbers, returning None if they cannot be compared.
+#[cfg(not(feature = "arbitrary_precision"))]
+pub(crate) fn cmp(a: &Number, b: &Number) -> Option<Ordering> {
+    if let (Some(a), Some(b)) = (a.as_i64(), b.as_i64()) {
+        return Some(a.cmp(&b));
+    }
+    if let (Some(a), Some(b)) = (a.as_u64(), b.as_u64()) {
+        return Some(a.cmp(&b));
+    }
+    a.as_f64()?.partial_cmp(&b.as_f64()?)
+}
+
+/// compares two Numbers exactly.
+#[cfg(feature = "arbitrary_precision")]
+pub(crate) fn cmp(a: &Number, b: &Number) -> Option<Ordering> {
+    Some(to_decimal(a)?.cmp(&to_decimal(b)?))
+}
+
+/// adds two Numbers, returning None if the result cannot be represented.
+#[cfg(not(feature = "arbitrary_precision"))]
+pub(crate) fn add(a: &Number, b: &Number) -> Option<Number> {
+    if let (Some(a), Some(b)) = (a.as_i64(), b.as_i64()) {
+        if let Some(n) = a.checked_add(b) {
+            return Some(n.into());
+        }
+    }
+    if let (Some(a), Some(b)) = (a.as_u64(), b.as_u64()) {
+        if let Some(n) = a.checked_add(b) {
+            return Some(n.into());
+        }
+    }
+    Number::from_f64(a.as_f64()? + b.as_f64()?)
+}
+
+/// adds two Numbers exactly.
+#[cfg(feature = "arbitrary_precision")]
+pub(crate) fn add(a: &Number, b: &Number) -> Option<Number> {
+    from_decimal(to_decimal(a)? + to_decimal(b)?)
+}
+
+/// subtracts the second Number from the first, returning None if the result cannot be
+/// represented.
+#[cfg(not(feature = "arbitrary_precision"))]
+pub(crate) fn sub(a: &Number, b: &Number) -> Option<Number> {
+    if let (Some(a), Some(b)) = (a.as_i64(), b.as_i64()) {
+        if let Some(n) = a.checked_sub(b) {
+            return Some(n.into());
+        }
+    }
+    if let (Some(a), Some(b)) = (a.as_u64(), b.as_u64()) {
+        if let Some(n) = a.checked_sub(b) {
+            return Some(n.into());
+        }
+    }
+    Number::from_f64(a.as_f64()? - b.as_f64()?)
+}
+
+/// multiplies two Numbers, returning None if the result cannot be represented.
+#[cfg(not(feature = "arbitrary_precision"))]
+pub(crate) fn mul(a: &Number, b: &Number) -> Option<Number> {
+    if let (Some(a), Some(b)) = (a.as_i64(), b.as_i64()) {
+        if let Some(n) = a.checked_mul(b) {
+            return Some(n.into());
+        }
+    }
+    if let (Some(a), Some(b)) = (a.as_u64(), b.as_u64()) {
+        if let Some(n) = a.checked_mul(b) {
+            return Some(n.into());
+        }
+    }
+    Number::from_f64(a.as_f64()? * b.as_f64()?)
+}
+
+/// divides the first Number by the second, returning None when dividing by zero or if the result
+/// cannot be represented.
+///
+/// Integers that divide exactly remain integers.
+#[cfg(not(feature = "arbitrary_precision"))]
+pub(crate) fn div(a: &Number, b: &Number) -> Option<Number> {
+    if let (Some(a), Some(b)) = (a.as_i64(), b.as_i64()) {
+        if b == 0 {
+            return None;
+        }
+        if a.checked_rem(b) == Some(0) {
+            return a.checked_div(b).map(Number::from);
+        }
+    }
+    if let (Some(a), Some(b)) = (a.as_u64(), b.as_u64()) {
+        if b == 0 {
+            return None;
+        }
+        if a % b == 0 {
+            return Some((a / b).into());
+        }
+    }
+    let b = b.as_f64()?;
+    if b == 0.0 {
+        return None;
+    }
+    Number::from_f64(a.as_f64()? / b)
+}
+
+/// returns the remainder of dividing the first Number by the second, returning None when dividing
+/// by zero or if the result cannot be represented.
+#[cfg(not(feature = "arbitrary_precision"))]
+pub(crate) fn rem(a: &Number, b: &Number) -> Option<Number> {
+    if let (Some(a), Some(b)) = (a.as_i64(), b.as_i64()) {
+        if b == 0 {
+            return None;
+        }
+        if let Some(n) = a.checked_rem(b) {
+            return Some(n.into());
+        }
+    }
+    if let (Some(a), Some(b)) = (a.as_u64(), b.as_u64()) {
+        return a.checked_rem(b).map(Number::from);
+    }
+    let b = b.as_f64()?;
+    if b == 0.0 {
+        return None;
+    }
+    Number::from_f64(a.as_f64()? % b)
+}
+
+/// subtracts the second Number from the first exactly.
+#[cfg(feature = "arbitrary_precision")]
+pub(crate) fn sub(a: &Number, b: &Number) -> Option<Number> {
+    from_decimal(to_decimal(a)? - to_decimal(b)?)
+}
+
+/// multiplies two Numbers exactly.
+#[cfg(feature = "arbitrary_precision")]
+pub(crate) fn mul(a: &Number, b: &Number) -> Option<Number> {
+    from_decimal(to_decimal(a)? * to_decimal(b)?)
+}
+
+/// divides the first Number by the second, returning None when dividing by zero.
+///
+/// Results which do not terminate are rounded to 100 significant digits.
+#[cfg(feature = "arbitrary_precision")]
+pub(crate) fn div(a: &Number, b: &Number) -> Option<Number> {
+    let b = to_decimal(b)?;
+    if bigdecimal::Zero::is_zero(&b) {
+        return None;
+    }
+    from_decimal((to_decimal(a)? / b).normalized())
+}
+
+/// returns the remainder of dividing the first Number by the second exactly, returning None when
+/// dividing by zero.
+#[cfg(feature = "arbitrary_precision")]
+pub(crate) fn rem(a: &Number, b: &Number) -> Option<Number> {
+    let b = to_decimal(b)?;
+    if bigdecimal::Zero::is_zero(&b) {
+        return None;
+    }
+    from_decimal(to_decimal(a)? % b)
+}
+
+/// rounds the Number to the given number of decimal places, returning None if the result cannot
+/// be represented.
+///
+/// Integers are returned unchanged and results rounded to 0 decimal places are integers.
+#[cfg(not(feature = "arbitrary_precision"))]
+pub(crate) fn round(n: &Number, precision: u32, rounding: Rounding) -> Option<Number> {
+    if n.is_i64() || n.is_u64() {
+        return Some(n.clone());
+    }
+    // an f64 has at most 15 significant decimal digits, so higher precisions cannot change it.
+    let precision = precision.min(crate::actions::Round::MAX_PRECISION);
+    let factor = 10_f64.powi(precision as i32);
+    let f = n.as_f64()?;
+    let scaled = f * factor;
+    // values of at least 2^53 are already integral and may overflow when scaled.
+    if !scaled.is_finite() || f.abs() >= 9_007_199_254_740_992.0 {
+        return Some(n.clone());
+    }
+    let rounded = match rounding {
+        Rounding::Round => scaled.round(),
+        Rounding::Floor => scaled.floor(),
+        Rounding::Ceil => scaled.ceil(),
+    };
+    if precision == 0 && rounded >= i64::MIN as f64 && rounded < i64::MAX as f64 {
+        return Some((rounded as i64).into());
+    }
+    Number::from_f64(rounded / factor)
+}
+
+/// rounds the Number exactly to the given number of decimal places.
+#[cfg(feature = "arbitrary_precision")]
+pub(crate) fn round(n: &Number, precision: u32, rounding: Rounding) -> Option<Number> {
+    use bigdecimal::RoundingMode;
+
+    let d = to_decimal(n)?;
+    if d.fractional_digit_count() <= precision as i64 {
+        return Some(n.clone());
+    }
+    let mode = match rounding {
+        Rounding::Round => RoundingMode::HalfUp,
+        Rounding::Floor => RoundingMode::Floor,
+        Rounding::Ceil => RoundingMode::Ceiling,
+    };
+    from_decimal(d.with_scale_round(precision as i64, mode))
+}
+
+#[cfg(feature = "arbitrary_precision")]
+fn to_decimal(n: &Number) -> Option<bigdecimal::BigDecimal> {
+    // with arbitrary_precision the Number retains its exact original representation.
+    n.to_string().parse().ok()
+}
+
+#[cfg(feature = "arbitrary_precision")]
+fn from_decimal(d: bigdecimal::BigDecimal) -> Option<Number> {
+    d.to_string().parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn num(s: &str) -> Number {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn add_integers() {
+        assert_eq!(Some(num("3")), add(&num("1"), &num("2")));
+        assert_eq!(Some(num("-1")), add(&num("1"), &num("-2")));
+        assert_eq!(
+            json!(9007199254740993_u64),
+            json!(add(&num("9007199254740992"), &num("1")))
+        );
+    }
+
+    #[cfg(not(feature = "arbitrary_precision"))]
+    #[test]
+    fn add_floats() {
+        assert_eq!(Some(num("3.5")), add(&num("1.5"), &num("2")));
+        assert_eq!(
+            Some(num("18446744073709551616.0")),
+            add(&num("18446744073709551615"), &num("1"))
+        );
+    }
+
+    #[test]
+    fn arithmetic_integers() {
+        assert_eq!(Some(num("-1")), sub(&num("1"), &num("2")));
+        assert_eq!(Some(num("6")), mul(&num("2"), &num("3")));
+        assert_eq!(Some(num("3")), div(&num("6"), &num("2")));
+        assert_eq!(Some(num("1")), rem(&num("7"), &num("3")));
+        assert_eq!(None, div(&num("1"), &num("0")));
+        assert_eq!(None, rem(&num("1"), &num("0")));
+    }
+
+    #[cfg(not(feature = "arbitrary_precision"))]
+    #[test]
+    fn arithmetic_floats() {
+        assert_eq!(Some(num("2.5")), div(&num("5"), &num("2")));
+        assert_eq!(Some(num("0.5")), rem(&num("2.5"), &num("1")));
+        assert_eq!(Some(num("3.0")), mul(&num("1.5"), &num("2")));
+        assert_eq!(None, div(&num("1.5"), &num("0.0")));
+    }
+
+    #[test]
+    fn comparison() {
+        assert_eq!(Some(Ordering::Less), cmp(&num("-1"), &num("2")));
+        assert_eq!(Some(Ordering::Greater), cmp(&num("2.5"), &num("2")));
+        assert_eq!(
+            Some(Ordering::Greater),
+            cmp(&num("18446744073709551615"), &num("-1"))
+        );
+        assert_eq!(Some(Ordering::Equal), cmp(&num("3"), &num("3")));
+    }
+
+    #[test]
+    fn rounding() {
+        assert_eq!(Some(num("3")), round(&num("2.5"), 0, Rounding::Round));
+        assert_eq!(Some(num("-3")), round(&num("-2.5"), 0, Rounding::Round));
+        assert_eq!(Some(num("2")), round(&num("2.9"), 0, Rounding::Floor));
+        assert_eq!(Some(num("-2")), round(&num("-2.9"), 0, Rounding::Ceil));
+        assert_eq!(Some(num("7")), round(&num("7"), 2, Rounding::Round));
+        assert_eq!(
+            Some(num("0.1")),
+            round(&num("0.1"), u32::MAX, Rounding::Round)
+        );
+        assert_eq!(Some(num("1e308")), round(&num("1e308"), 2, Rounding::Round));
+        assert_eq!(
+            Some(num("1.7976931348623157e308")),
+            round(&num("1.7976931348623157e308"), 3, Rounding::Ceil)
+        );
+        assert_eq!(
+            "3.14",
+            round(&num("3.14159"), 2, Rounding::Round)
+                .unwrap()
+                .to_string()
+        );
+        assert_eq!(
+            "3.15",
+            round(&num("3.14159"), 2, Rounding::Ceil)
+                .unwrap()
+                .to_string()
+        );
+    }
+
+    #[cfg(feature = "arbitrary_precision")]
+    #[test]
+    fn arithmetic_exact() {
+        assert_eq!("2.5", div(&num("5"), &num("2")).unwrap().to_string());
+        assert_eq!("0.1", sub(&num("0.3"), &num("0.2")).unwrap().to_string());
+        assert_eq!("0.06", mul(&num("0.2"), &num("0.3")).unwrap().to_string());
+        assert_eq!("0.5", rem(&num("2.5"), &num("1")).unwrap().to_string());
+    }
+
+    #[cfg(feature = "arbitrary_precision")]
+    #[test]
+    fn add_exact() {
+        assert_eq!("0.3", add(&num("0.1"), &num("0.2")).unwrap().to_string());
+        assert_eq!(
+            "100000000000000000000000000000001",
+            add(&num("100000000000000000000000000000000"), &num("1"))
+                .unwrap()
+                .to_string()
+        );
+    }
+}
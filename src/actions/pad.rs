@@ -0,0 +1,79 @@
+use crate::action::Action;
+use crate::context::Context;
+use crate::errors::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+use std::ops::Deref;
+
+/// This represents the padding operation type
+#[derive(Debug, Serialize, Deserialize)]
+pub enum Type {
+    Start,
+    End,
+}
+
+/// This type represents an [Action](../action/trait.Action.html) which pads the start or end of a
+/// string, or number, to a fixed width by repeating the padding string.
+///
+/// The width is by character and values already at or beyond the width are returned as is. The
+/// number of characters added is limited by the
+/// [Limits](../context/struct.Limits.html#structfield.max_string_padding).
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Pad {
+    r#type: Type,
+    width: usize,
+    padding: String,
+    action: Box<dyn Action>,
+}
+
+impl Pad {
+    pub fn new(r#type: Type, width: usize, padding: String, action: Box<dyn Action>) -> Self {
+        Self {
+            r#type,
+            width,
+            padding,
+            action,
+        }
+    }
+}
+
+#[typetag::serde]
+impl Action for Pad {
+    fn apply<'a>(
+        &'a self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        let s = match self.action.apply(source, destination, ctx)? {
+            Some(v) => match v.deref() {
+                Value::String(s) => s.clone(),
+                Value::Number(n) => n.to_string(),
+                _ => return Ok(None),
+            },
+            None => return Ok(None),
+        };
+        let len = s.chars().count();
+        if len >= self.width || self.padding.is_empty() {
+            return Ok(Some(Cow::Owned(Value::String(s))));
+        }
+        ctx.pad_chars(self.width - len)?;
+        let padding: String = self
+            .padding
+            .chars()
+            .cycle()
+            .take(self.width - len)
+            .collect();
+        let s = match self.r#type {
+            Type::Start => padding + &s,
+            Type::End => s + &padding,
+        };
+        Ok(Some(Cow::Owned(Value::String(s))))
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        vec![self.action.as_ref()]
+    }
+}
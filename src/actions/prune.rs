@@ -0,0 +1,94 @@
+use crate::action::Action;
+use crate::context::Context;
+use crate::errors::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+
+/// This type represents which Values are considered empty and removed by the
+/// [Prune](struct.Prune.html) Action. By default all are removed.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Options {
+    pub nulls: bool,
+    pub empty_strings: bool,
+    pub empty_arrays: bool,
+    pub empty_objects: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            nulls: true,
+            empty_strings: true,
+            empty_arrays: true,
+            empty_objects: true,
+        }
+    }
+}
+
+impl Options {
+    #[inline]
+    fn is_empty(&self, value: &Value) -> bool {
+        match value {
+            Value::Null => self.nulls,
+            Value::String(s) => self.empty_strings && s.is_empty(),
+            Value::Array(arr) => self.empty_arrays && arr.is_empty(),
+            Value::Object(o) => self.empty_objects && o.is_empty(),
+            _ => false,
+        }
+    }
+
+    /// recursively removes the empty Values, returning if the Value itself is now empty.
+    fn prune(&self, value: &mut Value) -> bool {
+        match value {
+            Value::Array(arr) => arr.retain_mut(|v| !self.prune(v)),
+            Value::Object(o) => o.retain(|_, v| !self.prune(v)),
+            _ => {}
+        }
+        self.is_empty(value)
+    }
+}
+
+/// This type represents an [Action](../action/trait.Action.html) which recursively removes empty
+/// Values, such as `null`, from Objects and Arrays so the output doesn't carry noise fields.
+///
+/// Objects and Arrays which become empty are themselves removed and no Value is returned when the
+/// result is empty.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Prune {
+    options: Options,
+    action: Box<dyn Action>,
+}
+
+impl Prune {
+    pub fn new(options: Options, action: Box<dyn Action>) -> Self {
+        Self { options, action }
+    }
+}
+
+#[typetag::serde]
+impl Action for Prune {
+    fn apply<'a>(
+        &'a self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        match self.action.apply(source, destination, ctx)? {
+            Some(v) => {
+                let mut v = v.into_owned();
+                if self.options.prune(&mut v) {
+                    Ok(None)
+                } else {
+                    Ok(Some(Cow::Owned(v)))
+                }
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        vec![self.action.as_ref()]
+    }
+}
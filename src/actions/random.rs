@@ -0,0 +1,103 @@
+use crate::action::Action;
+use crate::context::{Context, RandomSource};
+use crate::errors::Error;
+use rand::seq::SliceRandom;
+use rand::{Rng, RngCore};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+
+/// adapts the injected [RandomSource](../context/trait.RandomSource.html) for use with `rand` so
+/// the output can be made deterministic.
+struct Source<'a>(&'a dyn RandomSource);
+
+impl RngCore for Source<'_> {
+    fn next_u32(&mut self) -> u32 {
+        (self.0.next_u64() >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            let bytes = self.0.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+/// This type represents an [Action](../action/trait.Action.html) which returns a random integer
+/// between the minimum and maximum, inclusive.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RandomInt {
+    min: i64,
+    max: i64,
+}
+
+impl RandomInt {
+    pub const fn new(min: i64, max: i64) -> Self {
+        Self { min, max }
+    }
+}
+
+#[typetag::serde]
+impl Action for RandomInt {
+    fn apply<'a>(
+        &'a self,
+        _source: &'a Value,
+        _destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        if self.min > self.max {
+            return Ok(None);
+        }
+        let n = Source(ctx.random()).gen_range(self.min..=self.max);
+        Ok(Some(Cow::Owned(n.into())))
+    }
+}
+
+/// This type represents an [Action](../action/trait.Action.html) which returns a random element
+/// of an Array.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RandomChoice {
+    action: Box<dyn Action>,
+}
+
+impl RandomChoice {
+    pub fn new(action: Box<dyn Action>) -> Self {
+        Self { action }
+    }
+}
+
+#[typetag::serde]
+impl Action for RandomChoice {
+    fn apply<'a>(
+        &'a self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        match self.action.apply(source, destination, ctx)? {
+            Some(Cow::Borrowed(Value::Array(arr))) => {
+                Ok(arr.choose(&mut Source(ctx.random())).map(Cow::Borrowed))
+            }
+            Some(Cow::Owned(Value::Array(arr))) => Ok(arr
+                .choose(&mut Source(ctx.random()))
+                .map(|v| Cow::Owned(v.clone()))),
+            _ => Ok(None),
+        }
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        vec![self.action.as_ref()]
+    }
+}
@@ -0,0 +1,78 @@
+use crate::action::Action;
+use crate::context::Context;
+use crate::errors::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::borrow::Cow;
+
+const ACCUMULATOR: &str = "$acc";
+const ITEM: &str = "$item";
+
+/// This type represents an [Action](../action/trait.Action.html) which folds the elements of an
+/// Array into a single Value, eg. for running totals or building concatenated keys.
+///
+/// The expression is evaluated for each element with a source root of an Object containing the
+/// accumulated Value as `$acc` and the element as `$item`, starting with the initial Value, and
+/// its result becomes the next accumulated Value; no Value accumulates as `null`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Reduce {
+    action: Box<dyn Action>,
+    expression: Box<dyn Action>,
+    initial: Box<dyn Action>,
+}
+
+impl Reduce {
+    pub fn new(
+        action: Box<dyn Action>,
+        expression: Box<dyn Action>,
+        initial: Box<dyn Action>,
+    ) -> Self {
+        Self {
+            action,
+            expression,
+            initial,
+        }
+    }
+}
+
+#[typetag::serde]
+impl Action for Reduce {
+    fn apply<'a>(
+        &'a self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        let arr = match self.action.apply(source, destination, ctx)? {
+            Some(Cow::Owned(Value::Array(arr))) => arr,
+            Some(Cow::Borrowed(Value::Array(arr))) => arr.clone(),
+            _ => return Ok(None),
+        };
+        let mut acc = self
+            .initial
+            .apply(source, destination, ctx)?
+            .map_or(Value::Null, Cow::into_owned);
+        for (i, item) in arr.into_iter().enumerate() {
+            let mut scope = Map::new();
+            scope.insert(ACCUMULATOR.to_owned(), acc);
+            scope.insert(ITEM.to_owned(), item);
+            let scope = Value::Object(scope);
+            acc = ctx.scoped(i, || {
+                let mut destination = Value::Null;
+                self.expression
+                    .apply(&scope, &mut destination, ctx)
+                    .map(|v| v.map_or(Value::Null, Cow::into_owned))
+            })?;
+        }
+        Ok(Some(Cow::Owned(acc)))
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        vec![
+            self.action.as_ref(),
+            self.expression.as_ref(),
+            self.initial.as_ref(),
+        ]
+    }
+}
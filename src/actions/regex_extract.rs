@@ -0,0 +1,72 @@
+use crate::action::Action;
+use crate::actions::Pattern;
+use crate::context::Context;
+use crate::errors::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+use std::ops::Deref;
+
+/// This represents the capture group to extract from a regular expression match.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Group {
+    Index(usize),
+    Name(String),
+}
+
+impl Default for Group {
+    fn default() -> Self {
+        Group::Index(0)
+    }
+}
+
+/// This type represents an [Action](../action/trait.Action.html) which extracts the first match of
+/// a regular expression, or one of its capture groups, from a string.
+///
+/// If the expression or capture group does not match then no value is returned.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RegexExtract {
+    pattern: Pattern,
+    group: Group,
+    action: Box<dyn Action>,
+}
+
+impl RegexExtract {
+    pub fn new(pattern: Pattern, group: Group, action: Box<dyn Action>) -> Self {
+        Self {
+            pattern,
+            group,
+            action,
+        }
+    }
+}
+
+#[typetag::serde]
+impl Action for RegexExtract {
+    fn apply<'a>(
+        &'a self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        match self.action.apply(source, destination, ctx)? {
+            Some(v) => match v.deref() {
+                Value::String(s) => {
+                    let m = self.pattern.captures(s).and_then(|caps| match &self.group {
+                        Group::Index(i) => caps.get(*i),
+                        Group::Name(name) => caps.name(name),
+                    });
+                    Ok(m.map(|m| Cow::Owned(Value::String(m.as_str().to_owned()))))
+                }
+                _ => Ok(None),
+            },
+            None => Ok(None),
+        }
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        vec![self.action.as_ref()]
+    }
+}
@@ -0,0 +1,56 @@
+use crate::action::Action;
+use crate::actions::Pattern;
+use crate::context::Context;
+use crate::errors::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+use std::ops::Deref;
+
+/// This type represents an [Action](../action/trait.Action.html) which replaces all matches of a
+/// regular expression within a string.
+///
+/// The replacement may reference capture groups by index or name eg. `$1` or `${name}`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RegexReplace {
+    pattern: Pattern,
+    replacement: String,
+    action: Box<dyn Action>,
+}
+
+impl RegexReplace {
+    pub fn new(pattern: Pattern, replacement: String, action: Box<dyn Action>) -> Self {
+        Self {
+            pattern,
+            replacement,
+            action,
+        }
+    }
+}
+
+#[typetag::serde]
+impl Action for RegexReplace {
+    fn apply<'a>(
+        &'a self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        match self.action.apply(source, destination, ctx)? {
+            Some(v) => match v.deref() {
+                Value::String(s) => Ok(Some(Cow::Owned(Value::String(
+                    self.pattern
+                        .replace_all(s, self.replacement.as_str())
+                        .into_owned(),
+                )))),
+                _ => Ok(None),
+            },
+            None => Ok(None),
+        }
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        vec![self.action.as_ref()]
+    }
+}
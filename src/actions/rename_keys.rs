@@ -0,0 +1,55 @@
+use crate::action::Action;
+use crate::context::Context;
+use crate::errors::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+/// This type represents an [Action](../action/trait.Action.html) which renames the keys of an
+/// Object using the mapping of old to new key names, keys not in the mapping are kept as is.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RenameKeys {
+    mapping: BTreeMap<String, String>,
+    action: Box<dyn Action>,
+}
+
+impl RenameKeys {
+    pub fn new(mapping: BTreeMap<String, String>, action: Box<dyn Action>) -> Self {
+        Self { mapping, action }
+    }
+
+    #[inline]
+    fn rename(&self, key: &str) -> String {
+        self.mapping
+            .get(key)
+            .map_or_else(|| key.to_owned(), Clone::clone)
+    }
+}
+
+#[typetag::serde]
+impl Action for RenameKeys {
+    fn apply<'a>(
+        &'a self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        let renamed = match self.action.apply(source, destination, ctx)? {
+            Some(Cow::Owned(Value::Object(o))) => o
+                .into_iter()
+                .map(|(k, v)| (self.rename(&k), v))
+                .collect::<Map<String, Value>>(),
+            Some(Cow::Borrowed(Value::Object(o))) => {
+                o.iter().map(|(k, v)| (self.rename(k), v.clone())).collect()
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(Cow::Owned(Value::Object(renamed))))
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        vec![self.action.as_ref()]
+    }
+}
@@ -0,0 +1,47 @@
+use crate::action::Action;
+use crate::context::Context;
+use crate::errors::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+use std::ops::Deref;
+
+/// This type represents an [Action](../action/trait.Action.html) which replaces all occurrences of
+/// a substring within a string.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Replace {
+    from: String,
+    to: String,
+    action: Box<dyn Action>,
+}
+
+impl Replace {
+    pub fn new(from: String, to: String, action: Box<dyn Action>) -> Self {
+        Self { from, to, action }
+    }
+}
+
+#[typetag::serde]
+impl Action for Replace {
+    fn apply<'a>(
+        &'a self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        match self.action.apply(source, destination, ctx)? {
+            Some(v) => match v.deref() {
+                Value::String(s) => Ok(Some(Cow::Owned(Value::String(
+                    s.replace(&self.from, &self.to),
+                )))),
+                _ => Ok(None),
+            },
+            None => Ok(None),
+        }
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        vec![self.action.as_ref()]
+    }
+}
@@ -0,0 +1,50 @@
+use crate::action::Action;
+use crate::context::Context;
+use crate::errors::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+
+/// This type represents an [Action](../action/trait.Action.html) which reverses the order of the
+/// elements of an Array or the characters of a String, eg. for newest-first listings.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Reverse {
+    action: Box<dyn Action>,
+}
+
+impl Reverse {
+    pub fn new(action: Box<dyn Action>) -> Self {
+        Self { action }
+    }
+}
+
+#[typetag::serde]
+impl Action for Reverse {
+    fn apply<'a>(
+        &'a self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        let reversed = match self.action.apply(source, destination, ctx)? {
+            Some(Cow::Owned(Value::Array(mut arr))) => {
+                arr.reverse();
+                Value::Array(arr)
+            }
+            Some(Cow::Borrowed(Value::Array(arr))) => {
+                Value::Array(arr.iter().rev().cloned().collect())
+            }
+            Some(v) => match v.as_ref() {
+                Value::String(s) => Value::String(s.chars().rev().collect()),
+                _ => return Ok(None),
+            },
+            None => return Ok(None),
+        };
+        Ok(Some(Cow::Owned(reversed)))
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        vec![self.action.as_ref()]
+    }
+}
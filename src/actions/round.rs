@@ -0,0 +1,68 @@
+use crate::action::Action;
+use crate::actions::number;
+use crate::context::Context;
+use crate::errors::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+use std::ops::Deref;
+
+/// This represents the rounding operation type
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum Type {
+    /// rounds half-way cases away from zero.
+    Round,
+    /// rounds towards negative infinity.
+    Floor,
+    /// rounds towards positive infinity.
+    Ceil,
+}
+
+/// This type represents an [Action](../action/trait.Action.html) which rounds a number to a number
+/// of decimal places.
+///
+/// Integers are returned unchanged and numbers rounded to 0 decimal places become integers. The
+/// precision is at most [MAX_PRECISION](#associatedconstant.MAX_PRECISION) decimal places.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Round {
+    r#type: Type,
+    precision: u32,
+    action: Box<dyn Action>,
+}
+
+impl Round {
+    /// The maximum number of decimal places a number can be rounded to.
+    pub const MAX_PRECISION: u32 = 15;
+
+    pub fn new(r#type: Type, precision: u32, action: Box<dyn Action>) -> Self {
+        Self {
+            r#type,
+            precision,
+            action,
+        }
+    }
+}
+
+#[typetag::serde]
+impl Action for Round {
+    fn apply<'a>(
+        &'a self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        match self.action.apply(source, destination, ctx)? {
+            Some(v) => match v.deref() {
+                Value::Number(n) => Ok(number::round(n, self.precision, self.r#type)
+                    .map(|n| Cow::Owned(Value::Number(n)))),
+                _ => Ok(None),
+            },
+            None => Ok(None),
+        }
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        vec![self.action.as_ref()]
+    }
+}
@@ -0,0 +1,79 @@
+use crate::action::Action;
+use crate::context::Context;
+use crate::errors::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::borrow::Cow;
+
+/// This represents the key selection type
+#[derive(Debug, Serialize, Deserialize)]
+pub enum Type {
+    Pick,
+    Omit,
+}
+
+/// This type represents an [Action](../action/trait.Action.html) which returns a new Object
+/// containing only, or all but, the listed keys of an Object, eg. for projecting a subset of a
+/// wide source Object or excluding sensitive fields.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SelectKeys {
+    r#type: Type,
+    keys: Vec<String>,
+    action: Box<dyn Action>,
+}
+
+impl SelectKeys {
+    pub fn new(r#type: Type, keys: Vec<String>, action: Box<dyn Action>) -> Self {
+        Self {
+            r#type,
+            keys,
+            action,
+        }
+    }
+}
+
+#[typetag::serde]
+impl Action for SelectKeys {
+    fn apply<'a>(
+        &'a self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        let selected = match self.action.apply(source, destination, ctx)? {
+            Some(Cow::Owned(Value::Object(mut o))) => match self.r#type {
+                Type::Pick => self
+                    .keys
+                    .iter()
+                    .filter_map(|k| o.remove_entry(k))
+                    .collect::<Map<String, Value>>(),
+                Type::Omit => {
+                    for k in self.keys.iter() {
+                        o.remove(k);
+                    }
+                    o
+                }
+            },
+            Some(Cow::Borrowed(Value::Object(o))) => match self.r#type {
+                Type::Pick => self
+                    .keys
+                    .iter()
+                    .filter_map(|k| o.get_key_value(k))
+                    .map(|(k, v)| (k.clone(), v.clone()))
+                    .collect(),
+                Type::Omit => o
+                    .iter()
+                    .filter(|(k, _)| !self.keys.contains(k))
+                    .map(|(k, v)| (k.clone(), v.clone()))
+                    .collect(),
+            },
+            _ => return Ok(None),
+        };
+        Ok(Some(Cow::Owned(Value::Object(selected))))
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        vec![self.action.as_ref()]
+    }
+}
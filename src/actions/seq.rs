@@ -0,0 +1,35 @@
+use crate::action::Action;
+use crate::context::Context;
+use crate::errors::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+
+/// This type represents an [Action](../action/trait.Action.html) which returns the next value, starting
+/// at 1, of the named sequence eg. for assigning row numbers.
+///
+/// Sequences are thread-safe and shared across all applications of the same
+/// [Transformer](../transformer/struct.Transformer.html).
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Seq {
+    name: String,
+}
+
+impl Seq {
+    pub const fn new(name: String) -> Self {
+        Self { name }
+    }
+}
+
+#[typetag::serde]
+impl Action for Seq {
+    fn apply<'a>(
+        &'a self,
+        _source: &'a Value,
+        _destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        Ok(Some(Cow::Owned(ctx.next_sequence(&self.name).into())))
+    }
+}
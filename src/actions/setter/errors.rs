@@ -3,6 +3,19 @@ use thiserror::Error;
 /// This type represents all possible errors that an occur while applying a transformation.
 #[derive(Error, Debug)]
 pub enum Error {
-    #[error("Invalid destination type. {0}")]
-    InvalidDestinationType(String),
+    #[error("Invalid destination type at '{}'. {}", pointer, err)]
+    InvalidDestinationType { err: String, pointer: String },
+
+    #[error(
+        "Source Object is missing the key '{}' required to upsert into the destination Array at '{}'.",
+        key,
+        pointer
+    )]
+    MissingUpsertKey { key: String, pointer: String },
+
+    #[error(
+        "Merge conflict at '{}', the destination already contains a different value.",
+        pointer
+    )]
+    MergeConflict { pointer: String },
 }
@@ -4,8 +4,9 @@ pub mod namespace;
 pub use errors::Error;
 
 use crate::action::Action;
-use crate::actions::setter::namespace::Namespace;
+use crate::actions::setter::namespace::{MergeStrategy, Namespace};
 use crate::actions::setter::Error as SetterError;
+use crate::context::Context;
 use crate::errors::Error as CrateErr;
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
@@ -13,15 +14,31 @@ use std::borrow::Cow;
 
 /// This type represents an [Action](../action/trait.Action.html) which sets data to the
 /// destination JSON Value.
+///
+/// A Setter skipping nulls, or any Setter when enabled in the
+/// [Context](../context/struct.Context.html), leaves the destination unset when its child returns
+/// `null` rather than writing `null`.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Setter {
     namespace: Vec<Namespace>,
     child: Box<dyn Action>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    skip_nulls: bool,
 }
 
 impl Setter {
     pub fn new(namespace: Vec<Namespace>, child: Box<dyn Action>) -> Self {
-        Self { namespace, child }
+        Self {
+            namespace,
+            child,
+            skip_nulls: false,
+        }
+    }
+
+    /// sets whether the destination is left unset when the child returns `null`.
+    pub fn with_skip_nulls(mut self, skip_nulls: bool) -> Self {
+        self.skip_nulls = skip_nulls;
+        self
     }
 }
 
@@ -31,159 +48,674 @@ impl Action for Setter {
         &self,
         source: &'a Value,
         destination: &mut Value,
+        ctx: &Context,
     ) -> Result<Option<Cow<'a, Value>>, CrateErr> {
-        if let Some(field) = self.child.apply(source, destination)? {
-            let field = field.into_owned();
-            let mut current = destination;
-            for ns in &self.namespace {
-                match ns {
-                    Namespace::Object { id } => {
-                        match current {
-                            Value::Object(o) => {
-                                current = o.entry(id.clone()).or_insert(Value::Null);
-                            }
-                            Value::Null => {
-                                let mut o = Map::new();
-                                o.insert(id.clone(), Value::Null);
-                                *current = Value::Object(o);
-                                current = current.as_object_mut().unwrap().get_mut(id).unwrap();
-                            }
-                            _ => {
-                                return Err(SetterError::InvalidDestinationType(format!(
-                                    "Attempting to set an Object by id to an {:?}",
-                                    current
-                                ))
-                                .into())
-                            }
-                        };
+        ctx.evaluate()?;
+        ctx.traverse(self.namespace.len())?;
+
+        let field = self.child.apply(source, destination, ctx)?;
+        if let Some(field) = missing_or(field, &self.namespace, self.skip_nulls, ctx) {
+            write(&self.namespace, destination, field.into_owned(), ctx)?;
+        }
+        Ok(None)
+    }
+
+    fn destination_paths(&self) -> Vec<String> {
+        vec![Namespace::join(&self.namespace)]
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        vec![self.child.as_ref()]
+    }
+}
+
+/// This type represents an [Action](../action/trait.Action.html) which sets the result of a single
+/// evaluation of its child to multiple locations within the destination JSON Value.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MultiSetter {
+    namespaces: Vec<Vec<Namespace>>,
+    child: Box<dyn Action>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    skip_nulls: bool,
+}
+
+impl MultiSetter {
+    pub fn new(namespaces: Vec<Vec<Namespace>>, child: Box<dyn Action>) -> Self {
+        Self {
+            namespaces,
+            child,
+            skip_nulls: false,
+        }
+    }
+
+    /// sets whether the destinations are left unset when the child returns `null`.
+    pub fn with_skip_nulls(mut self, skip_nulls: bool) -> Self {
+        self.skip_nulls = skip_nulls;
+        self
+    }
+}
+
+#[typetag::serde]
+impl Action for MultiSetter {
+    fn apply<'a>(
+        &self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, CrateErr> {
+        ctx.evaluate()?;
+        for namespace in &self.namespaces {
+            ctx.traverse(namespace.len())?;
+        }
+
+        let field = self.child.apply(source, destination, ctx)?;
+        if let Some((last, namespaces)) = self.namespaces.split_last() {
+            for namespace in namespaces {
+                if let Some(field) = missing_or(field.clone(), namespace, self.skip_nulls, ctx) {
+                    write(namespace, destination, field.into_owned(), ctx)?;
+                }
+            }
+            if let Some(field) = missing_or(field, last, self.skip_nulls, ctx) {
+                write(last, destination, field.into_owned(), ctx)?;
+            }
+        }
+        Ok(None)
+    }
+
+    fn destination_paths(&self) -> Vec<String> {
+        self.namespaces
+            .iter()
+            .map(|ns| Namespace::join(ns))
+            .collect()
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        vec![self.child.as_ref()]
+    }
+}
+
+/// returns an explicit `null` in place of a missing Value when enabled in the Context, unless the
+/// namespace merges into the destination, or no Value in place of `null` when skipping nulls, which
+/// takes precedence.
+#[inline]
+fn missing_or<'a>(
+    field: Option<Cow<'a, Value>>,
+    namespace: &[Namespace],
+    skip_nulls: bool,
+    ctx: &Context,
+) -> Option<Cow<'a, Value>> {
+    let skip_nulls = skip_nulls || ctx.skip_nulls();
+    match field {
+        Some(v) if skip_nulls && v.is_null() => None,
+        None if ctx.null_for_missing() && !skip_nulls && !merges(namespace) => {
+            Some(Cow::Owned(Value::Null))
+        }
+        field => field,
+    }
+}
+
+/// returns whether the namespace merges or combines the source data into the destination, which
+/// requires an Object or Array rather than `null`.
+fn merges(namespace: &[Namespace]) -> bool {
+    namespace.iter().any(|ns| {
+        matches!(
+            ns,
+            Namespace::MergeObject { .. }
+                | Namespace::DeepMergeObject { .. }
+                | Namespace::MergeArray { .. }
+                | Namespace::CombineArray
+                | Namespace::WildcardArray
+                | Namespace::UpsertArray { .. }
+                | Namespace::UnionArray { .. }
+        )
+    })
+}
+
+/// sets the field within the destination, counting its nodes towards the Context's limits unless
+/// the namespace only sets absent destinations, which are counted by `set` once written.
+#[inline]
+fn write(
+    namespace: &[Namespace],
+    destination: &mut Value,
+    field: Value,
+    ctx: &Context,
+) -> Result<(), CrateErr> {
+    if namespace.last() != Some(&Namespace::IfAbsent) {
+        ctx.write(&field)?;
+    }
+    set("", namespace, destination, field, ctx)
+}
+
+#[inline]
+fn set(
+    parent: &str,
+    namespace: &[Namespace],
+    destination: &mut Value,
+    field: Value,
+    ctx: &Context,
+) -> Result<(), CrateErr> {
+    let mut current = destination;
+    for (i, ns) in namespace.iter().enumerate() {
+        // numeric JSON Pointer tokens set an Object by id when one exists, otherwise by index.
+        let resolved;
+        let ns = match ns {
+            Namespace::PointerIndex { index } if current.is_object() => {
+                resolved = Namespace::Object {
+                    id: index.to_string(),
+                };
+                &resolved
+            }
+            ns => ns,
+        };
+        match ns {
+            Namespace::Object { id } => {
+                match current {
+                    Value::Object(o) => {
+                        if namespace.get(i + 1) == Some(&Namespace::IfAbsent) && o.contains_key(id)
+                        {
+                            return Ok(());
+                        }
+                        current = o.entry(id.clone()).or_insert(Value::Null);
                     }
-                    Namespace::Array { index } => {
-                        let index = *index;
-                        match current {
-                            Value::Array(arr) => {
-                                if index >= arr.len() {
-                                    arr.resize_with(index + 1, Value::default);
-                                    arr[index] = Value::Null;
+                    Value::Null => {
+                        let mut o = Map::new();
+                        o.insert(id.clone(), Value::Null);
+                        *current = Value::Object(o);
+                        current = current.as_object_mut().unwrap().get_mut(id).unwrap();
+                    }
+                    _ => {
+                        return Err(invalid_destination(
+                            parent,
+                            &namespace[..i],
+                            format!("Attempting to set an Object by id to an {:?}", current),
+                        )
+                        .into())
+                    }
+                };
+            }
+            Namespace::Array { index } | Namespace::PointerIndex { index } => {
+                let index = *index;
+                match current {
+                    Value::Array(arr) => {
+                        if namespace.get(i + 1) == Some(&Namespace::IfAbsent) && index < arr.len() {
+                            return Ok(());
+                        }
+                        if index >= arr.len() {
+                            ctx.pad(index - arr.len())?;
+                            arr.resize_with(index + 1, Value::default);
+                            arr[index] = Value::Null;
+                        }
+                        current = &mut arr[index];
+                    }
+                    Value::Null => {
+                        ctx.pad(index)?;
+                        *current = Value::Array(vec![Value::Null; index + 1]);
+                        current = &mut current.as_array_mut().unwrap()[index];
+                    }
+                    _ => {
+                        return Err(invalid_destination(
+                            parent,
+                            &namespace[..i],
+                            format!("Attempting to set an Array by index to an {:?}", current),
+                        )
+                        .into())
+                    }
+                };
+            }
+            Namespace::AppendArray => {
+                match current {
+                    Value::Array(arr) => {
+                        arr.push(Value::Null);
+                        current = arr.last_mut().unwrap();
+                    }
+                    Value::Null => {
+                        let arr = vec![Value::Null];
+                        *current = Value::Array(arr);
+                        current = current.as_array_mut().unwrap().last_mut().unwrap();
+                    }
+                    _ => {
+                        return Err(invalid_destination(
+                            parent,
+                            &namespace[..i],
+                            format!("Attempting to append an {:?} to an Array", current),
+                        )
+                        .into())
+                    }
+                };
+            }
+            Namespace::IfAbsent => {
+                ctx.write(&field)?;
+                break;
+            }
+            Namespace::AppendUniqueArray => {
+                return match current {
+                    Value::Array(arr) => {
+                        if !arr.contains(&field) {
+                            arr.push(field);
+                        }
+                        Ok(())
+                    }
+                    Value::Null => {
+                        *current = Value::Array(vec![field]);
+                        Ok(())
+                    }
+                    _ => Err(invalid_destination(
+                        parent,
+                        &namespace[..i],
+                        format!("Attempting to append an {:?} to an Array", current),
+                    )
+                    .into()),
+                };
+            }
+            Namespace::InsertArray { index } => {
+                let index = *index;
+                match current {
+                    Value::Array(arr) => {
+                        if index > arr.len() {
+                            ctx.pad(index - arr.len())?;
+                            arr.resize_with(index, Value::default);
+                        }
+                        arr.insert(index, Value::Null);
+                        current = &mut arr[index];
+                    }
+                    Value::Null => {
+                        ctx.pad(index)?;
+                        *current = Value::Array(vec![Value::Null; index + 1]);
+                        current = &mut current.as_array_mut().unwrap()[index];
+                    }
+                    _ => {
+                        return Err(invalid_destination(
+                            parent,
+                            &namespace[..i],
+                            format!(
+                                "Attempting to insert into an Array by index to an {:?}",
+                                current
+                            ),
+                        )
+                        .into())
+                    }
+                };
+            }
+            Namespace::MergeObject { strategy } => {
+                return match field {
+                    Value::Object(o) => match current {
+                        Value::Object(existing) => {
+                            let at = pointer(parent, &namespace[..i]);
+                            Ok(merge_object(existing, o, *strategy, false, &at)?)
+                        }
+                        Value::Null => {
+                            *current = Value::Object(o);
+                            Ok(())
+                        }
+                        _ => Err(invalid_destination(
+                            parent,
+                            &namespace[..i],
+                            format!("Attempting to merge an Object with and {:?}", current),
+                        )
+                        .into()),
+                    },
+                    _ => Err(invalid_destination(
+                        parent,
+                        &namespace[..i],
+                        format!("Attempting to merge {:?} with an Object", field),
+                    )
+                    .into()),
+                };
+            }
+            Namespace::DeepMergeObject { strategy } => {
+                return match field {
+                    Value::Object(o) => match current {
+                        Value::Object(existing) => {
+                            let at = pointer(parent, &namespace[..i]);
+                            Ok(merge_object(existing, o, *strategy, true, &at)?)
+                        }
+                        Value::Null => {
+                            *current = Value::Object(o);
+                            Ok(())
+                        }
+                        _ => Err(invalid_destination(
+                            parent,
+                            &namespace[..i],
+                            format!("Attempting to deep merge an Object with and {:?}", current),
+                        )
+                        .into()),
+                    },
+                    _ => Err(invalid_destination(
+                        parent,
+                        &namespace[..i],
+                        format!("Attempting to deep merge {:?} with an Object", field),
+                    )
+                    .into()),
+                };
+            }
+            Namespace::MergeArray { strategy } => {
+                return match field {
+                    Value::Array(mut arr) => match current {
+                        Value::Array(existing) if *strategy == MergeStrategy::Concat => {
+                            existing.append(&mut arr);
+                            Ok(())
+                        }
+                        Value::Array(existing) => {
+                            let at = pointer(parent, &namespace[..i]);
+                            for (j, v) in arr.into_iter().enumerate() {
+                                match existing.get_mut(j) {
+                                    Some(e) => {
+                                        resolve(e, v, *strategy, || format!("{}/{}", at, j))?
+                                    }
+                                    None => existing.push(v),
                                 }
-                                current = &mut arr[index];
                             }
-                            Value::Null => {
-                                *current = Value::Array(vec![Value::Null; index + 1]);
-                                current = &mut current.as_array_mut().unwrap()[index];
-                            }
-                            _ => {
-                                return Err(SetterError::InvalidDestinationType(format!(
-                                    "Attempting to set an Array by index to an {:?}",
-                                    current
-                                ))
+                            Ok(())
+                        }
+                        Value::Null => {
+                            *current = Value::Array(arr);
+                            Ok(())
+                        }
+                        _ => Err(invalid_destination(
+                            parent,
+                            &namespace[..i],
+                            format!("Attempting to merge an Array with and {:?}", current),
+                        )
+                        .into()),
+                    },
+                    _ => Err(invalid_destination(
+                        parent,
+                        &namespace[..i],
+                        format!("Attempting to merge {:?} with an Array", field),
+                    )
+                    .into()),
+                };
+            }
+            Namespace::CombineArray => {
+                return match field {
+                    Value::Array(mut arr) => match current {
+                        Value::Array(existing) => {
+                            existing.append(&mut arr);
+                            Ok(())
+                        }
+                        Value::Null => {
+                            *current = Value::Array(arr);
+                            Ok(())
+                        }
+                        _ => Err(invalid_destination(
+                            parent,
+                            &namespace[..i],
+                            format!("Attempting to combine an Array with and {:?}", current),
+                        )
+                        .into()),
+                    },
+                    _ => Err(invalid_destination(
+                        parent,
+                        &namespace[..i],
+                        format!("Attempting to merge {:?} with an Array", field),
+                    )
+                    .into()),
+                };
+            }
+            Namespace::UpsertArray { key } => {
+                return match field {
+                    Value::Object(o) => {
+                        let id = match o.get(key) {
+                            Some(id) => id.clone(),
+                            None => {
+                                return Err(SetterError::MissingUpsertKey {
+                                    pointer: pointer(parent, &namespace[..i]),
+                                    key: key.clone(),
+                                }
                                 .into())
                             }
                         };
-                    }
-                    Namespace::AppendArray => {
                         match current {
-                            Value::Array(arr) => {
-                                arr.push(Value::Null);
-                                current = arr.last_mut().unwrap();
+                            Value::Array(existing) => {
+                                upsert(existing, key, &id, o);
+                                Ok(())
                             }
                             Value::Null => {
-                                let arr = vec![Value::Null];
-                                *current = Value::Array(arr);
-                                current = current.as_array_mut().unwrap().last_mut().unwrap();
+                                *current = Value::Array(vec![Value::Object(o)]);
+                                Ok(())
                             }
+                            _ => Err(invalid_destination(
+                                parent,
+                                &namespace[..i],
+                                format!("Attempting to upsert an Object into an {:?}", current),
+                            )
+                            .into()),
+                        }
+                    }
+                    _ => Err(invalid_destination(
+                        parent,
+                        &namespace[..i],
+                        format!("Attempting to upsert {:?} into an Array by key", field),
+                    )
+                    .into()),
+                };
+            }
+            Namespace::UnionArray { key } => {
+                return match field {
+                    Value::Array(arr) => {
+                        match current {
+                            Value::Array(_) => {}
+                            Value::Null => *current = Value::Array(Vec::with_capacity(arr.len())),
                             _ => {
-                                return Err(SetterError::InvalidDestinationType(format!(
-                                    "Attempting to append an {:?} to an Array",
-                                    current
-                                ))
+                                return Err(invalid_destination(
+                                    parent,
+                                    &namespace[..i],
+                                    format!("Attempting to union an Array into an {:?}", current),
+                                )
                                 .into())
                             }
                         };
-                    }
-                    Namespace::MergeObject => {
-                        return match field {
-                            Value::Object(mut o) => match current {
-                                Value::Object(existing) => {
-                                    existing.append(&mut o);
-                                    Ok(None)
+                        let existing = current.as_array_mut().unwrap();
+                        for v in arr {
+                            match v {
+                                Value::Object(o) => {
+                                    let id = match o.get(key) {
+                                        Some(id) => id.clone(),
+                                        None => {
+                                            return Err(SetterError::MissingUpsertKey {
+                                                pointer: pointer(parent, &namespace[..i]),
+                                                key: key.clone(),
+                                            }
+                                            .into())
+                                        }
+                                    };
+                                    upsert(existing, key, &id, o);
                                 }
-                                Value::Null => {
-                                    let mut new = Map::new();
-                                    new.append(&mut o);
-                                    *current = Value::Object(new);
-                                    Ok(None)
+                                _ => {
+                                    return Err(invalid_destination(
+                                        parent,
+                                        &namespace[..i],
+                                        format!("Attempting to union {:?} into an Array by key", v),
+                                    )
+                                    .into())
                                 }
-                                _ => Err(SetterError::InvalidDestinationType(format!(
-                                    "Attempting to merge an Object with and {:?}",
-                                    current
-                                ))
-                                .into()),
-                            },
-                            _ => Err(SetterError::InvalidDestinationType(format!(
-                                "Attempting to merge {:?} with an Object",
-                                field
-                            ))
-                            .into()),
-                        };
-                    }
-                    Namespace::MergeArray => {
-                        return match field {
-                            Value::Array(arr) => match current {
-                                Value::Array(existing) => {
-                                    if arr.len() > existing.len() {
-                                        *existing = arr;
-                                        return Ok(None);
-                                    }
-                                    for (i, v) in arr.into_iter().enumerate() {
-                                        existing[i] = v.clone();
-                                    }
-                                    Ok(None)
-                                }
-                                Value::Null => {
-                                    *current = Value::Array(arr);
-                                    Ok(None)
-                                }
-                                _ => Err(SetterError::InvalidDestinationType(format!(
-                                    "Attempting to merge an Array with and {:?}",
-                                    current
-                                ))
-                                .into()),
-                            },
-                            _ => Err(SetterError::InvalidDestinationType(format!(
-                                "Attempting to merge {:?} with an Array",
-                                field
-                            ))
-                            .into()),
-                        };
+                            }
+                        }
+                        Ok(())
                     }
-                    Namespace::CombineArray => {
-                        return match field {
-                            Value::Array(mut arr) => match current {
-                                Value::Array(existing) => {
-                                    existing.append(&mut arr);
-                                    Ok(None)
-                                }
-                                Value::Null => {
-                                    *current = Value::Array(arr);
-                                    Ok(None)
-                                }
-                                _ => Err(SetterError::InvalidDestinationType(format!(
-                                    "Attempting to combine an Array with and {:?}",
-                                    current
-                                ))
-                                .into()),
-                            },
-                            _ => Err(SetterError::InvalidDestinationType(format!(
-                                "Attempting to merge {:?} with an Array",
-                                field
-                            ))
-                            .into()),
+                    _ => Err(invalid_destination(
+                        parent,
+                        &namespace[..i],
+                        format!("Attempting to union {:?} with an Array by key", field),
+                    )
+                    .into()),
+                };
+            }
+            Namespace::WildcardArray => {
+                return match field {
+                    Value::Array(arr) => {
+                        let remaining = &namespace[i + 1..];
+                        match current {
+                            Value::Array(_) => {}
+                            Value::Null => *current = Value::Array(Vec::with_capacity(arr.len())),
+                            _ => {
+                                return Err(invalid_destination(
+                                    parent,
+                                    &namespace[..i],
+                                    format!("Attempting to set Array elements to an {:?}", current),
+                                )
+                                .into())
+                            }
                         };
+                        let existing = current.as_array_mut().unwrap();
+                        if arr.len() > existing.len() {
+                            existing.resize_with(arr.len(), Value::default);
+                        }
+                        let prefix = pointer(parent, &namespace[..i]);
+                        for (j, (v, e)) in arr.into_iter().zip(existing.iter_mut()).enumerate() {
+                            set(&format!("{}/{}", prefix, j), remaining, e, v, ctx)?;
+                        }
+                        Ok(())
                     }
+                    _ => Err(invalid_destination(
+                        parent,
+                        &namespace[..i],
+                        format!("Attempting to set {:?} element-wise to an Array", field),
+                    )
+                    .into()),
                 };
             }
-            *current = field;
+        };
+    }
+    *current = field;
+    Ok(())
+}
+
+/// merges the Object into the element of the Array whose key field matches the id, appending it if
+/// none match.
+#[inline]
+fn upsert(existing: &mut Vec<Value>, key: &str, id: &Value, mut o: Map<String, Value>) {
+    let found = existing.iter_mut().find_map(|v| match v {
+        Value::Object(e) if e.get(key) == Some(id) => Some(e),
+        _ => None,
+    });
+    match found {
+        Some(e) => e.append(&mut o),
+        None => existing.push(Value::Object(o)),
+    };
+}
+
+/// merges the Object into the existing, resolving conflicting values using the strategy. When deep,
+/// nested Objects are recursively merged key-by-key.
+fn merge_object(
+    existing: &mut Map<String, Value>,
+    o: Map<String, Value>,
+    strategy: MergeStrategy,
+    deep: bool,
+    at: &str,
+) -> Result<(), SetterError> {
+    for (k, v) in o {
+        match (existing.get_mut(&k), v) {
+            (Some(Value::Object(e)), Value::Object(o)) if deep => merge_object(
+                e,
+                o,
+                strategy,
+                deep,
+                &format!("{}/{}", at, pointer_token(&k)),
+            )?,
+            (Some(e), v) => resolve(e, v, strategy, || format!("{}/{}", at, pointer_token(&k)))?,
+            (None, v) => {
+                existing.insert(k, v);
+            }
         }
-        Ok(None)
+    }
+    Ok(())
+}
+
+/// resolves the conflict between an existing destination value and the source value using the
+/// strategy.
+fn resolve<F>(
+    existing: &mut Value,
+    v: Value,
+    strategy: MergeStrategy,
+    at: F,
+) -> Result<(), SetterError>
+where
+    F: FnOnce() -> String,
+{
+    match (strategy, existing, v) {
+        (MergeStrategy::SourceWins, existing, v) => *existing = v,
+        (MergeStrategy::DestinationWins, _, _) => {}
+        (MergeStrategy::Error, existing, v) => {
+            if *existing != v {
+                return Err(SetterError::MergeConflict { pointer: at() });
+            }
+        }
+        (MergeStrategy::Concat, Value::Array(existing), Value::Array(mut v)) => {
+            existing.append(&mut v)
+        }
+        (MergeStrategy::Concat, existing, v) => *existing = v,
+    };
+    Ok(())
+}
+
+/// builds the RFC 6901 JSON Pointer of the destination location the namespace points to.
+fn pointer(parent: &str, namespace: &[Namespace]) -> String {
+    let mut pointer = parent.to_owned();
+    for ns in namespace {
+        if let Namespace::IfAbsent = ns {
+            continue;
+        }
+        pointer.push('/');
+        match ns {
+            Namespace::Object { id } => pointer.push_str(&pointer_token(id)),
+            Namespace::Array { index }
+            | Namespace::InsertArray { index }
+            | Namespace::PointerIndex { index } => pointer.push_str(&index.to_string()),
+            Namespace::AppendArray | Namespace::AppendUniqueArray => pointer.push('-'),
+            _ => pointer.push_str(&ns.to_string()),
+        };
+    }
+    pointer
+}
+
+/// escapes an Object id as an RFC 6901 JSON Pointer reference token.
+#[inline]
+fn pointer_token(id: &str) -> String {
+    id.replace('~', "~0").replace('/', "~1")
+}
+
+#[inline]
+fn invalid_destination(parent: &str, namespace: &[Namespace], err: String) -> SetterError {
+    SetterError::InvalidDestinationType {
+        pointer: pointer(parent, namespace),
+        err,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::actions::Constant;
+    use serde_json::json;
+
+    #[test]
+    fn invalid_destination_pointer() -> Result<(), Box<dyn std::error::Error>> {
+        let setter = Setter::new(
+            Namespace::parse("person.a~b/c[1].name")?,
+            Box::new(Constant::new("Dean Karn".into())),
+        );
+        let mut destination = json!({"person": {"a~b/c": [null, "value"]}});
+        let res = setter.apply(&Value::Null, &mut destination, &Context::default());
+        match res {
+            Err(CrateErr::Setter(SetterError::InvalidDestinationType { pointer, .. })) => {
+                assert_eq!("/person/a~0b~1c/1", pointer)
+            }
+            _ => panic!("expected InvalidDestinationType error"),
+        };
+
+        let setter = Setter::new(
+            Namespace::parse("locations[*].road")?,
+            Box::new(Constant::new(json!(["26 Here Blvd", "26 Lakeside"]))),
+        );
+        let mut destination = json!({"locations": [{}, "value"]});
+        let res = setter.apply(&Value::Null, &mut destination, &Context::default());
+        match res {
+            Err(CrateErr::Setter(SetterError::InvalidDestinationType { pointer, .. })) => {
+                assert_eq!("/locations/1", pointer)
+            }
+            _ => panic!("expected InvalidDestinationType error"),
+        };
+        Ok(())
     }
 }
@@ -13,15 +13,36 @@ pub enum Error {
     #[error("Missing end bracket ']' in array index for namespace: {0}")]
     MissingArrayIndexBracket(String),
 
-    #[error("Invalid Merge Object Syntax for namespace: {0}. Merge Object Syntax must be exactly '{{}}' and is only valid at the end of the namespace.")]
+    #[error("Invalid Merge Object Syntax for namespace: {0}. Merge Object Syntax must be '{{}}', optionally with a strategy of '>', '<', '!' or '+' eg. '{{<}}', and is only valid at the end of the namespace.")]
     InvalidMergeObjectSyntax(String),
 
-    #[error("Invalid Merge Array Syntax for namespace: {0}. Merge Array Syntax must be exactly '[-]' and is only valid at the end of the namespace.")]
+    #[error("Invalid Deep Merge Object Syntax for namespace: {0}. Deep Merge Object Syntax must be '{{*}}', optionally with a strategy of '>', '<', '!' or '+' eg. '{{*<}}', and is only valid at the end of the namespace.")]
+    InvalidDeepMergeObjectSyntax(String),
+
+    #[error("Invalid Merge Array Syntax for namespace: {0}. Merge Array Syntax must be '[-]', optionally with a strategy of '>', '<', '!' or '+' eg. '[-<]', and is only valid at the end of the namespace.")]
     InvalidMergeArraySyntax(String),
 
+    #[error("Invalid Unique Append Array Syntax for namespace: {0}. Unique Append Array Syntax must be exactly '[?]' and is only valid at the end of the namespace.")]
+    InvalidAppendUniqueArraySyntax(String),
+
+    #[error("Invalid If Absent Syntax for namespace: {0}. If Absent Syntax must be exactly '??' and is only valid at the end of the namespace following an Object id or Array index.")]
+    InvalidIfAbsentSyntax(String),
+
     #[error("Invalid Combine Array Syntax for namespace: {0}. Combine Array Syntax must be exactly '[+]' and is only valid at the end of the namespace.")]
     InvalidCombineArraySyntax(String),
 
+    #[error("Invalid Wildcard Array Syntax for namespace: {0}. Wildcard Array Syntax must be exactly '[*]'.")]
+    InvalidWildcardArraySyntax(String),
+
+    #[error("Invalid Upsert Array Syntax for namespace: {0}. Upsert Array Syntax must be '[=key]' with a non-empty key and is only valid at the end of the namespace.")]
+    InvalidUpsertArraySyntax(String),
+
+    #[error("Invalid Union Array Syntax for namespace: {0}. Union Array Syntax must be '[~key]' with a non-empty key and is only valid at the end of the namespace.")]
+    InvalidUnionArraySyntax(String),
+
     #[error("Invalid Explicit Key Syntax for namespace {0}. Explicit Key Syntax must start with '[\"' and end with '\"]' with any enclosed '\"' escaped.")]
     InvalidExplicitKeySyntax(String),
+
+    #[error("Invalid JSON Pointer: {0}. A JSON Pointer must be empty or start with '/', with any '~' escaped as '~0' and '/' as '~1'.")]
+    InvalidJsonPointer(String),
 }
@@ -2,6 +2,7 @@ mod errors;
 
 pub use errors::Error;
 
+use crate::actions::getter::namespace::{pointer_index, pointer_tokens};
 use crate::actions::setter::namespace::Error as SetterErr;
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
@@ -22,49 +23,173 @@ pub enum Namespace {
     Object { id: String },
 
     /// Represents that the [Setter](../struct.Setter.html) should merge the source and destination
-    /// JSON Objects.
-    MergeObject,
+    /// JSON Objects, resolving conflicting values using the strategy.
+    MergeObject {
+        #[serde(default)]
+        strategy: MergeStrategy,
+    },
+
+    /// Represents that the [Setter](../struct.Setter.html) should recursively merge the source and
+    /// destination JSON Objects, merging nested Objects key-by-key and resolving any other
+    /// conflicting values using the strategy.
+    DeepMergeObject {
+        #[serde(default)]
+        strategy: MergeStrategy,
+    },
 
     /// Represents an index/location for an Array within the destination data.
     Array { index: usize },
 
+    /// Represents a numeric JSON Pointer reference token, which resolves to the Object id of the
+    /// same digits when the destination data is an Object, or to the index otherwise.
+    PointerIndex { index: usize },
+
     /// Represents that the [Setter](../struct.Setter.html) should append the source data to the
     /// destination JSON Array.
     AppendArray,
 
+    /// Represents that the [Setter](../struct.Setter.html) should append the source data to the
+    /// destination JSON Array only if an equal element is not already present.
+    AppendUniqueArray,
+
+    /// Represents that the [Setter](../struct.Setter.html) should insert the source data into the
+    /// destination JSON Array at the index, shifting the remaining elements right.
+    InsertArray { index: usize },
+
     /// Represents that the [Setter](../struct.Setter.html) should merge the source and destination
-    /// JSON Arrays.
-    MergeArray,
+    /// JSON Arrays, resolving the values at overlapping indexes using the strategy.
+    MergeArray {
+        #[serde(default)]
+        strategy: MergeStrategy,
+    },
 
     /// Represents that the [Setter](../struct.Setter.html) should combine the source JSON Array to
     /// the destination JSON Array by appending all array elements from the source Array to the
     /// destinations.
     CombineArray,
+
+    /// Represents that the [Setter](../struct.Setter.html) should only set the source data when the
+    /// preceding Object id or Array index does not already exist within the destination data.
+    IfAbsent,
+
+    /// Represents that the [Setter](../struct.Setter.html) should set each element of the source
+    /// JSON Array to the element at the same index within the destination JSON Array, applying the
+    /// remaining namespace to each element.
+    WildcardArray,
+
+    /// Represents that the [Setter](../struct.Setter.html) should locate the element within the
+    /// destination JSON Array whose `key` field matches the source Object's `key` field and merge
+    /// the source Object into it, appending the source Object if no element matches.
+    UpsertArray { key: String },
+
+    /// Represents that the [Setter](../struct.Setter.html) should merge each Object of the source
+    /// JSON Array into the element within the destination JSON Array whose `key` field matches,
+    /// appending the source Object if no element matches.
+    UnionArray { key: String },
+}
+
+/// Represents how the [Setter](../struct.Setter.html) resolves a conflict when merging a value
+/// into a destination which already contains one.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, Default)]
+pub enum MergeStrategy {
+    /// The source value replaces the destination value.
+    #[default]
+    SourceWins,
+
+    /// The destination value is kept.
+    DestinationWins,
+
+    /// An error is returned unless the values are equal.
+    Error,
+
+    /// Arrays are concatenated, otherwise the source value replaces the destination value.
+    Concat,
+}
+
+impl MergeStrategy {
+    /// parses the strategy from its suffix within the merge syntax eg. `<` of `{<}`.
+    fn from_suffix(suffix: &str) -> Option<Self> {
+        match suffix {
+            "" | ">" => Some(MergeStrategy::SourceWins),
+            "<" => Some(MergeStrategy::DestinationWins),
+            "!" => Some(MergeStrategy::Error),
+            "+" => Some(MergeStrategy::Concat),
+            _ => None,
+        }
+    }
+
+    fn suffix(&self) -> &'static str {
+        match self {
+            MergeStrategy::SourceWins => "",
+            MergeStrategy::DestinationWins => "<",
+            MergeStrategy::Error => "!",
+            MergeStrategy::Concat => "+",
+        }
+    }
 }
 
 impl Display for Namespace {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
         match self {
             Namespace::Object { id } => write!(f, "{}", id),
-            Namespace::MergeObject => write!(f, "{{}}"),
+            Namespace::MergeObject { strategy } => write!(f, "{{{}}}", strategy.suffix()),
+            Namespace::DeepMergeObject { strategy } => write!(f, "{{*{}}}", strategy.suffix()),
             Namespace::AppendArray => write!(f, "[]"),
-            Namespace::MergeArray => write!(f, "[-]"),
+            Namespace::AppendUniqueArray => write!(f, "[?]"),
+            Namespace::IfAbsent => write!(f, "??"),
+            Namespace::InsertArray { index } => write!(f, "[>{}]", index),
+            Namespace::MergeArray { strategy } => write!(f, "[-{}]", strategy.suffix()),
             Namespace::CombineArray => write!(f, "[+]"),
-            Namespace::Array { index } => write!(f, "[{}]", index),
+            Namespace::UpsertArray { key } => write!(f, "[={}]", key),
+            Namespace::UnionArray { key } => write!(f, "[~{}]", key),
+            Namespace::WildcardArray => write!(f, "[*]"),
+            Namespace::Array { index } | Namespace::PointerIndex { index } => {
+                write!(f, "[{}]", index)
+            }
         }
     }
 }
 
 impl Namespace {
+    /// formats a set of [Namespace](enum.Namespace.html)'s back into the transformation syntax.
+    pub fn join(namespace: &[Namespace]) -> String {
+        let mut s = String::new();
+        for ns in namespace {
+            if let Namespace::Object { .. } = ns {
+                if !s.is_empty() {
+                    s.push('.');
+                }
+            }
+            s.push_str(&ns.to_string());
+        }
+        s
+    }
+
     /// parses a transformation syntax string into an Vec of [Namespace](enum.Namespace.html)'s for
     /// use in the [Setter](../struct.Setter.html).
     ///
     /// The transformation syntax is very similar to access JSON data in Javascript with a few additions:
     /// * `{}` eg. test.value{} which denotes that the source Object and destination Object `value` should merge their data instead of the source replace the destination value
+    /// * `{*}` eg. test.value{*} which denotes that the source Object and destination Object `value` should recursively merge their data, merging nested Objects key-by-key.
+    ///
+    /// The merge syntax `{}`, `{*}` and `[-]` replaces conflicting destination values with the source by default, a strategy suffix changes this eg. `{<}`, `{*!}` or `[-+]`:
+    /// * `>` the source value replaces the destination value, the default.
+    /// * `<` the destination value is kept.
+    /// * `!` an error is returned unless the values are equal.
+    /// * `+` Arrays are concatenated, otherwise the source value replaces the destination value.
     /// * `[]` eg. test.value[] which denotes that the source data should be appended to the Array `value` rather than replacing the destination value.
+    /// * `[?]` eg. test.value[?] which denotes that the source data should be appended to the Array `value` only if an equal element is not already present.
+    /// * `[>n]` eg. test.value[>2] which denotes that the source data should be inserted into the Array `value` at index 2, shifting the remaining elements right rather than replacing the element.
     /// * `[+]` eg. test.value[+] which denotes that the source Array should append all of it's values onto the destination Array.
     /// * `[-]` eg. test.value[-] which denotes that the source Array values should replace the destination Array's values at the overlapping indexes.
-    /// NOTE: `{}`, `[+]` and `[-]` can only be used on the last element of the Namespace syntax.
+    /// * `[*]` eg. locations[*].road which denotes that each element of the source Array should be set, in order, to the element at the same index of the destination Array with the remaining namespace applied.
+    /// * `[=key]` eg. test.users[=id] which denotes that the source Object should be merged into the destination Array element with the same `id`, or appended if none match.
+    /// * `[~key]` eg. test.users[~id] which denotes that each Object of the source Array should be merged into the destination Array element with the same `id`, or appended if none match.
+    ///
+    /// A trailing `??` eg. test.value?? denotes that the source data should only be set when `value`
+    /// does not already exist, so defaults can be layered under data set earlier.
+    ///
+    /// NOTE: `{}`, `{*}`, `[?]`, `[+]`, `[-]`, `[=key]` and `[~key]` can only be used on the last element of the Namespace syntax.
     ///
     /// To handle special characters such as ``(blank), `[`, `]`, `"` and `.` you can use the explicit
     /// key syntax `["example[].blah"]` which would represent the key in the following JSON:
@@ -77,6 +202,16 @@ impl Namespace {
         if input.is_empty() {
             return Ok(Vec::new());
         }
+        if let Some(rest) = input.strip_suffix("??") {
+            let mut namespaces = Namespace::parse(rest)?;
+            return match namespaces.last() {
+                Some(Namespace::Object { .. }) | Some(Namespace::Array { .. }) => {
+                    namespaces.push(Namespace::IfAbsent);
+                    Ok(namespaces)
+                }
+                _ => Err(Error::InvalidIfAbsentSyntax(input.to_owned())),
+            };
+        }
 
         let bytes = input.as_bytes();
         let mut namespaces = Vec::new();
@@ -114,18 +249,23 @@ impl Namespace {
                         });
                         s.clear();
                     }
-                    // merge object syntax
-                    idx += 1;
-                    if idx < bytes.len() && bytes[idx] != b'}' {
-                        // error invalid merge object syntax
-                        return Err(Error::InvalidMergeObjectSyntax(input.to_owned()));
-                    }
-                    idx += 1;
-                    if idx != bytes.len() {
-                        // error merge object must be the last part in the namespace.
-                        return Err(Error::InvalidMergeObjectSyntax(input.to_owned()));
-                    }
-                    namespaces.push(Namespace::MergeObject);
+                    // merge object syntax, which must be the last part in the namespace.
+                    let merge = &input[idx + 1..];
+                    let strategy = merge
+                        .strip_suffix('}')
+                        .map(|m| m.strip_prefix('*').unwrap_or(m))
+                        .and_then(MergeStrategy::from_suffix);
+                    namespaces.push(match strategy {
+                        Some(strategy) if merge.starts_with('*') => {
+                            Namespace::DeepMergeObject { strategy }
+                        }
+                        Some(strategy) => Namespace::MergeObject { strategy },
+                        None if merge.starts_with('*') => {
+                            return Err(Error::InvalidDeepMergeObjectSyntax(input.to_owned()))
+                        }
+                        None => return Err(Error::InvalidMergeObjectSyntax(input.to_owned())),
+                    });
+                    idx = bytes.len();
                 }
                 b'[' => {
                     if !s.is_empty() {
@@ -179,18 +319,13 @@ impl Namespace {
                             continue 'outer;
                         }
                         b'-' => {
-                            // merge array
-                            idx += 1;
-                            if idx < bytes.len() && bytes[idx] != b']' {
-                                // error invalid merge object syntax
-                                return Err(Error::InvalidMergeArraySyntax(input.to_owned()));
-                            }
-                            idx += 1;
-                            if idx != bytes.len() {
-                                // error merge object must be the last part in the namespace.
-                                return Err(Error::InvalidMergeArraySyntax(input.to_owned()));
-                            }
-                            namespaces.push(Namespace::MergeArray);
+                            // merge array, which must be the last part in the namespace.
+                            let strategy = input[idx + 1..]
+                                .strip_suffix(']')
+                                .and_then(MergeStrategy::from_suffix)
+                                .ok_or_else(|| Error::InvalidMergeArraySyntax(input.to_owned()))?;
+                            namespaces.push(Namespace::MergeArray { strategy });
+                            idx = bytes.len();
                         }
                         b'+' => {
                             // merge array
@@ -206,6 +341,86 @@ impl Namespace {
                             }
                             namespaces.push(Namespace::CombineArray);
                         }
+                        b'?' => {
+                            // unique append array, which must be the last part in the namespace.
+                            if &input[idx..] != "?]" {
+                                return Err(Error::InvalidAppendUniqueArraySyntax(
+                                    input.to_owned(),
+                                ));
+                            }
+                            namespaces.push(Namespace::AppendUniqueArray);
+                            idx = bytes.len();
+                        }
+                        b'>' => {
+                            // insert array index
+                            idx += 1;
+                            while idx < bytes.len() {
+                                let b = bytes[idx];
+                                match b {
+                                    b']' => {
+                                        namespaces.push(Namespace::InsertArray {
+                                            index: unsafe {
+                                                String::from_utf8_unchecked(s.clone())
+                                            }
+                                            .parse()?,
+                                        });
+                                        s.clear();
+                                        idx += 1;
+                                        continue 'outer;
+                                    }
+                                    _ => {
+                                        idx += 1;
+                                        s.push(b)
+                                    }
+                                };
+                            }
+                            // error no end bracket
+                            return Err(Error::MissingArrayIndexBracket(input.to_owned()));
+                        }
+                        b'*' => {
+                            // wildcard array
+                            idx += 1;
+                            if idx >= bytes.len() || bytes[idx] != b']' {
+                                return Err(Error::InvalidWildcardArraySyntax(input.to_owned()));
+                            }
+                            namespaces.push(Namespace::WildcardArray);
+                            idx += 1;
+                            continue 'outer;
+                        }
+                        op @ b'=' | op @ b'~' => {
+                            // upsert or union array by key
+                            idx += 1;
+                            while idx < bytes.len() {
+                                let b = bytes[idx];
+                                match b {
+                                    b']' => {
+                                        if s.is_empty() || idx + 1 != bytes.len() {
+                                            // error key must be supplied and must be the last part in the namespace.
+                                            return Err(if op == b'=' {
+                                                Error::InvalidUpsertArraySyntax(input.to_owned())
+                                            } else {
+                                                Error::InvalidUnionArraySyntax(input.to_owned())
+                                            });
+                                        }
+                                        let key = unsafe { String::from_utf8_unchecked(s.clone()) };
+                                        namespaces.push(if op == b'=' {
+                                            Namespace::UpsertArray { key }
+                                        } else {
+                                            Namespace::UnionArray { key }
+                                        });
+                                        s.clear();
+                                        idx += 1;
+                                        continue 'outer;
+                                    }
+                                    _ => {
+                                        idx += 1;
+                                        s.push(b)
+                                    }
+                                };
+                            }
+                            // error no end bracket
+                            return Err(Error::MissingArrayIndexBracket(input.to_owned()));
+                        }
                         _ => {
                             // parse array index
                             while idx < bytes.len() {
@@ -246,6 +461,25 @@ impl Namespace {
         }
         Ok(namespaces)
     }
+
+    /// parses a JSON Pointer (RFC 6901) eg. `/nested/inner/key` into an Vec of
+    /// [Namespace](enum.Namespace.html)'s for use in the [Setter](../struct.Setter.html).
+    ///
+    /// `~1` and `~0` are unescaped to `/` and `~` respectively. Tokens which are a valid Array index
+    /// eg. `0` or `12`, are resolved against an existing Object by key and otherwise as an Array
+    /// index, `-` as appending to the Array as in JSON Patch and all others as an Object key.
+    pub fn parse_pointer(input: &str) -> Result<Vec<Namespace>, SetterErr> {
+        let tokens =
+            pointer_tokens(input).ok_or_else(|| SetterErr::InvalidJsonPointer(input.to_owned()))?;
+        Ok(tokens
+            .into_iter()
+            .map(|token| match pointer_index(&token) {
+                Some(index) => Namespace::PointerIndex { index },
+                None if token == "-" => Namespace::AppendArray,
+                None => Namespace::Object { id: token },
+            })
+            .collect())
+    }
 }
 
 #[cfg(test)]
@@ -268,11 +502,43 @@ mod tests {
             Namespace::Object {
                 id: "person".into(),
             },
-            Namespace::MergeObject,
+            Namespace::MergeObject {
+                strategy: MergeStrategy::SourceWins,
+            },
         ];
         assert_eq!(expected, results);
     }
 
+    #[test]
+    fn test_object_deep_merge() {
+        let ns = "test.value{*}";
+        let results = Namespace::parse(ns).unwrap();
+        let expected = vec![
+            Namespace::Object { id: "test".into() },
+            Namespace::Object { id: "value".into() },
+            Namespace::DeepMergeObject {
+                strategy: MergeStrategy::SourceWins,
+            },
+        ];
+        assert_eq!(expected, results);
+        assert_eq!(ns, Namespace::join(&results));
+
+        let results = Namespace::parse("{*<}").unwrap();
+        let expected = vec![Namespace::DeepMergeObject {
+            strategy: MergeStrategy::DestinationWins,
+        }];
+        assert_eq!(expected, results);
+
+        for ns in &["test{*?}", "test{*}.a", "test{*"] {
+            let results = Namespace::parse(ns);
+            let actual = matches!(
+                results.err().unwrap(),
+                Error::InvalidDeepMergeObjectSyntax(_)
+            );
+            assert!(actual);
+        }
+    }
+
     #[test]
     fn test_array_merge() {
         let ns = "person[-]";
@@ -281,11 +547,61 @@ mod tests {
             Namespace::Object {
                 id: "person".into(),
             },
-            Namespace::MergeArray,
+            Namespace::MergeArray {
+                strategy: MergeStrategy::SourceWins,
+            },
         ];
         assert_eq!(expected, results);
     }
 
+    #[test]
+    fn test_merge_strategy() {
+        for (ns, expected, display) in &[
+            (
+                "a{>}",
+                Namespace::MergeObject {
+                    strategy: MergeStrategy::SourceWins,
+                },
+                "a{}",
+            ),
+            (
+                "a{<}",
+                Namespace::MergeObject {
+                    strategy: MergeStrategy::DestinationWins,
+                },
+                "a{<}",
+            ),
+            (
+                "a{*!}",
+                Namespace::DeepMergeObject {
+                    strategy: MergeStrategy::Error,
+                },
+                "a{*!}",
+            ),
+            (
+                "a[-+]",
+                Namespace::MergeArray {
+                    strategy: MergeStrategy::Concat,
+                },
+                "a[-+]",
+            ),
+        ] {
+            let results = Namespace::parse(ns).unwrap();
+            assert_eq!(&results[1], expected);
+            assert_eq!(Namespace::join(&results), *display);
+        }
+
+        let results = Namespace::parse("a{?}");
+        let actual = matches!(results.err().unwrap(), Error::InvalidMergeObjectSyntax(_));
+        assert!(actual);
+
+        for ns in &["a[-?]", "a[-", "a[-].b"] {
+            let results = Namespace::parse(ns);
+            let actual = matches!(results.err().unwrap(), Error::InvalidMergeArraySyntax(_));
+            assert!(actual);
+        }
+    }
+
     #[test]
     fn test_array_combine() {
         let ns = "person[+]";
@@ -299,6 +615,153 @@ mod tests {
         assert_eq!(expected, results);
     }
 
+    #[test]
+    fn test_array_upsert() {
+        let ns = "users[=id]";
+        let results = Namespace::parse(ns).unwrap();
+        let expected = vec![
+            Namespace::Object { id: "users".into() },
+            Namespace::UpsertArray { key: "id".into() },
+        ];
+        assert_eq!(expected, results);
+
+        for ns in &["users[=]", "users[=id].name", "users[=id"] {
+            assert!(Namespace::parse(ns).is_err());
+        }
+    }
+
+    #[test]
+    fn test_array_union() {
+        let ns = "users[~id]";
+        let results = Namespace::parse(ns).unwrap();
+        let expected = vec![
+            Namespace::Object { id: "users".into() },
+            Namespace::UnionArray { key: "id".into() },
+        ];
+        assert_eq!(expected, results);
+        assert_eq!(ns, Namespace::join(&results));
+
+        for ns in &["users[~]", "users[~id].name", "users[~id"] {
+            assert!(Namespace::parse(ns).is_err());
+        }
+    }
+
+    #[test]
+    fn test_wildcard_array() {
+        let ns = "locations[*].road";
+        let results = Namespace::parse(ns).unwrap();
+        let expected = vec![
+            Namespace::Object {
+                id: "locations".into(),
+            },
+            Namespace::WildcardArray,
+            Namespace::Object { id: "road".into() },
+        ];
+        assert_eq!(expected, results);
+
+        assert!(Namespace::parse("locations[*").is_err());
+    }
+
+    #[test]
+    fn test_if_absent() {
+        let ns = "person.names[0]??";
+        let results = Namespace::parse(ns).unwrap();
+        let expected = vec![
+            Namespace::Object {
+                id: "person".into(),
+            },
+            Namespace::Object { id: "names".into() },
+            Namespace::Array { index: 0 },
+            Namespace::IfAbsent,
+        ];
+        assert_eq!(expected, results);
+        assert_eq!(ns, Namespace::join(&results));
+
+        let results = Namespace::parse(r#"["a??"]"#).unwrap();
+        let expected = vec![Namespace::Object { id: "a??".into() }];
+        assert_eq!(expected, results);
+
+        for ns in &["??", "a????", "tags[]??", "a{}??"] {
+            let results = Namespace::parse(ns);
+            let actual = matches!(results.err().unwrap(), Error::InvalidIfAbsentSyntax(_));
+            assert!(actual);
+        }
+    }
+
+    #[test]
+    fn test_join() {
+        let ns = "person.addresses[0].street[]";
+        let results = Namespace::parse(ns).unwrap();
+        assert_eq!(ns, Namespace::join(&results));
+        assert_eq!("", Namespace::join(&[]));
+    }
+
+    #[test]
+    fn test_append_unique_array() {
+        let ns = "person.tags[?]";
+        let results = Namespace::parse(ns).unwrap();
+        let expected = vec![
+            Namespace::Object {
+                id: "person".into(),
+            },
+            Namespace::Object { id: "tags".into() },
+            Namespace::AppendUniqueArray,
+        ];
+        assert_eq!(expected, results);
+        assert_eq!(ns, Namespace::join(&results));
+
+        for ns in &["tags[?", "tags[?x]", "tags[?].a"] {
+            let results = Namespace::parse(ns);
+            let actual = matches!(
+                results.err().unwrap(),
+                Error::InvalidAppendUniqueArraySyntax(_)
+            );
+            assert!(actual);
+        }
+    }
+
+    #[test]
+    fn test_insert_array() {
+        let ns = "items[>2].name";
+        let results = Namespace::parse(ns).unwrap();
+        let expected = vec![
+            Namespace::Object { id: "items".into() },
+            Namespace::InsertArray { index: 2 },
+            Namespace::Object { id: "name".into() },
+        ];
+        assert_eq!(expected, results);
+        assert_eq!(ns, Namespace::join(&results));
+
+        let results = Namespace::parse("items[>a]");
+        let actual = matches!(
+            results.err().unwrap(),
+            Error::InvalidNamespaceArrayIndex { .. }
+        );
+        assert!(actual);
+
+        let results = Namespace::parse("items[>2");
+        let actual = matches!(results.err().unwrap(), Error::MissingArrayIndexBracket(_));
+        assert!(actual);
+    }
+
+    #[test]
+    fn test_pointer() {
+        let results = Namespace::parse_pointer("/people/-/a~1b/2").unwrap();
+        let expected = vec![
+            Namespace::Object {
+                id: "people".into(),
+            },
+            Namespace::AppendArray,
+            Namespace::Object { id: "a/b".into() },
+            Namespace::PointerIndex { index: 2 },
+        ];
+        assert_eq!(expected, results);
+
+        let results = Namespace::parse_pointer("people");
+        let actual = matches!(results.err().unwrap(), Error::InvalidJsonPointer { .. });
+        assert!(actual);
+    }
+
     #[test]
     fn test_append_array() {
         let ns = "person[]";
@@ -0,0 +1,58 @@
+use crate::action::Action;
+use crate::context::Context;
+use crate::errors::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+
+/// This type represents an [Action](../action/trait.Action.html) which returns the elements of an
+/// Array from the start index, inclusive, to the optional end index, exclusive, eg. for simple
+/// pagination.
+///
+/// The indexes are clamped to the length of the Array.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Slice {
+    start: usize,
+    end: Option<usize>,
+    action: Box<dyn Action>,
+}
+
+impl Slice {
+    pub fn new(start: usize, end: Option<usize>, action: Box<dyn Action>) -> Self {
+        Self { start, end, action }
+    }
+
+    #[inline]
+    fn range(&self, len: usize) -> (usize, usize) {
+        let end = self.end.map_or(len, |end| end.min(len));
+        (self.start.min(end), end)
+    }
+}
+
+#[typetag::serde]
+impl Action for Slice {
+    fn apply<'a>(
+        &'a self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        let sliced = match self.action.apply(source, destination, ctx)? {
+            Some(Cow::Owned(Value::Array(arr))) => {
+                let (start, end) = self.range(arr.len());
+                arr.into_iter().skip(start).take(end - start).collect()
+            }
+            Some(Cow::Borrowed(Value::Array(arr))) => {
+                let (start, end) = self.range(arr.len());
+                arr[start..end].to_vec()
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(Cow::Owned(Value::Array(sliced))))
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        vec![self.action.as_ref()]
+    }
+}
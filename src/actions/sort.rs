@@ -0,0 +1,80 @@
+use crate::action::Action;
+use crate::actions::compare::total_cmp;
+use crate::context::Context;
+use crate::errors::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+
+/// This represents the sort order
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum Order {
+    Asc,
+    Desc,
+}
+
+/// This type represents an [Action](../action/trait.Action.html) which sorts the elements of an
+/// Array, or by the result of the key action evaluated with each element as the source root.
+///
+/// The sort is stable and Values of differing types are ordered as null < bool < number < string
+/// < array < object; a missing key is treated as `null`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Sort {
+    action: Box<dyn Action>,
+    key: Option<Box<dyn Action>>,
+    order: Order,
+}
+
+impl Sort {
+    pub fn new(action: Box<dyn Action>, key: Option<Box<dyn Action>>, order: Order) -> Self {
+        Self { action, key, order }
+    }
+}
+
+#[typetag::serde]
+impl Action for Sort {
+    fn apply<'a>(
+        &'a self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        let arr = match self.action.apply(source, destination, ctx)? {
+            Some(Cow::Owned(Value::Array(arr))) => arr,
+            Some(Cow::Borrowed(Value::Array(arr))) => arr.clone(),
+            _ => return Ok(None),
+        };
+        let mut keyed = Vec::with_capacity(arr.len());
+        for (i, element) in arr.into_iter().enumerate() {
+            let key = match &self.key {
+                Some(key) => ctx.scoped(i, || {
+                    let mut destination = Value::Null;
+                    key.apply(&element, &mut destination, ctx)
+                        .map(|k| k.map_or(Value::Null, Cow::into_owned))
+                })?,
+                None => Value::Null,
+            };
+            keyed.push((key, element));
+        }
+        keyed.sort_by(|(ak, a), (bk, b)| {
+            let o = match self.key {
+                Some(_) => total_cmp(ak, bk),
+                None => total_cmp(a, b),
+            };
+            match self.order {
+                Order::Asc => o,
+                Order::Desc => o.reverse(),
+            }
+        });
+        let sorted = keyed.into_iter().map(|(_, v)| v).collect();
+        Ok(Some(Cow::Owned(Value::Array(sorted))))
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        std::iter::once(&self.action)
+            .chain(self.key.iter())
+            .map(|a| a.as_ref())
+            .collect()
+    }
+}
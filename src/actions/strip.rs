@@ -1,4 +1,5 @@
 use crate::action::Action;
+use crate::context::Context;
 use crate::errors::Error;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -37,8 +38,10 @@ impl Action for Strip {
         &'a self,
         source: &'a Value,
         destination: &mut Value,
+        ctx: &Context,
     ) -> Result<Option<Cow<'a, Value>>, Error> {
-        let res: Option<Cow<'a, Value>> = self.action.apply(source, destination)?;
+        ctx.evaluate()?;
+        let res: Option<Cow<'a, Value>> = self.action.apply(source, destination, ctx)?;
         match &res {
             Some(v) => match v.deref() {
                 Value::String(s) => {
@@ -56,4 +59,8 @@ impl Action for Strip {
             None => Ok(None),
         }
     }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        vec![self.action.as_ref()]
+    }
 }
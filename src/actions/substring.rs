@@ -0,0 +1,54 @@
+use crate::action::Action;
+use crate::context::Context;
+use crate::errors::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+use std::ops::Deref;
+
+/// This type represents an [Action](../action/trait.Action.html) which returns the characters of a
+/// string from the `start` index up to, but not including, the `end` index.
+///
+/// The indices are by character rather than byte and are clamped to the length of the string.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Substring {
+    start: usize,
+    end: usize,
+    action: Box<dyn Action>,
+}
+
+impl Substring {
+    pub fn new(start: usize, end: usize, action: Box<dyn Action>) -> Self {
+        Self { start, end, action }
+    }
+}
+
+#[typetag::serde]
+impl Action for Substring {
+    fn apply<'a>(
+        &'a self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        match self.action.apply(source, destination, ctx)? {
+            Some(v) => match v.deref() {
+                Value::String(s) => {
+                    let s = s
+                        .chars()
+                        .skip(self.start)
+                        .take(self.end.saturating_sub(self.start))
+                        .collect();
+                    Ok(Some(Cow::Owned(Value::String(s))))
+                }
+                _ => Ok(None),
+            },
+            None => Ok(None),
+        }
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        vec![self.action.as_ref()]
+    }
+}
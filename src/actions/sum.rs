@@ -1,7 +1,9 @@
 use crate::action::Action;
+use crate::actions::number;
+use crate::context::Context;
 use crate::errors::Error;
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use serde_json::{Number, Value};
 use std::borrow::Cow;
 use std::ops::Deref;
 
@@ -24,31 +26,26 @@ impl Action for Sum {
         &self,
         source: &'a Value,
         destination: &mut Value,
+        ctx: &Context,
     ) -> Result<Option<Cow<'a, Value>>, Error> {
-        let mut result: f64 = 0.0;
-        let mut has_f64_value = false;
+        ctx.evaluate()?;
+        let mut result = Number::from(0);
 
         for v in self.values.iter() {
-            match v.apply(source, destination)? {
+            match v.apply(source, destination, ctx)? {
                 Some(v) => {
                     match v.deref() {
                         Value::Number(num) => {
-                            if num.is_f64() {
-                                has_f64_value = true;
-                            }
-                            if let Some(n) = num.as_f64() {
-                                result += n;
+                            if let Some(n) = number::add(&result, num) {
+                                result = n;
                             }
                         }
                         Value::Array(arr) => {
                             for v in arr {
                                 match v {
                                     Value::Number(num) => {
-                                        if num.is_f64() {
-                                            has_f64_value = true;
-                                        }
-                                        if let Some(n) = num.as_f64() {
-                                            result += n;
+                                        if let Some(n) = number::add(&result, num) {
+                                            result = n;
                                         }
                                     }
                                     _ => continue,
@@ -61,11 +58,10 @@ impl Action for Sum {
                 None => continue,
             };
         }
+        Ok(Some(Cow::Owned(Value::Number(result))))
+    }
 
-        if has_f64_value {
-            Ok(Some(Cow::Owned(result.into())))
-        } else {
-            Ok(Some(Cow::Owned((result as i64).into())))
-        }
+    fn children(&self) -> Vec<&dyn Action> {
+        self.values.iter().map(|a| a.as_ref()).collect()
     }
 }
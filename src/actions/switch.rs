@@ -0,0 +1,76 @@
+use crate::action::Action;
+use crate::context::Context;
+use crate::errors::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+
+/// This type represents a single case of a [Switch](struct.Switch.html), whose `then` action is
+/// applied when the result of its `when` action equals the input.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Case {
+    when: Box<dyn Action>,
+    then: Box<dyn Action>,
+}
+
+impl Case {
+    pub fn new(when: Box<dyn Action>, then: Box<dyn Action>) -> Self {
+        Self { when, then }
+    }
+}
+
+/// This type represents an [Action](../action/trait.Action.html) which compares the result of the
+/// input action against each case, in order, returning the result of the first matching case or
+/// otherwise the result of the optional default action.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Switch {
+    input: Box<dyn Action>,
+    cases: Vec<Case>,
+    default: Option<Box<dyn Action>>,
+}
+
+impl Switch {
+    pub fn new(input: Box<dyn Action>, cases: Vec<Case>, default: Option<Box<dyn Action>>) -> Self {
+        Self {
+            input,
+            cases,
+            default,
+        }
+    }
+}
+
+#[typetag::serde]
+impl Action for Switch {
+    fn apply<'a>(
+        &'a self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        let input = self.input.apply(source, destination, ctx)?;
+        if let Some(input) = input {
+            for case in &self.cases {
+                if let Some(when) = case.when.apply(source, destination, ctx)? {
+                    if when == input {
+                        return case.then.apply(source, destination, ctx);
+                    }
+                }
+            }
+        }
+        match &self.default {
+            Some(action) => action.apply(source, destination, ctx),
+            None => Ok(None),
+        }
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        let mut children = vec![self.input.as_ref()];
+        for case in &self.cases {
+            children.push(case.when.as_ref());
+            children.push(case.then.as_ref());
+        }
+        children.extend(self.default.iter().map(|a| a.as_ref()));
+        children
+    }
+}
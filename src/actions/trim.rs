@@ -1,4 +1,5 @@
 use crate::action::Action;
+use crate::context::Context;
 use crate::errors::Error;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -33,8 +34,10 @@ impl Action for Trim {
         &self,
         source: &'a Value,
         destination: &mut Value,
+        ctx: &Context,
     ) -> Result<Option<Cow<'a, Value>>, Error> {
-        match self.action.apply(source, destination)? {
+        ctx.evaluate()?;
+        match self.action.apply(source, destination, ctx)? {
             Some(v) => match v.deref() {
                 Value::String(s) => {
                     let s = match self.r#type {
@@ -50,4 +53,8 @@ impl Action for Trim {
             None => Ok(None),
         }
     }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        vec![self.action.as_ref()]
+    }
 }
@@ -0,0 +1,60 @@
+use crate::action::Action;
+use crate::context::Context;
+use crate::errors::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+use std::ops::Deref;
+
+/// This type represents an [Action](../action/trait.Action.html) which shortens a string to a
+/// maximum length in characters.
+///
+/// When the string is shortened the optional suffix, eg. `...`, is appended and counts towards the
+/// maximum length.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Truncate {
+    max: usize,
+    suffix: String,
+    action: Box<dyn Action>,
+}
+
+impl Truncate {
+    pub fn new(max: usize, suffix: String, action: Box<dyn Action>) -> Self {
+        Self {
+            max,
+            suffix,
+            action,
+        }
+    }
+}
+
+#[typetag::serde]
+impl Action for Truncate {
+    fn apply<'a>(
+        &'a self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        match self.action.apply(source, destination, ctx)? {
+            Some(v) => match v.deref() {
+                Value::String(s) => {
+                    if s.chars().nth(self.max).is_none() {
+                        return Ok(Some(v));
+                    }
+                    let suffix_len = self.suffix.chars().count().min(self.max);
+                    let mut truncated: String = s.chars().take(self.max - suffix_len).collect();
+                    truncated.extend(self.suffix.chars().take(suffix_len));
+                    Ok(Some(Cow::Owned(Value::String(truncated))))
+                }
+                _ => Ok(None),
+            },
+            None => Ok(None),
+        }
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        vec![self.action.as_ref()]
+    }
+}
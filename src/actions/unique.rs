@@ -0,0 +1,84 @@
+use crate::action::Action;
+use crate::context::Context;
+use crate::errors::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+use std::collections::HashSet;
+
+/// This type represents an [Action](../action/trait.Action.html) which removes the duplicate
+/// elements of an Array, or those with a duplicate result of the key action evaluated with each
+/// element as the source root, preserving the first-seen order.
+///
+/// A missing key is treated as `null`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Unique {
+    action: Box<dyn Action>,
+    key: Option<Box<dyn Action>>,
+}
+
+impl Unique {
+    pub fn new(action: Box<dyn Action>, key: Option<Box<dyn Action>>) -> Self {
+        Self { action, key }
+    }
+
+    fn is_new(
+        &self,
+        i: usize,
+        element: &Value,
+        seen: &mut HashSet<String>,
+        ctx: &Context,
+    ) -> Result<bool, Error> {
+        let key = match &self.key {
+            Some(key) => ctx.scoped(i, || {
+                let mut destination = Value::Null;
+                key.apply(element, &mut destination, ctx)
+                    .map(|k| k.as_deref().unwrap_or(&Value::Null).to_string())
+            })?,
+            None => element.to_string(),
+        };
+        Ok(seen.insert(key))
+    }
+}
+
+#[typetag::serde]
+impl Action for Unique {
+    fn apply<'a>(
+        &'a self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        let mut seen = HashSet::new();
+        let unique = match self.action.apply(source, destination, ctx)? {
+            Some(Cow::Owned(Value::Array(arr))) => {
+                let mut unique = Vec::with_capacity(arr.len());
+                for (i, element) in arr.into_iter().enumerate() {
+                    if self.is_new(i, &element, &mut seen, ctx)? {
+                        unique.push(element);
+                    }
+                }
+                unique
+            }
+            Some(Cow::Borrowed(Value::Array(arr))) => {
+                let mut unique = Vec::with_capacity(arr.len());
+                for (i, element) in arr.iter().enumerate() {
+                    if self.is_new(i, element, &mut seen, ctx)? {
+                        unique.push(element.clone());
+                    }
+                }
+                unique
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(Cow::Owned(Value::Array(unique))))
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        std::iter::once(&self.action)
+            .chain(self.key.iter())
+            .map(|a| a.as_ref())
+            .collect()
+    }
+}
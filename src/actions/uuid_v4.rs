@@ -0,0 +1,38 @@
+use crate::action::Action;
+use crate::context::Context;
+use crate::errors::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+
+/// This type represents an [Action](../action/trait.Action.html) which generates a new random v4
+/// UUID string each time it's applied.
+///
+/// The random bytes are drawn from the [RandomSource](../context/trait.RandomSource.html) so the
+/// output can be made deterministic.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Uuid {}
+
+impl Uuid {
+    pub const fn new() -> Self {
+        Self {}
+    }
+}
+
+#[typetag::serde]
+impl Action for Uuid {
+    fn apply<'a>(
+        &'a self,
+        _source: &'a Value,
+        _destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        let random = ctx.random();
+        let mut bytes = [0_u8; 16];
+        bytes[..8].copy_from_slice(&random.next_u64().to_be_bytes());
+        bytes[8..].copy_from_slice(&random.next_u64().to_be_bytes());
+        let uuid = uuid::Builder::from_random_bytes(bytes).into_uuid();
+        Ok(Some(Cow::Owned(Value::String(uuid.to_string()))))
+    }
+}
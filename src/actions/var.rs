@@ -0,0 +1,35 @@
+use crate::action::Action;
+use crate::context::Context;
+use crate::errors::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+
+/// This type represents an [Action](../action/trait.Action.html) which returns a variable from the
+/// per-apply context supplied to
+/// [apply_with_context](../transformer/struct.Transformer.html#method.apply_with_context).
+///
+/// No value is returned when the variable isn't supplied.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Var {
+    name: String,
+}
+
+impl Var {
+    pub const fn new(name: String) -> Self {
+        Self { name }
+    }
+}
+
+#[typetag::serde]
+impl Action for Var {
+    fn apply<'a>(
+        &'a self,
+        _source: &'a Value,
+        _destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        Ok(ctx.var(&self.name).map(|v| Cow::Owned(v.clone())))
+    }
+}
@@ -0,0 +1,49 @@
+use crate::action::Action;
+use crate::context::Context;
+use crate::errors::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+
+/// This type represents an [Action](../action/trait.Action.html) which only applies its action
+/// when the condition evaluates to a truthy Value.
+///
+/// A condition is falsy when it returns no Value, `null` or `false`; anything else is truthy.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct When {
+    condition: Box<dyn Action>,
+    action: Box<dyn Action>,
+}
+
+impl When {
+    pub fn new(condition: Box<dyn Action>, action: Box<dyn Action>) -> Self {
+        Self { condition, action }
+    }
+}
+
+#[typetag::serde]
+impl Action for When {
+    fn apply<'a>(
+        &'a self,
+        source: &'a Value,
+        destination: &mut Value,
+        ctx: &Context,
+    ) -> Result<Option<Cow<'a, Value>>, Error> {
+        ctx.evaluate()?;
+        let condition = ctx.guarded(|| self.condition.apply(source, destination, ctx))?;
+        if !is_truthy(condition.as_deref()) {
+            return Ok(None);
+        }
+        self.action.apply(source, destination, ctx)
+    }
+
+    fn children(&self) -> Vec<&dyn Action> {
+        vec![self.condition.as_ref(), self.action.as_ref()]
+    }
+}
+
+/// returns if the result of an Action is considered true by conditional Actions.
+#[inline]
+pub(crate) fn is_truthy(value: Option<&Value>) -> bool {
+    !matches!(value, None | Some(Value::Null) | Some(Value::Bool(false)))
+}
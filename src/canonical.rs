@@ -0,0 +1,153 @@
+//! Canonical JSON serialization following the JSON Canonicalization Scheme (JCS) of
+//! [RFC 8785](https://www.rfc-editor.org/rfc/rfc8785), suitable for signing and hashing.
+
+use serde_json::{Number, Value};
+
+/// serializes the Value into its canonical JSON form, with Object keys sorted by their UTF-16 code
+/// units, no insignificant whitespace and numbers formatted as ECMAScript would.
+///
+/// NOTE: as all numbers are treated as IEEE 754 doubles, integers beyond 2^53 lose precision.
+pub fn to_string(value: &Value) -> String {
+    let mut out = String::new();
+    write_value(value, &mut out);
+    out
+}
+
+fn write_value(value: &Value, out: &mut String) {
+    match value {
+        Value::Null => out.push_str("null"),
+        Value::Bool(b) => out.push_str(if *b { "true" } else { "false" }),
+        Value::Number(n) => write_number(n, out),
+        Value::String(s) => write_string(s, out),
+        Value::Array(arr) => {
+            out.push('[');
+            for (i, v) in arr.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_value(v, out);
+            }
+            out.push(']');
+        }
+        Value::Object(o) => {
+            let mut entries: Vec<(&String, &Value)> = o.iter().collect();
+            entries.sort_by(|(a, _), (b, _)| a.encode_utf16().cmp(b.encode_utf16()));
+            out.push('{');
+            for (i, (k, v)) in entries.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_string(k, out);
+                out.push(':');
+                write_value(v, out);
+            }
+            out.push('}');
+        }
+    }
+}
+
+#[inline]
+fn write_string(s: &str, out: &mut String) {
+    // serde_json's escaping of strings matches that required by JCS.
+    out.push_str(&serde_json::to_string(s).unwrap()); // unwrap safe, serializing a str cannot fail
+}
+
+fn write_number(n: &Number, out: &mut String) {
+    let f = match n.as_f64() {
+        Some(f) => f,
+        None => return out.push_str(&n.to_string()),
+    };
+    if f == 0.0 {
+        return out.push('0');
+    }
+    if f < 0.0 {
+        out.push('-');
+    }
+
+    // the shortest round-trip representation, eg. 1.2345e6, split into its significant digits
+    // and decimal exponent.
+    let sci = format!("{:e}", f.abs());
+    let (mantissa, exp) = sci.split_once('e').unwrap(); // unwrap safe, always in exponent form
+    let digits: String = mantissa.chars().filter(|c| *c != '.').collect();
+    let k = digits.len() as i32;
+    let n = exp.parse::<i32>().unwrap() + 1; // unwrap safe, always a valid exponent
+
+    if k <= n && n <= 21 {
+        out.push_str(&digits);
+        out.push_str(&"0".repeat((n - k) as usize));
+    } else if 0 < n && n <= 21 {
+        out.push_str(&digits[..n as usize]);
+        out.push('.');
+        out.push_str(&digits[n as usize..]);
+    } else if -6 < n && n <= 0 {
+        out.push_str("0.");
+        out.push_str(&"0".repeat(-n as usize));
+        out.push_str(&digits);
+    } else {
+        out.push_str(&digits[..1]);
+        if k > 1 {
+            out.push('.');
+            out.push_str(&digits[1..]);
+        }
+        out.push('e');
+        out.push(if n - 1 < 0 { '-' } else { '+' });
+        out.push_str(&(n - 1).abs().to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn numbers() {
+        let cases = [
+            (json!(0), "0"),
+            (json!(-0.0), "0"),
+            (json!(1.0), "1"),
+            (json!(-1), "-1"),
+            (json!(123456789), "123456789"),
+            (json!(1.5), "1.5"),
+            (json!(0.000001), "0.000001"),
+            (json!(1e-7), "1e-7"),
+            (json!(1e21), "1e+21"),
+            (json!(1e20), "100000000000000000000"),
+            (json!(333333333.3333333), "333333333.3333333"),
+            (json!(4.50), "4.5"),
+            (json!(2e-3), "0.002"),
+            (json!(0.000000000000000000000000001), "1e-27"),
+            (json!(9007199254740993_u64), "9007199254740992"),
+        ];
+        for (value, expected) in cases.iter() {
+            assert_eq!(&to_string(value), expected, "{}", value);
+        }
+    }
+
+    #[test]
+    fn objects() {
+        let value = json!({
+            "numbers": [333333333.3333333, 1E30, 4.50, 2e-3, 0.000000000000000000000000001],
+            "string": "\u{20ac}$\u{000F}\u{000a}A'\u{0042}\u{0022}\u{005c}\\\"/",
+            "literals": [null, true, false],
+            "\u{20ac}": "Euro Sign",
+            "\r": "Carriage Return",
+            "\u{fb33}": "Hebrew Letter Dalet With Dagesh",
+            "1": "One",
+            "\u{1f600}": "Emoji: Grinning Face",
+            "\u{0080}": "Control",
+            "\u{00f6}": "Latin Small Letter O With Diaeresis"
+        });
+        assert_eq!(
+            to_string(&value),
+            concat!(
+                r#"{"\r":"Carriage Return","1":"One","literals":[null,true,false],"#,
+                r#""numbers":[333333333.3333333,1e+30,4.5,0.002,1e-27],"#,
+                r#""string":"€$\u000f\nA'B\"\\\\\"/","#,
+                "\"\u{0080}\":\"Control\",\"\u{00f6}\":\"Latin Small Letter O With Diaeresis\",",
+                "\"\u{20ac}\":\"Euro Sign\",\"\u{1f600}\":\"Emoji: Grinning Face\",",
+                "\"\u{fb33}\":\"Hebrew Letter Dalet With Dagesh\"}"
+            )
+        );
+    }
+}
@@ -0,0 +1,768 @@
+//! Context made available to [Action](../action/trait.Action.html)'s while applying a
+//! transformation.
+
+use crate::errors::Error;
+use once_cell::sync::Lazy;
+use serde_json::Value;
+use std::cell::Cell;
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::{BuildHasher, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::SystemTime;
+
+static SYSTEM_CLOCK: SystemClock = SystemClock;
+static DEFAULT_RANDOM: Lazy<DefaultRandom> = Lazy::new(DefaultRandom::default);
+static NO_KEYS: Lazy<Keys> = Lazy::new(Keys::default);
+static NO_LOOKUP_TABLES: Lazy<LookupTables> = Lazy::new(LookupTables::default);
+static NO_VARS: Lazy<HashMap<String, Value>> = Lazy::new(HashMap::new);
+
+/// A source of the current time used by time based [Action](../action/trait.Action.html)'s,
+/// allowing it to be injected for deterministic output.
+pub trait Clock: Send + Sync + Debug {
+    /// returns the current time.
+    fn now(&self) -> SystemTime;
+}
+
+/// A [Clock](trait.Clock.html) which returns the current system time.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    #[inline]
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// A [Clock](trait.Clock.html) which always returns the same time.
+#[derive(Debug, Clone, Copy)]
+pub struct FixedClock(SystemTime);
+
+impl FixedClock {
+    pub const fn new(time: SystemTime) -> Self {
+        Self(time)
+    }
+}
+
+impl Clock for FixedClock {
+    #[inline]
+    fn now(&self) -> SystemTime {
+        self.0
+    }
+}
+
+/// A source of random numbers used by generator [Action](../action/trait.Action.html)'s,
+/// allowing it to be injected for deterministic output.
+pub trait RandomSource: Send + Sync + Debug {
+    /// returns the next random u64.
+    fn next_u64(&self) -> u64;
+
+    /// returns the next random f64 in the range [0, 1).
+    fn next_f64(&self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+}
+
+/// A non-deterministic [RandomSource](trait.RandomSource.html) seeded from the OS randomness used
+/// by the standard library's `HashMap`.
+///
+/// NOTE: this is NOT suitable for cryptographic purposes.
+#[derive(Debug, Default)]
+pub struct DefaultRandom {
+    state: RandomState,
+    counter: AtomicU64,
+}
+
+impl RandomSource for DefaultRandom {
+    #[inline]
+    fn next_u64(&self) -> u64 {
+        let mut hasher = self.state.build_hasher();
+        hasher.write_u64(self.counter.fetch_add(1, Ordering::Relaxed));
+        hasher.finish()
+    }
+}
+
+/// A deterministic [RandomSource](trait.RandomSource.html) which produces the same sequence of
+/// numbers for the same seed.
+#[derive(Debug)]
+pub struct SeededRandom {
+    state: AtomicU64,
+}
+
+impl SeededRandom {
+    pub const fn new(seed: u64) -> Self {
+        Self {
+            state: AtomicU64::new(seed),
+        }
+    }
+}
+
+impl RandomSource for SeededRandom {
+    #[inline]
+    fn next_u64(&self) -> u64 {
+        // SplitMix64
+        let mut z = self
+            .state
+            .fetch_add(0x9E37_79B9_7F4A_7C15, Ordering::Relaxed)
+            .wrapping_add(0x9E37_79B9_7F4A_7C15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+/// This type represents the secret keys, by id, made available to keyed
+/// [Action](../action/trait.Action.html)'s such as `hmac`, keeping the secrets themselves out of
+/// the transformation.
+///
+/// The secrets are never included in the Debug output.
+#[derive(Default, Clone)]
+pub struct Keys(HashMap<String, Vec<u8>>);
+
+impl Keys {
+    /// adds, or replaces, the secret key for the id.
+    pub fn insert<I, K>(&mut self, id: I, key: K)
+    where
+        I: Into<String>,
+        K: Into<Vec<u8>>,
+    {
+        self.0.insert(id.into(), key.into());
+    }
+
+    /// returns the secret key for the id, if any.
+    #[inline]
+    pub fn get(&self, id: &str) -> Option<&[u8]> {
+        self.0.get(id).map(Vec::as_slice)
+    }
+}
+
+impl Debug for Keys {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.0.keys()).finish()
+    }
+}
+
+/// This type represents the named, thread-safe, incrementing sequences used by the `seq`
+/// [Action](../action/trait.Action.html), which persist across applications of a transformation.
+#[derive(Debug, Default)]
+pub struct Sequences(Mutex<HashMap<String, u64>>);
+
+impl Sequences {
+    /// returns the next value of the named sequence, starting at 1.
+    pub fn next(&self, name: &str) -> u64 {
+        let mut sequences = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        match sequences.get_mut(name) {
+            Some(n) => {
+                *n += 1;
+                *n
+            }
+            None => {
+                sequences.insert(name.to_owned(), 1);
+                1
+            }
+        }
+    }
+}
+
+/// This type represents the named lookup tables made available to the `lookup`
+/// [Action](../action/trait.Action.html), allowing mappings such as country codes to names to be
+/// supplied as data.
+#[derive(Debug, Default, Clone)]
+pub struct LookupTables(HashMap<String, HashMap<String, Value>>);
+
+impl LookupTables {
+    /// adds, or replaces, the named lookup table.
+    pub fn insert<N>(&mut self, name: N, table: HashMap<String, Value>)
+    where
+        N: Into<String>,
+    {
+        self.0.insert(name.into(), table);
+    }
+
+    /// returns the named lookup table, if any.
+    #[inline]
+    pub fn get(&self, name: &str) -> Option<&HashMap<String, Value>> {
+        self.0.get(name)
+    }
+}
+
+/// This type represents the resource limits enforced while applying a transformation, protecting
+/// against transformations that would otherwise consume unbounded memory or CPU.
+///
+/// All limits default to unlimited.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Limits {
+    /// The maximum number of JSON nodes, counting every nested value, that may be written to the
+    /// destination.
+    pub max_destination_nodes: Option<usize>,
+
+    /// The maximum number of `null` elements that may be inserted to pad an Array when setting a
+    /// value by index, eg. `items[100000000]`.
+    pub max_array_padding: Option<usize>,
+
+    /// The maximum number of characters that may be added to pad a string, eg.
+    /// `pad_start(4000000000, " ", name)`.
+    pub max_string_padding: Option<usize>,
+
+    /// The maximum number of [Action](../action/trait.Action.html) evaluations.
+    pub max_action_evaluations: Option<usize>,
+
+    /// The maximum number of namespace segments a Getter or Setter may traverse, eg.
+    /// `person.addresses[0].street` has a depth of 4.
+    pub max_namespace_depth: Option<usize>,
+}
+
+/// This represents the individual limit of [Limits](struct.Limits.html) that has been exceeded.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Limit {
+    DestinationNodes,
+    ArrayPadding,
+    StringPadding,
+    ActionEvaluations,
+    NamespaceDepth,
+}
+
+impl Display for Limit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match self {
+            Limit::DestinationNodes => write!(f, "max_destination_nodes"),
+            Limit::ArrayPadding => write!(f, "max_array_padding"),
+            Limit::StringPadding => write!(f, "max_string_padding"),
+            Limit::ActionEvaluations => write!(f, "max_action_evaluations"),
+            Limit::NamespaceDepth => write!(f, "max_namespace_depth"),
+        }
+    }
+}
+
+/// This represents a category of side-effecting operation an
+/// [Action](../action/trait.Action.html) may require.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Capability {
+    /// Reading environment variables.
+    Environment,
+    /// Reading or writing files.
+    FileSystem,
+    /// Making network requests.
+    Network,
+    /// Executing scripts or other dynamic code.
+    Scripting,
+}
+
+impl Display for Capability {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match self {
+            Capability::Environment => write!(f, "environment"),
+            Capability::FileSystem => write!(f, "file system"),
+            Capability::Network => write!(f, "network"),
+            Capability::Scripting => write!(f, "scripting"),
+        }
+    }
+}
+
+/// This type represents the runtime policy of which [Capability](enum.Capability.html)'s Actions
+/// are allowed to use while applying a transformation.
+///
+/// The policy is enforced at apply time and so also applies to deserialized transformations.
+/// By default all capabilities are allowed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Policy {
+    pub allow_environment: bool,
+    pub allow_file_system: bool,
+    pub allow_network: bool,
+    pub allow_scripting: bool,
+}
+
+impl Default for Policy {
+    fn default() -> Self {
+        Self {
+            allow_environment: true,
+            allow_file_system: true,
+            allow_network: true,
+            allow_scripting: true,
+        }
+    }
+}
+
+impl Policy {
+    /// returns a policy which denies all capabilities, suitable for applying untrusted
+    /// transformations.
+    pub const fn sandboxed() -> Self {
+        Self {
+            allow_environment: false,
+            allow_file_system: false,
+            allow_network: false,
+            allow_scripting: false,
+        }
+    }
+
+    /// returns if the [Capability](enum.Capability.html) is allowed.
+    #[inline]
+    pub fn allows(&self, capability: Capability) -> bool {
+        match capability {
+            Capability::Environment => self.allow_environment,
+            Capability::FileSystem => self.allow_file_system,
+            Capability::Network => self.allow_network,
+            Capability::Scripting => self.allow_scripting,
+        }
+    }
+}
+
+/// This type represents the context of a single application of a transformation, containing the
+/// injected sources such as the [Clock](trait.Clock.html) and
+/// [RandomSource](trait.RandomSource.html) and tracking the [Limits](struct.Limits.html).
+#[derive(Debug)]
+pub struct Context<'a> {
+    clock: &'a dyn Clock,
+    random: &'a dyn RandomSource,
+    keys: &'a Keys,
+    lookup_tables: &'a LookupTables,
+    vars: &'a HashMap<String, Value>,
+    sequences: Option<&'a Sequences>,
+    own_sequences: Sequences,
+    limits: Limits,
+    policy: Policy,
+    null_for_missing: bool,
+    skip_nulls: bool,
+    strict: bool,
+    #[cfg(feature = "i18n")]
+    locale: &'a str,
+    index: Cell<Option<usize>>,
+    guarded: Cell<bool>,
+    evaluations: Cell<usize>,
+    nodes: Cell<usize>,
+}
+
+impl Default for Context<'static> {
+    fn default() -> Self {
+        Self::new(&SYSTEM_CLOCK, &*DEFAULT_RANDOM)
+    }
+}
+
+impl<'a> Context<'a> {
+    pub fn new(clock: &'a dyn Clock, random: &'a dyn RandomSource) -> Self {
+        Self {
+            clock,
+            random,
+            keys: &NO_KEYS,
+            lookup_tables: &NO_LOOKUP_TABLES,
+            vars: &NO_VARS,
+            sequences: None,
+            own_sequences: Sequences::default(),
+            limits: Limits::default(),
+            policy: Policy::default(),
+            null_for_missing: false,
+            skip_nulls: false,
+            strict: false,
+            #[cfg(feature = "i18n")]
+            locale: crate::i18n::DEFAULT_LOCALE,
+            index: Cell::new(None),
+            guarded: Cell::new(false),
+            evaluations: Cell::new(0),
+            nodes: Cell::new(0),
+        }
+    }
+
+    /// sets the [Keys](struct.Keys.html) available to keyed actions.
+    pub fn with_keys(mut self, keys: &'a Keys) -> Self {
+        self.keys = keys;
+        self
+    }
+
+    /// sets the [LookupTables](struct.LookupTables.html) available to the `lookup` action.
+    pub fn with_lookup_tables(mut self, lookup_tables: &'a LookupTables) -> Self {
+        self.lookup_tables = lookup_tables;
+        self
+    }
+
+    /// sets the per-apply variables available to the `var` action.
+    pub fn with_vars(mut self, vars: &'a HashMap<String, Value>) -> Self {
+        self.vars = vars;
+        self
+    }
+
+    /// sets the [Sequences](struct.Sequences.html) used by the `seq` action, by default each
+    /// Context has its own.
+    pub fn with_sequences(mut self, sequences: &'a Sequences) -> Self {
+        self.sequences = Some(sequences);
+        self
+    }
+
+    /// sets the [Limits](struct.Limits.html) to enforce.
+    pub fn with_limits(mut self, limits: Limits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// sets the [Policy](struct.Policy.html) to enforce.
+    pub fn with_policy(mut self, policy: Policy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// sets whether an explicit `null` is set to the destination when the source of a Setter
+    /// returns no Value, eg. when the source path is missing.
+    pub fn with_null_for_missing(mut self, null_for_missing: bool) -> Self {
+        self.null_for_missing = null_for_missing;
+        self
+    }
+
+    /// returns whether an explicit `null` is set to the destination for missing source Values.
+    #[inline]
+    pub fn null_for_missing(&self) -> bool {
+        self.null_for_missing
+    }
+
+    /// sets whether a Setter skips writing to the destination when its source returns `null`,
+    /// taking precedence over [with_null_for_missing](#method.with_null_for_missing).
+    pub fn with_skip_nulls(mut self, skip_nulls: bool) -> Self {
+        self.skip_nulls = skip_nulls;
+        self
+    }
+
+    /// returns whether Setters skip writing `null` Values to the destination.
+    #[inline]
+    pub fn skip_nulls(&self) -> bool {
+        self.skip_nulls
+    }
+
+    /// sets whether every Getter returns an error, rather than no Value, when its path is missing
+    /// or the source is of the wrong type.
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// returns whether every Getter is strict, which is never the case within
+    /// [guarded](#method.guarded) actions.
+    #[inline]
+    pub fn strict(&self) -> bool {
+        self.strict && !self.guarded.get()
+    }
+
+    /// sets the locale tag, eg. `en-CA`, used by locale-aware actions when none is supplied.
+    #[cfg(feature = "i18n")]
+    pub fn with_locale(mut self, locale: &'a str) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// returns the locale tag used by locale-aware actions, preferring the
+    /// [LOCALE_VAR](../i18n/constant.LOCALE_VAR.html) per-apply variable when set.
+    #[cfg(feature = "i18n")]
+    #[inline]
+    pub fn locale(&self) -> &'a str {
+        match self.vars.get(crate::i18n::LOCALE_VAR) {
+            Some(Value::String(locale)) => locale,
+            _ => self.locale,
+        }
+    }
+
+    /// returns an error if the [Capability](enum.Capability.html) is not allowed by the
+    /// [Policy](struct.Policy.html).
+    ///
+    /// Actions with side-effects MUST call this prior to performing them.
+    #[inline]
+    pub fn require(&self, capability: Capability) -> Result<(), Error> {
+        if self.policy.allows(capability) {
+            Ok(())
+        } else {
+            Err(Error::PolicyViolation(capability))
+        }
+    }
+
+    /// returns the [Limits](struct.Limits.html) being enforced.
+    #[inline]
+    pub fn limits(&self) -> &Limits {
+        &self.limits
+    }
+
+    /// records the evaluation of an [Action](../action/trait.Action.html), returning an error if
+    /// the maximum number of evaluations has been exceeded.
+    ///
+    /// All built-in Actions call this at the start of their evaluation and custom Actions should
+    /// do the same.
+    #[inline]
+    pub fn evaluate(&self) -> Result<(), Error> {
+        if let Some(max) = self.limits.max_action_evaluations {
+            let evaluations = self.evaluations.get() + 1;
+            if evaluations > max {
+                return Err(Error::LimitExceeded {
+                    limit: Limit::ActionEvaluations,
+                    max,
+                });
+            }
+            self.evaluations.set(evaluations);
+        }
+        Ok(())
+    }
+
+    /// records a value being written to the destination, returning an error if the maximum
+    /// number of destination nodes has been exceeded.
+    #[inline]
+    pub fn write(&self, value: &Value) -> Result<(), Error> {
+        if let Some(max) = self.limits.max_destination_nodes {
+            let nodes = self.nodes.get() + count_nodes(value);
+            if nodes > max {
+                return Err(Error::LimitExceeded {
+                    limit: Limit::DestinationNodes,
+                    max,
+                });
+            }
+            self.nodes.set(nodes);
+        }
+        Ok(())
+    }
+
+    /// checks the number of `null` elements about to be inserted to pad an Array, returning an
+    /// error if it exceeds the maximum array padding.
+    #[inline]
+    pub fn pad(&self, padding: usize) -> Result<(), Error> {
+        match self.limits.max_array_padding {
+            Some(max) if padding > max => Err(Error::LimitExceeded {
+                limit: Limit::ArrayPadding,
+                max,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// checks the number of characters about to be added to pad a string, returning an error if it
+    /// exceeds the maximum string padding.
+    #[inline]
+    pub fn pad_chars(&self, padding: usize) -> Result<(), Error> {
+        match self.limits.max_string_padding {
+            Some(max) if padding > max => Err(Error::LimitExceeded {
+                limit: Limit::StringPadding,
+                max,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// checks the depth of a namespace about to be traversed, returning an error if it exceeds the
+    /// maximum namespace depth.
+    #[inline]
+    pub fn traverse(&self, depth: usize) -> Result<(), Error> {
+        match self.limits.max_namespace_depth {
+            Some(max) if depth > max => Err(Error::LimitExceeded {
+                limit: Limit::NamespaceDepth,
+                max,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// calls the function with strict mode suspended, restoring it afterwards.
+    ///
+    /// Actions which handle missing Values themselves, eg. `exists`, `coalesce` and conditions,
+    /// MUST call the child actions whose Values may be missing within this.
+    #[inline]
+    pub fn guarded<T, F>(&self, f: F) -> T
+    where
+        F: FnOnce() -> T,
+    {
+        let previous = self.guarded.replace(true);
+        let result = f();
+        self.guarded.set(previous);
+        result
+    }
+
+    /// calls the function with the index of the Array element currently being iterated over,
+    /// restoring the previous index afterwards to support nested iterations.
+    ///
+    /// Actions which iterate over Array elements MUST call their child actions within this.
+    #[inline]
+    pub fn scoped<T, F>(&self, index: usize, f: F) -> T
+    where
+        F: FnOnce() -> T,
+    {
+        let previous = self.index.replace(Some(index));
+        let result = f();
+        self.index.set(previous);
+        result
+    }
+
+    /// returns the index of the Array element currently being iterated over, if any.
+    #[inline]
+    pub fn index(&self) -> Option<usize> {
+        self.index.get()
+    }
+
+    /// returns the [Clock](trait.Clock.html) to be used for the current time.
+    #[inline]
+    pub fn clock(&self) -> &'a dyn Clock {
+        self.clock
+    }
+
+    /// returns the secret key for the id, returning an error if no key has been registered for it.
+    #[inline]
+    pub fn key(&self, id: &str) -> Result<&'a [u8], Error> {
+        self.keys
+            .get(id)
+            .ok_or_else(|| Error::MissingKey(id.to_owned()))
+    }
+
+    /// returns the next value of the named sequence.
+    #[inline]
+    pub fn next_sequence(&self, name: &str) -> u64 {
+        self.sequences.unwrap_or(&self.own_sequences).next(name)
+    }
+
+    /// returns the per-apply variable, if any.
+    #[inline]
+    pub fn var(&self, name: &str) -> Option<&'a Value> {
+        self.vars.get(name)
+    }
+
+    /// returns the named lookup table, returning an error if no table has been registered for it.
+    #[inline]
+    pub fn lookup_table(&self, name: &str) -> Result<&'a HashMap<String, Value>, Error> {
+        self.lookup_tables
+            .get(name)
+            .ok_or_else(|| Error::MissingLookupTable(name.to_owned()))
+    }
+
+    /// returns the [RandomSource](trait.RandomSource.html) to be used for random values.
+    #[inline]
+    pub fn random(&self) -> &'a dyn RandomSource {
+        self.random
+    }
+}
+
+fn count_nodes(value: &Value) -> usize {
+    match value {
+        Value::Array(arr) => 1 + arr.iter().map(count_nodes).sum::<usize>(),
+        Value::Object(o) => 1 + o.values().map(count_nodes).sum::<usize>(),
+        _ => 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::time::Duration;
+
+    #[test]
+    fn scoped() {
+        let ctx = Context::default();
+        assert_eq!(None, ctx.index());
+        ctx.scoped(1, || {
+            assert_eq!(Some(1), ctx.index());
+            ctx.scoped(5, || assert_eq!(Some(5), ctx.index()));
+            assert_eq!(Some(1), ctx.index());
+        });
+        assert_eq!(None, ctx.index());
+    }
+
+    #[test]
+    fn limits() {
+        let ctx = Context::default().with_limits(Limits {
+            max_destination_nodes: Some(5),
+            max_array_padding: Some(10),
+            max_string_padding: Some(20),
+            max_action_evaluations: Some(2),
+            max_namespace_depth: Some(3),
+        });
+        assert!(ctx.evaluate().is_ok());
+        assert!(ctx.evaluate().is_ok());
+        assert!(matches!(
+            ctx.evaluate(),
+            Err(Error::LimitExceeded {
+                limit: Limit::ActionEvaluations,
+                max: 2
+            })
+        ));
+
+        assert!(ctx.write(&json!({"a": [1, 2]})).is_ok());
+        assert!(ctx.write(&json!(1)).is_ok());
+        assert!(matches!(
+            ctx.write(&json!(1)),
+            Err(Error::LimitExceeded {
+                limit: Limit::DestinationNodes,
+                max: 5
+            })
+        ));
+
+        assert!(ctx.pad(10).is_ok());
+        assert!(ctx.pad(11).is_err());
+
+        assert!(ctx.pad_chars(20).is_ok());
+        assert!(matches!(
+            ctx.pad_chars(21),
+            Err(Error::LimitExceeded {
+                limit: Limit::StringPadding,
+                max: 20
+            })
+        ));
+
+        assert!(ctx.traverse(3).is_ok());
+        assert!(ctx.traverse(4).is_err());
+
+        let ctx = Context::default();
+        for _ in 0..100 {
+            assert!(ctx.evaluate().is_ok());
+        }
+    }
+
+    #[test]
+    fn policy() {
+        let ctx = Context::default();
+        assert!(ctx.require(Capability::Environment).is_ok());
+
+        let ctx = Context::default().with_policy(Policy {
+            allow_network: false,
+            ..Policy::default()
+        });
+        assert!(ctx.require(Capability::Environment).is_ok());
+        assert!(matches!(
+            ctx.require(Capability::Network),
+            Err(Error::PolicyViolation(Capability::Network))
+        ));
+
+        let ctx = Context::default().with_policy(Policy::sandboxed());
+        assert!(ctx.require(Capability::Scripting).is_err());
+        assert!(ctx.require(Capability::FileSystem).is_err());
+    }
+
+    #[test]
+    fn seeded_random() {
+        let a = SeededRandom::new(42);
+        let b = SeededRandom::new(42);
+        for _ in 0..10 {
+            let n = a.next_u64();
+            assert_eq!(n, b.next_u64());
+        }
+        let f = a.next_f64();
+        assert!((0.0..1.0).contains(&f));
+        assert_ne!(
+            SeededRandom::new(1).next_u64(),
+            SeededRandom::new(2).next_u64()
+        );
+    }
+
+    #[test]
+    fn fixed_clock() {
+        let time = SystemTime::UNIX_EPOCH + Duration::from_secs(1_600_000_000);
+        let clock = FixedClock::new(time);
+        assert_eq!(time, clock.now());
+        assert_eq!(time, clock.now());
+    }
+
+    #[test]
+    fn sequences() {
+        let ctx = Context::default();
+        assert_eq!(1, ctx.next_sequence("id"));
+        assert_eq!(2, ctx.next_sequence("id"));
+        assert_eq!(1, Context::default().next_sequence("id"));
+
+        let sequences = Sequences::default();
+        assert_eq!(1, sequences.next("id"));
+        let ctx = Context::default().with_sequences(&sequences);
+        assert_eq!(2, ctx.next_sequence("id"));
+        assert_eq!(1, ctx.next_sequence("other"));
+    }
+}
@@ -1,14 +1,41 @@
 //! Errors that can occur applying transformations.
 
+use crate::actions::getter::Error as GetterError;
 use crate::actions::setter::Error as SetterError;
+use crate::context::{Capability, Limit};
 use thiserror::Error;
 
 /// This type represents all possible errors that an occur while building and applying a Transformation.
 #[derive(Error, Debug)]
 pub enum Error {
+    #[error(transparent)]
+    Getter(#[from] GetterError),
+
     #[error(transparent)]
     Setter(#[from] SetterError),
 
     #[error(transparent)]
     JSONError(#[from] serde_json::Error),
+
+    #[error("Transformation limit '{}' of {} exceeded.", limit, max)]
+    LimitExceeded { limit: Limit, max: usize },
+
+    #[error("Policy violation, {0} access is not allowed.")]
+    PolicyViolation(Capability),
+
+    #[error("No key has been registered for id: '{0}'")]
+    MissingKey(String),
+
+    #[error("No lookup table has been registered with name: '{0}'")]
+    MissingLookupTable(String),
+
+    #[cfg(feature = "jmespath")]
+    #[error(transparent)]
+    JmesPath(#[from] jmespath::JmespathError),
+
+    #[error("Unknown locale: '{0}'")]
+    UnknownLocale(String),
+
+    #[error("Destination root is not an Object, found: {0}")]
+    NonObjectRoot(String),
 }
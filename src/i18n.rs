@@ -0,0 +1,358 @@
+//! Locale-aware formatting of numbers, currencies and dates.
+//!
+//! A built-in set of common locales is supported, derived from CLDR but simplified, eg. a negative
+//! sign always precedes the currency symbol. The locale used by the formatting
+//! [Action](../action/trait.Action.html)'s can be supplied explicitly, set per transformation with
+//! [TransformBuilder::locale](../transformer/struct.TransformBuilder.html#method.locale) or set
+//! per apply with the [LOCALE_VAR](constant.LOCALE_VAR.html) context variable.
+
+use crate::actions::datetime::{self, Timestamp};
+use serde_json::Number;
+
+/// The context variable which, when set to a locale tag, overrides the locale of the
+/// transformation for a single apply, see
+/// [apply_with_context](../transformer/struct.Transformer.html#method.apply_with_context).
+pub const LOCALE_VAR: &str = "locale";
+
+/// The locale used when none has been configured.
+pub const DEFAULT_LOCALE: &str = "en-US";
+
+/// This represents where the currency symbol is placed relative to the amount.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SymbolPosition {
+    /// eg. `$1.00`
+    Prefix,
+    /// eg. `R$ 1,00`
+    PrefixSpaced,
+    /// eg. `1,00 €`
+    SuffixSpaced,
+}
+
+/// This type represents the formatting conventions of a locale.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Locale {
+    tag: &'static str,
+    decimal: &'static str,
+    group: &'static str,
+    min_grouping: usize,
+    symbol: SymbolPosition,
+    date: &'static str,
+}
+
+static LOCALES: &[Locale] = &[
+    Locale {
+        tag: "en-US",
+        decimal: ".",
+        group: ",",
+        min_grouping: 1,
+        symbol: SymbolPosition::Prefix,
+        date: "M/d/yyyy",
+    },
+    Locale {
+        tag: "en-CA",
+        decimal: ".",
+        group: ",",
+        min_grouping: 1,
+        symbol: SymbolPosition::Prefix,
+        date: "yyyy-MM-dd",
+    },
+    Locale {
+        tag: "en-GB",
+        decimal: ".",
+        group: ",",
+        min_grouping: 1,
+        symbol: SymbolPosition::Prefix,
+        date: "dd/MM/yyyy",
+    },
+    Locale {
+        tag: "fr-FR",
+        decimal: ",",
+        group: "\u{202f}",
+        min_grouping: 1,
+        symbol: SymbolPosition::SuffixSpaced,
+        date: "dd/MM/yyyy",
+    },
+    Locale {
+        tag: "fr-CA",
+        decimal: ",",
+        group: "\u{a0}",
+        min_grouping: 1,
+        symbol: SymbolPosition::SuffixSpaced,
+        date: "yyyy-MM-dd",
+    },
+    Locale {
+        tag: "de-DE",
+        decimal: ",",
+        group: ".",
+        min_grouping: 1,
+        symbol: SymbolPosition::SuffixSpaced,
+        date: "dd.MM.yyyy",
+    },
+    Locale {
+        tag: "es-ES",
+        decimal: ",",
+        group: ".",
+        min_grouping: 2,
+        symbol: SymbolPosition::SuffixSpaced,
+        date: "d/M/yyyy",
+    },
+    Locale {
+        tag: "it-IT",
+        decimal: ",",
+        group: ".",
+        min_grouping: 1,
+        symbol: SymbolPosition::SuffixSpaced,
+        date: "dd/MM/yyyy",
+    },
+    Locale {
+        tag: "nl-NL",
+        decimal: ",",
+        group: ".",
+        min_grouping: 1,
+        symbol: SymbolPosition::PrefixSpaced,
+        date: "dd-MM-yyyy",
+    },
+    Locale {
+        tag: "pt-BR",
+        decimal: ",",
+        group: ".",
+        min_grouping: 1,
+        symbol: SymbolPosition::PrefixSpaced,
+        date: "dd/MM/yyyy",
+    },
+    Locale {
+        tag: "ja-JP",
+        decimal: ".",
+        group: ",",
+        min_grouping: 1,
+        symbol: SymbolPosition::Prefix,
+        date: "yyyy/MM/dd",
+    },
+];
+
+/// The symbol, and the symbol used within its own region, and number of fraction digits of
+/// currencies by ISO 4217 code. All other codes use the code itself as the symbol.
+static CURRENCIES: &[(&str, &str, &str, usize)] = &[
+    ("AUD", "A$", "$", 2),
+    ("BRL", "R$", "R$", 2),
+    ("CAD", "CA$", "$", 2),
+    ("CHF", "CHF", "CHF", 2),
+    ("CNY", "CN¥", "¥", 2),
+    ("EUR", "€", "€", 2),
+    ("GBP", "£", "£", 2),
+    ("HKD", "HK$", "$", 2),
+    ("INR", "₹", "₹", 2),
+    ("JPY", "¥", "¥", 0),
+    ("KRW", "₩", "₩", 0),
+    ("MXN", "MX$", "$", 2),
+    ("NZD", "NZ$", "$", 2),
+    ("USD", "US$", "$", 2),
+];
+
+/// returns true if the code is a valid ISO 4217 currency code, ie. three uppercase ASCII letters.
+pub fn is_currency(code: &str) -> bool {
+    code.len() == 3 && code.bytes().all(|b| b.is_ascii_uppercase())
+}
+
+impl Locale {
+    /// returns the built-in locale for the tag, eg. `en-CA` or `en_CA`, falling back to the
+    /// default region of the language, eg. `fr` or `fr-BE` return `fr-FR`.
+    pub fn get(tag: &str) -> Option<&'static Locale> {
+        let tag = tag.replace('_', "-");
+        LOCALES
+            .iter()
+            .find(|l| l.tag.eq_ignore_ascii_case(&tag))
+            .or_else(|| {
+                let language = tag.split('-').next()?;
+                LOCALES.iter().find(|l| {
+                    l.tag
+                        .split('-')
+                        .next()
+                        .is_some_and(|lang| lang.eq_ignore_ascii_case(language))
+                })
+            })
+    }
+
+    /// returns the tag of the locale, eg. `en-CA`.
+    #[inline]
+    pub fn tag(&self) -> &'static str {
+        self.tag
+    }
+
+    fn region(&self) -> &'static str {
+        self.tag.split('-').nth(1).unwrap_or_default()
+    }
+
+    /// formats the number with grouping and up to 3 fraction digits, eg. `1,234.568`.
+    pub fn format_number(&self, n: &Number) -> String {
+        let s = decimal(n, 3);
+        let s = if s.contains('.') {
+            s.trim_end_matches('0').trim_end_matches('.')
+        } else {
+            &s
+        };
+        self.localize(s)
+    }
+
+    /// formats the number as an amount of the currency, eg. `$1,234.50` or `1 234,50 $`.
+    pub fn format_currency(&self, code: &str, n: &Number) -> String {
+        let (symbol, digits) = match CURRENCIES.iter().find(|c| c.0 == code) {
+            Some((_, _, local, digits)) if code.starts_with(self.region()) => (*local, *digits),
+            Some((_, symbol, _, digits)) => (*symbol, *digits),
+            None => (code, 2),
+        };
+        let s = decimal(n, digits);
+        let (sign, s) = match s.strip_prefix('-') {
+            Some(s) => ("-", s),
+            None => ("", s.as_str()),
+        };
+        let amount = self.localize(s);
+        match self.symbol {
+            SymbolPosition::Prefix if symbol.ends_with(|c: char| c.is_alphabetic()) => {
+                format!("{}{}\u{a0}{}", sign, symbol, amount)
+            }
+            SymbolPosition::Prefix => format!("{}{}{}", sign, symbol, amount),
+            SymbolPosition::PrefixSpaced => format!("{}{}\u{a0}{}", sign, symbol, amount),
+            SymbolPosition::SuffixSpaced => format!("{}{}\u{a0}{}", sign, amount, symbol),
+        }
+    }
+
+    /// formats the date, in UTC, of the timestamp eg. `1/31/2020` or `31.01.2020`.
+    pub(crate) fn format_date(&self, ts: Timestamp) -> String {
+        let (year, month, day) = datetime::civil_from_days(ts.secs.div_euclid(86_400));
+        let mut s = String::new();
+        let mut chars = self.date.chars().peekable();
+        while let Some(c) = chars.next() {
+            let mut width = 1;
+            while chars.peek() == Some(&c) {
+                chars.next();
+                width += 1;
+            }
+            let n = match c {
+                'y' => year,
+                'M' => month,
+                'd' => day,
+                _ => {
+                    (0..width).for_each(|_| s.push(c));
+                    continue;
+                }
+            };
+            s.push_str(&format!("{:0width$}", n, width = width));
+        }
+        s
+    }
+
+    /// applies the grouping and decimal separators of the locale to a plain decimal string.
+    fn localize(&self, s: &str) -> String {
+        let (sign, s) = match s.strip_prefix('-') {
+            Some(s) => ("-", s),
+            None => ("", s),
+        };
+        let (int, fraction) = match s.split_once('.') {
+            Some((int, fraction)) => (int, Some(fraction)),
+            None => (s, None),
+        };
+        let mut result = String::from(sign);
+        if int.len() > 3 + self.min_grouping - 1 {
+            for (i, c) in int.chars().enumerate() {
+                if i > 0 && (int.len() - i) % 3 == 0 {
+                    result.push_str(self.group);
+                }
+                result.push(c);
+            }
+        } else {
+            result.push_str(int);
+        }
+        if let Some(fraction) = fraction {
+            result.push_str(self.decimal);
+            result.push_str(fraction);
+        }
+        result
+    }
+}
+
+/// returns the number as a plain decimal string, with exactly `digits` fraction digits unless it
+/// is an integer and no digits are required.
+fn decimal(n: &Number, digits: usize) -> String {
+    if n.is_i64() || n.is_u64() {
+        let s = n.to_string();
+        if digits == 0 {
+            s
+        } else {
+            format!("{}.{}", s, "0".repeat(digits))
+        }
+    } else {
+        let s = format!("{:.*}", digits, n.as_f64().unwrap_or_default());
+        // avoid formatting negative zero, eg. `-0.00`.
+        if s.starts_with('-') && s.bytes().all(|b| matches!(b, b'-' | b'0' | b'.')) {
+            s[1..].to_owned()
+        } else {
+            s
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locales() {
+        assert_eq!(Locale::get("en_ca").map(Locale::tag), Some("en-CA"));
+        assert_eq!(Locale::get("fr").map(Locale::tag), Some("fr-FR"));
+        assert_eq!(Locale::get("de-AT").map(Locale::tag), Some("de-DE"));
+        assert_eq!(Locale::get("xx-XX"), None);
+    }
+
+    #[test]
+    fn numbers() {
+        let n = |s: &str| -> Number { serde_json::from_str(s).unwrap() };
+        for (tag, value, expected) in &[
+            ("en-US", "1234567.891", "1,234,567.891"),
+            ("en-US", "-1234.5", "-1,234.5"),
+            ("en-US", "0.00001", "0"),
+            ("de-DE", "1234.5", "1.234,5"),
+            ("fr-FR", "1234", "1\u{202f}234"),
+            ("es-ES", "1234", "1234"),
+            ("es-ES", "12345", "12.345"),
+        ] {
+            assert_eq!(
+                Locale::get(tag).unwrap().format_number(&n(value)),
+                *expected
+            );
+        }
+    }
+
+    #[test]
+    fn currencies() {
+        let n = |s: &str| -> Number { serde_json::from_str(s).unwrap() };
+        for (tag, code, value, expected) in &[
+            ("en-CA", "CAD", "1234.5", "$1,234.50"),
+            ("en-US", "CAD", "1234.5", "CA$1,234.50"),
+            ("en-CA", "USD", "-3", "-US$3.00"),
+            ("fr-CA", "CAD", "1234.5", "1\u{a0}234,50\u{a0}$"),
+            ("de-DE", "EUR", "0.126", "0,13\u{a0}€"),
+            ("pt-BR", "BRL", "10", "R$\u{a0}10,00"),
+            ("ja-JP", "JPY", "1234.5", "¥1,234"),
+            ("en-US", "XYZ", "1", "XYZ\u{a0}1.00"),
+        ] {
+            assert_eq!(
+                Locale::get(tag).unwrap().format_currency(code, &n(value)),
+                *expected
+            );
+        }
+    }
+
+    #[test]
+    fn dates() {
+        let ts = datetime::parse_rfc3339("2020-01-05T23:30:00Z").unwrap();
+        for (tag, expected) in &[
+            ("en-US", "1/5/2020"),
+            ("en-CA", "2020-01-05"),
+            ("de-DE", "05.01.2020"),
+            ("es-ES", "5/1/2020"),
+        ] {
+            assert_eq!(Locale::get(tag).unwrap().format_date(ts), *expected);
+        }
+    }
+}
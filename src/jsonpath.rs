@@ -0,0 +1,182 @@
+//! Compilation of JSONPath expressions into [Getter](../actions/struct.Getter.html) namespaces.
+//!
+//! This allows existing JSONPath based configurations to be used as the source of a transformation
+//! with the `jsonpath` action eg. `jsonpath("$.store.book[*].author")`.
+//!
+//! The subset of JSONPath supported is that which can be represented by a Getter:
+//! * `$` the root of the source.
+//! * `.key`, `['key']` and `["key"]` an Object key.
+//! * `['a','b']` multiple Object keys, see
+//!   [MultiKey](../actions/getter/namespace/enum.Namespace.html#variant.MultiKey).
+//! * `[0]` an Array index.
+//! * `[*]` and `.*` every element of an Array, see
+//!   [WildcardArray](../actions/getter/namespace/enum.Namespace.html#variant.WildcardArray).
+//!
+//! Recursive descent `..`, filters `[?(...)]`, slices `[0:2]`, unions of indexes `[0,1]` and
+//! negative indexes are not supported and return an [Error](enum.Error.html).
+
+use crate::actions::getter::namespace::Namespace;
+use thiserror::Error;
+
+/// This type represents all possible errors that can occur while compiling a JSONPath expression.
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("Invalid JSONPath: {path}. {err}")]
+    InvalidSyntax { path: String, err: String },
+
+    #[error("Unsupported JSONPath: {path}. {feature} cannot be represented by a Getter.")]
+    Unsupported { path: String, feature: String },
+}
+
+/// compiles a JSONPath expression eg. `$.store.book[*].author` into an Vec of
+/// [Namespace](../actions/getter/namespace/enum.Namespace.html)'s for use in the
+/// [Getter](../actions/struct.Getter.html).
+pub fn compile(path: &str) -> Result<Vec<Namespace>, Error> {
+    let invalid = |err: &str| Error::InvalidSyntax {
+        path: path.to_owned(),
+        err: err.to_owned(),
+    };
+    let unsupported = |feature: &str| Error::Unsupported {
+        path: path.to_owned(),
+        feature: feature.to_owned(),
+    };
+
+    let mut rest = path
+        .trim()
+        .strip_prefix('$')
+        .ok_or_else(|| invalid("A JSONPath must start with '$'."))?;
+    let mut namespaces = Vec::new();
+
+    while !rest.is_empty() {
+        if rest.starts_with("..") {
+            return Err(unsupported("Recursive descent '..'"));
+        }
+        if let Some(r) = rest.strip_prefix('.') {
+            let end = r.find(['.', '[']).unwrap_or(r.len());
+            let key = &r[..end];
+            match key {
+                "" => return Err(invalid("A '.' must be followed by a key.")),
+                "*" => namespaces.push(Namespace::WildcardArray),
+                _ => namespaces.push(Namespace::Object { id: key.to_owned() }),
+            }
+            rest = &r[end..];
+            continue;
+        }
+        let r = rest
+            .strip_prefix('[')
+            .ok_or_else(|| invalid("Expected '.' or '['."))?;
+        if r.starts_with(['\'', '"']) {
+            let mut ids = Vec::new();
+            let mut r = r;
+            loop {
+                let quote = r
+                    .chars()
+                    .next()
+                    .filter(|c| *c == '\'' || *c == '"')
+                    .ok_or_else(|| invalid("A ',' must be followed by a quoted key."))?;
+                let (key, len) = quoted(&r[1..], quote)
+                    .ok_or_else(|| invalid("A quoted key must be closed by a matching quote."))?;
+                ids.push(key);
+                r = r[1 + len..].trim_start();
+                match r.strip_prefix(',') {
+                    Some(next) => r = next.trim_start(),
+                    None => break,
+                }
+            }
+            rest = r
+                .strip_prefix(']')
+                .ok_or_else(|| invalid("A quoted key must be followed by ']'."))?;
+            namespaces.push(match ids.len() {
+                1 => Namespace::Object {
+                    id: ids.pop().unwrap(), // unwrap safe, has exactly one key
+                },
+                _ => Namespace::MultiKey { ids },
+            });
+            continue;
+        }
+        let end = r
+            .find(']')
+            .ok_or_else(|| invalid("Missing end bracket ']'."))?;
+        let selector = r[..end].trim();
+        rest = &r[end + 1..];
+        match selector {
+            "*" => namespaces.push(Namespace::WildcardArray),
+            s if s.starts_with('?') => return Err(unsupported("Filter '[?()]'")),
+            s if s.starts_with('(') => return Err(unsupported("Script '[()]'")),
+            s if s.contains(':') => return Err(unsupported("Slice '[:]'")),
+            s if s.contains(',') => return Err(unsupported("Union '[,]'")),
+            s if s.starts_with('-') => return Err(unsupported("Negative index")),
+            s => namespaces.push(Namespace::Array {
+                index: s
+                    .parse()
+                    .map_err(|_| invalid("An index must be a non-negative integer."))?,
+            }),
+        }
+    }
+    Ok(namespaces)
+}
+
+/// returns the unescaped content up to the closing quote and the number of bytes consumed,
+/// including the closing quote.
+fn quoted(s: &str, quote: char) -> Option<(String, usize)> {
+    let mut key = String::new();
+    let mut chars = s.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' => key.push(chars.next()?.1),
+            c if c == quote => return Some((key, i + 1)),
+            c => key.push(c),
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compiles() {
+        let results = compile(r#"$.store.book[*]['author'].*["a\"b"][2]['c', "d"]"#).unwrap();
+        let expected = vec![
+            Namespace::Object {
+                id: "store".to_owned(),
+            },
+            Namespace::Object {
+                id: "book".to_owned(),
+            },
+            Namespace::WildcardArray,
+            Namespace::Object {
+                id: "author".to_owned(),
+            },
+            Namespace::WildcardArray,
+            Namespace::Object {
+                id: "a\"b".to_owned(),
+            },
+            Namespace::Array { index: 2 },
+            Namespace::MultiKey {
+                ids: vec!["c".to_owned(), "d".to_owned()],
+            },
+        ];
+        assert_eq!(expected, results);
+        assert_eq!(compile("$").unwrap(), Vec::new());
+    }
+
+    #[test]
+    fn errors() {
+        for path in &["store", "$.", "$['a'", "$['a',]", "$[1", "$[a]", "$x"] {
+            let actual = matches!(compile(path), Err(Error::InvalidSyntax { .. }));
+            assert!(actual, "{}", path);
+        }
+        for path in &[
+            "$..author",
+            "$.book[?(@.price < 10)]",
+            "$.book[0:2]",
+            "$.book[0,1]",
+            "$.book[-1]",
+        ] {
+            let actual = matches!(compile(path), Err(Error::Unsupported { .. }));
+            assert!(actual, "{}", path);
+        }
+    }
+}
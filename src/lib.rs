@@ -140,12 +140,20 @@
 //!
 pub mod action;
 pub mod actions;
+pub mod canonical;
+pub mod context;
 pub mod errors;
+#[cfg(feature = "i18n")]
+pub mod i18n;
+#[cfg(feature = "jsonpath")]
+pub mod jsonpath;
+pub mod lineage;
 pub mod parser;
+pub mod testing;
 pub mod transformer;
 
 #[doc(inline)]
-pub use parser::{Parsable, Parser, COMMA_SEP_RE, QUOTED_STR_RE};
+pub use parser::{Destination, Parsable, Parser, SpecEntry, COMMA_SEP_RE, QUOTED_STR_RE};
 
 #[doc(inline)]
 pub use transformer::TransformBuilder;
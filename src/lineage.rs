@@ -0,0 +1,89 @@
+//! Lineage of the data written by a transformation, for generating data-lineage documentation
+//! and analysing the impact of changes to source data.
+
+use crate::action::Action;
+use serde::{Deserialize, Serialize};
+
+/// This type represents the lineage of a single destination path written by a transformation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Lineage {
+    /// the source paths read to produce the destination, in order of first use.
+    pub sources: Vec<String>,
+
+    /// the destination path written.
+    pub destination: String,
+
+    /// the names of the actions involved in producing the destination, in order of first use.
+    pub actions: Vec<String>,
+}
+
+/// returns the [Lineage](struct.Lineage.html) of every destination path written by the actions.
+///
+/// Source paths read and actions applied outside of the Setter, such as a condition deciding
+/// whether it's applied, are included in its lineage.
+pub fn lineage(actions: &[Box<dyn Action>]) -> Vec<Lineage> {
+    let mut lineage = Vec::new();
+    for action in actions {
+        walk(action.as_ref(), &Usage::default(), &mut lineage);
+    }
+    lineage
+}
+
+#[derive(Default, Clone)]
+struct Usage {
+    sources: Vec<String>,
+    actions: Vec<String>,
+}
+
+impl Usage {
+    fn add(&mut self, action: &dyn Action) {
+        push_unique(&mut self.actions, action.typetag_name().to_owned());
+        for source in action.source_paths() {
+            push_unique(&mut self.sources, source);
+        }
+        for child in action.children() {
+            self.add(child);
+        }
+    }
+}
+
+fn walk(action: &dyn Action, inherited: &Usage, lineage: &mut Vec<Lineage>) {
+    let destinations = action.destination_paths();
+    if !destinations.is_empty() {
+        let mut usage = inherited.clone();
+        usage.add(action);
+        for destination in destinations {
+            lineage.push(Lineage {
+                sources: usage.sources.clone(),
+                destination,
+                actions: usage.actions.clone(),
+            });
+        }
+        return;
+    }
+
+    let (writers, others): (Vec<&dyn Action>, Vec<&dyn Action>) =
+        action.children().into_iter().partition(|c| writes(*c));
+    let mut usage = inherited.clone();
+    push_unique(&mut usage.actions, action.typetag_name().to_owned());
+    for source in action.source_paths() {
+        push_unique(&mut usage.sources, source);
+    }
+    for other in others {
+        usage.add(other);
+    }
+    for writer in writers {
+        walk(writer, &usage, lineage);
+    }
+}
+
+fn writes(action: &dyn Action) -> bool {
+    !action.destination_paths().is_empty() || action.children().into_iter().any(writes)
+}
+
+#[inline]
+fn push_unique(values: &mut Vec<String>, value: String) {
+    if !values.contains(&value) {
+        values.push(value);
+    }
+}
@@ -1,8 +1,231 @@
 use crate::action::Action;
-use crate::actions::{Constant, Join, Len, Strip, StripType, Sum, Trim, TrimType};
-use crate::parser::Error;
-use crate::{Parser, COMMA_SEP_RE, QUOTED_STR_RE};
+use crate::actions::getter::namespace::Namespace as GetterNamespace;
+use crate::actions::Getter;
+#[cfg(feature = "uuid")]
+use crate::actions::Uuid;
+use crate::actions::{
+    parse_duration, parse_template, Affix, AffixType, Aggregate, AggregateType, Arithmetic,
+    ArithmeticType, Case, ChangeCase, ChangeCaseType, Chunk, Coalesce, Compare, CompareType,
+    Concat, Constant, Convert, ConvertCase, ConvertCaseType, ConvertKeys, ConvertType, DateAdd,
+    Diff, Element, ElementType, Enumerate, Env, Epoch, EpochType, EpochUnit, Exists, Filter,
+    Flatten, FlattenKeys, FlattenKeysType, Format, FormatSegment, GroupBy, If, Index, IndexOf,
+    Join, Len, LenType, Logic, LogicType, Lookup, Map, Members, MembersType, Not, Now, Pad,
+    PadType, Pattern, Prune, PruneOptions, Reduce, RegexExtract, RegexGroup, RegexReplace,
+    RenameKeys, Replace, Reverse, Round, RoundType, SelectKeys, SelectKeysType, Seq, Slice, Sort,
+    SortOrder, Strip, StripType, Substring, Sum, Switch, TimeFormat, Trim, TrimType, Truncate,
+    Unique, Var, When,
+};
+#[cfg(feature = "base64")]
+use crate::actions::{Base64, Base64Type};
+#[cfg(feature = "hash")]
+use crate::actions::{Digest, DigestType, Hmac};
+#[cfg(feature = "inflector")]
+use crate::actions::{Inflect, InflectType};
+#[cfg(feature = "jmespath")]
+use crate::actions::{JmesPath, JmesPathExpression};
+#[cfg(feature = "i18n")]
+use crate::actions::{Localize, LocalizeType};
+#[cfg(feature = "rand")]
+use crate::actions::{RandomChoice, RandomInt};
+use crate::parser::{check_namespace_depth, Error, ALLOW_FILE_SYSTEM};
+use crate::{Parser, QUOTED_STR_RE};
 use serde_json::Value;
+use std::cell::Cell;
+
+pub(super) fn parse_min(val: &str) -> Result<Box<dyn Action>, Error> {
+    parse_aggregate("min", AggregateType::Min, val)
+}
+
+pub(super) fn parse_max(val: &str) -> Result<Box<dyn Action>, Error> {
+    parse_aggregate("max", AggregateType::Max, val)
+}
+
+pub(super) fn parse_avg(val: &str) -> Result<Box<dyn Action>, Error> {
+    parse_aggregate("avg", AggregateType::Avg, val)
+}
+
+fn parse_aggregate(name: &str, r#type: AggregateType, val: &str) -> Result<Box<dyn Action>, Error> {
+    let values = parse_actions(val)?;
+    if values.is_empty() {
+        return Err(Error::InvalidNumberOfProperties(name.to_owned()));
+    }
+    Ok(Box::new(Aggregate::new(r#type, values)))
+}
+
+pub(super) fn parse_round(val: &str) -> Result<Box<dyn Action>, Error> {
+    parse_rounding("round", RoundType::Round, val)
+}
+
+pub(super) fn parse_floor(val: &str) -> Result<Box<dyn Action>, Error> {
+    parse_rounding("floor", RoundType::Floor, val)
+}
+
+pub(super) fn parse_ceil(val: &str) -> Result<Box<dyn Action>, Error> {
+    parse_rounding("ceil", RoundType::Ceil, val)
+}
+
+fn parse_rounding(name: &str, r#type: RoundType, val: &str) -> Result<Box<dyn Action>, Error> {
+    match split_args(val).as_slice() {
+        [action] => Ok(Box::new(Round::new(
+            r#type,
+            0,
+            Parser::parse_action(action)?,
+        ))),
+        [precision, action] => {
+            let precision = serde_json::from_str(precision.trim())
+                .ok()
+                .filter(|p| *p <= Round::MAX_PRECISION)
+                .ok_or_else(|| Error::InvalidPrecision(precision.to_string()))?;
+            Ok(Box::new(Round::new(
+                r#type,
+                precision,
+                Parser::parse_action(action)?,
+            )))
+        }
+        _ => Err(Error::InvalidNumberOfProperties(name.to_owned())),
+    }
+}
+
+pub(super) fn parse_sub(val: &str) -> Result<Box<dyn Action>, Error> {
+    parse_arithmetic("sub", ArithmeticType::Sub, val)
+}
+
+pub(super) fn parse_mul(val: &str) -> Result<Box<dyn Action>, Error> {
+    parse_arithmetic("mul", ArithmeticType::Mul, val)
+}
+
+pub(super) fn parse_product(val: &str) -> Result<Box<dyn Action>, Error> {
+    parse_arithmetic("product", ArithmeticType::Mul, val)
+}
+
+pub(super) fn parse_div(val: &str) -> Result<Box<dyn Action>, Error> {
+    parse_arithmetic("div", ArithmeticType::Div, val)
+}
+
+pub(super) fn parse_mod(val: &str) -> Result<Box<dyn Action>, Error> {
+    parse_arithmetic("mod", ArithmeticType::Mod, val)
+}
+
+fn parse_arithmetic(
+    name: &str,
+    r#type: ArithmeticType,
+    val: &str,
+) -> Result<Box<dyn Action>, Error> {
+    let values = parse_actions(val)?;
+    if values.is_empty() {
+        return Err(Error::InvalidNumberOfProperties(name.to_owned()));
+    }
+    Ok(Box::new(Arithmetic::new(r#type, values)))
+}
+
+pub(super) fn parse_starts_with(val: &str) -> Result<Box<dyn Action>, Error> {
+    parse_affix("starts_with", AffixType::StartsWith, val)
+}
+
+pub(super) fn parse_ends_with(val: &str) -> Result<Box<dyn Action>, Error> {
+    parse_affix("ends_with", AffixType::EndsWith, val)
+}
+
+fn parse_affix(name: &str, r#type: AffixType, val: &str) -> Result<Box<dyn Action>, Error> {
+    let mut actions = parse_actions(val)?;
+    if actions.len() != 2 {
+        return Err(Error::InvalidNumberOfProperties(name.to_owned()));
+    }
+    let affix = actions.pop().unwrap();
+    let action = actions.pop().unwrap();
+    Ok(Box::new(Affix::new(r#type, action, affix)))
+}
+
+pub(super) fn parse_and(val: &str) -> Result<Box<dyn Action>, Error> {
+    parse_logic("and", LogicType::And, val)
+}
+
+pub(super) fn parse_or(val: &str) -> Result<Box<dyn Action>, Error> {
+    parse_logic("or", LogicType::Or, val)
+}
+
+fn parse_logic(name: &str, r#type: LogicType, val: &str) -> Result<Box<dyn Action>, Error> {
+    let actions = parse_actions(val)?;
+    if actions.is_empty() {
+        return Err(Error::InvalidNumberOfProperties(name.to_owned()));
+    }
+    Ok(Box::new(Logic::new(r#type, actions)))
+}
+
+pub(super) fn parse_not(val: &str) -> Result<Box<dyn Action>, Error> {
+    let action = Parser::parse_action(val)?;
+    Ok(Box::new(Not::new(action)))
+}
+
+pub(super) fn parse_chunk(val: &str) -> Result<Box<dyn Action>, Error> {
+    match split_args(val).as_slice() {
+        [size, action] => {
+            let size: usize = serde_json::from_str(size.trim())?;
+            if size == 0 {
+                return Err(Error::InvalidChunkSize(format!("chunk({})", val)));
+            }
+            Ok(Box::new(Chunk::new(size, Parser::parse_action(action)?)))
+        }
+        _ => Err(Error::InvalidNumberOfProperties("chunk".to_owned())),
+    }
+}
+
+pub(super) fn parse_coalesce(val: &str) -> Result<Box<dyn Action>, Error> {
+    parse_coalesce_named("coalesce", val)
+}
+
+pub(super) fn parse_default(val: &str) -> Result<Box<dyn Action>, Error> {
+    parse_coalesce_named("default", val)
+}
+
+fn parse_coalesce_named(name: &str, val: &str) -> Result<Box<dyn Action>, Error> {
+    if val.trim().is_empty() {
+        return Err(Error::InvalidNumberOfProperties(name.to_owned()));
+    }
+    Ok(Box::new(Coalesce::new(parse_actions(val)?)))
+}
+
+pub(super) fn parse_eq(val: &str) -> Result<Box<dyn Action>, Error> {
+    parse_compare("eq", CompareType::Eq, val)
+}
+
+pub(super) fn parse_ne(val: &str) -> Result<Box<dyn Action>, Error> {
+    parse_compare("ne", CompareType::Ne, val)
+}
+
+pub(super) fn parse_gt(val: &str) -> Result<Box<dyn Action>, Error> {
+    parse_compare("gt", CompareType::Gt, val)
+}
+
+pub(super) fn parse_gte(val: &str) -> Result<Box<dyn Action>, Error> {
+    parse_compare("gte", CompareType::Gte, val)
+}
+
+pub(super) fn parse_lt(val: &str) -> Result<Box<dyn Action>, Error> {
+    parse_compare("lt", CompareType::Lt, val)
+}
+
+pub(super) fn parse_lte(val: &str) -> Result<Box<dyn Action>, Error> {
+    parse_compare("lte", CompareType::Lte, val)
+}
+
+fn parse_compare(name: &str, r#type: CompareType, val: &str) -> Result<Box<dyn Action>, Error> {
+    let mut actions = parse_actions(val)?;
+    if actions.len() != 2 {
+        return Err(Error::InvalidNumberOfProperties(name.to_owned()));
+    }
+    let right = actions.pop().unwrap();
+    let left = actions.pop().unwrap();
+    Ok(Box::new(Compare::new(r#type, left, right)))
+}
+
+pub(super) fn parse_concat(val: &str) -> Result<Box<dyn Action>, Error> {
+    let values = parse_actions(val)?;
+    if values.is_empty() {
+        return Err(Error::InvalidNumberOfProperties("concat".to_owned()));
+    }
+    Ok(Box::new(Concat::new(values)))
+}
 
 pub(super) fn parse_const(val: &str) -> Result<Box<dyn Action>, Error> {
     if val.is_empty() {
@@ -13,56 +236,609 @@ pub(super) fn parse_const(val: &str) -> Result<Box<dyn Action>, Error> {
     }
 }
 
-pub(super) fn parse_join(val: &str) -> Result<Box<dyn Action>, Error> {
-    let sep_len;
-    let sep = match QUOTED_STR_RE.find(val) {
-        Some(cap) => {
-            let s = cap.as_str();
-            sep_len = s.len();
-            let s = s[..s.len() - 1].trim(); // strip ',' and trim any whitespace
-            s[1..s.len() - 1].to_string() // remove '"" double quotes from beginning and end.
-        }
-        None => {
-            return Err(Error::InvalidQuotedValue(format!("join({})", val)));
+pub(super) fn parse_const_file(val: &str) -> Result<Box<dyn Action>, Error> {
+    let path: String = serde_json::from_str(val.trim())
+        .map_err(|_| Error::InvalidQuotedValue(format!("const_file({})", val)))?;
+    if !ALLOW_FILE_SYSTEM.with(Cell::get) {
+        return Err(Error::FileSystemDenied(path));
+    }
+    let data = std::fs::read_to_string(&path).map_err(|err| Error::ReadFile {
+        path: path.clone(),
+        err,
+    })?;
+    let value = if path.ends_with(".json") {
+        serde_json::from_str(&data)?
+    } else {
+        Value::String(data)
+    };
+    Ok(Box::new(Constant::new(value)))
+}
+
+pub(super) fn parse_convert_case(val: &str) -> Result<Box<dyn Action>, Error> {
+    let (case, val) = quoted_arg("convert_case", val)?;
+    let action = Parser::parse_action(val.trim())?;
+    Ok(Box::new(ConvertCase::new(case_type(&case)?, action)))
+}
+
+pub(super) fn parse_convert_keys(val: &str) -> Result<Box<dyn Action>, Error> {
+    let (case, val) = quoted_arg("convert_keys", val)?;
+    let action = Parser::parse_action(val.trim())?;
+    Ok(Box::new(ConvertKeys::new(case_type(&case)?, action)))
+}
+
+fn case_type(case: &str) -> Result<ConvertCaseType, Error> {
+    match case {
+        "snake" => Ok(ConvertCaseType::Snake),
+        "camel" => Ok(ConvertCaseType::Camel),
+        "kebab" => Ok(ConvertCaseType::Kebab),
+        "pascal" => Ok(ConvertCaseType::Pascal),
+        _ => Err(Error::InvalidCaseType(case.to_owned())),
+    }
+}
+
+pub(super) fn parse_date_add(val: &str) -> Result<Box<dyn Action>, Error> {
+    let (duration, val) = quoted_arg("date_add", val)?;
+    let millis = parse_duration(&duration).ok_or(Error::InvalidDuration(duration))?;
+    let action = Parser::parse_action(val.trim())?;
+    Ok(Box::new(DateAdd::new(millis, action)))
+}
+
+pub(super) fn parse_diff(val: &str) -> Result<Box<dyn Action>, Error> {
+    let mut values = parse_actions(val)?;
+    if values.len() != 2 {
+        return Err(Error::InvalidNumberOfProperties("diff".to_owned()));
+    }
+    let to = values.pop().unwrap(); // unwrap safe, length checked above
+    let from = values.pop().unwrap();
+    Ok(Box::new(Diff::new(from, to)))
+}
+
+pub(super) fn parse_to_epoch(val: &str) -> Result<Box<dyn Action>, Error> {
+    parse_epoch("to_epoch", EpochType::To, val)
+}
+
+pub(super) fn parse_from_epoch(val: &str) -> Result<Box<dyn Action>, Error> {
+    parse_epoch("from_epoch", EpochType::From, val)
+}
+
+pub(super) fn parse_enumerate(val: &str) -> Result<Box<dyn Action>, Error> {
+    let (action, key) = match split_args(val).as_slice() {
+        [action] => (*action, None),
+        [action, key] => match serde_json::from_str::<String>(key.trim()) {
+            Ok(key) => (*action, Some(key)),
+            Err(_) => return Err(Error::InvalidQuotedValue(format!("enumerate({})", val))),
+        },
+        _ => return Err(Error::InvalidNumberOfProperties("enumerate".to_owned())),
+    };
+    let action = Parser::parse_action(action)?;
+    Ok(Box::new(Enumerate::new(key, action)))
+}
+
+pub(super) fn parse_env(val: &str) -> Result<Box<dyn Action>, Error> {
+    let args = split_args(val.trim());
+    let (name, default) = match args.as_slice() {
+        [name] => (name, None),
+        [name, default] => (name, Some(serde_json::from_str(default.trim())?)),
+        _ => return Err(Error::InvalidNumberOfProperties("env".to_owned())),
+    };
+    let name: String = serde_json::from_str(name.trim())
+        .map_err(|_| Error::InvalidQuotedValue(format!("env({})", val)))?;
+    Ok(Box::new(Env::new(name, default)))
+}
+
+fn parse_epoch(name: &str, r#type: EpochType, val: &str) -> Result<Box<dyn Action>, Error> {
+    let (action, unit) = match split_args(val).as_slice() {
+        [action] => (Parser::parse_action(action)?, EpochUnit::Seconds),
+        [action, unit] => {
+            let unit: String = serde_json::from_str(unit.trim())
+                .map_err(|_| Error::InvalidQuotedValue(format!("{}({})", name, val)))?;
+            let unit = match unit.as_str() {
+                "s" => EpochUnit::Seconds,
+                "ms" => EpochUnit::Milliseconds,
+                _ => return Err(Error::InvalidTimeUnit(unit)),
+            };
+            (Parser::parse_action(action)?, unit)
         }
+        _ => return Err(Error::InvalidNumberOfProperties(name.to_owned())),
     };
+    Ok(Box::new(Epoch::new(r#type, unit, action)))
+}
 
-    let sub_matches = COMMA_SEP_RE.captures_iter(&val[sep_len..]);
-    let mut values = Vec::new();
-    for m in sub_matches {
-        match m.get(0) {
-            Some(m) => values.push(Parser::parse_action(m.as_str().trim())?),
-            None => continue,
-        };
+pub(super) fn parse_exists(val: &str) -> Result<Box<dyn Action>, Error> {
+    let action = Parser::parse_action(val)?;
+    Ok(Box::new(Exists::new(action)))
+}
+
+pub(super) fn parse_filter(val: &str) -> Result<Box<dyn Action>, Error> {
+    let mut actions = parse_actions(val)?;
+    if actions.len() != 2 {
+        return Err(Error::InvalidNumberOfProperties("filter".to_owned()));
+    }
+    let predicate = actions.pop().unwrap();
+    let action = actions.pop().unwrap();
+    Ok(Box::new(Filter::new(action, predicate)))
+}
+
+pub(super) fn parse_first(val: &str) -> Result<Box<dyn Action>, Error> {
+    let action = Parser::parse_action(val)?;
+    Ok(Box::new(Element::new(ElementType::First, action)))
+}
+
+pub(super) fn parse_last(val: &str) -> Result<Box<dyn Action>, Error> {
+    let action = Parser::parse_action(val)?;
+    Ok(Box::new(Element::new(ElementType::Last, action)))
+}
+
+pub(super) fn parse_flatten(val: &str) -> Result<Box<dyn Action>, Error> {
+    match split_args(val).as_slice() {
+        [action] => Ok(Box::new(Flatten::new(1, Parser::parse_action(action)?))),
+        [action, depth] => Ok(Box::new(Flatten::new(
+            serde_json::from_str(depth.trim())?,
+            Parser::parse_action(action)?,
+        ))),
+        _ => Err(Error::InvalidNumberOfProperties("flatten".to_owned())),
+    }
+}
+
+pub(super) fn parse_flatten_keys(val: &str) -> Result<Box<dyn Action>, Error> {
+    parse_flatten_keys_type("flatten_keys", FlattenKeysType::Flatten, val)
+}
+
+pub(super) fn parse_unflatten_keys(val: &str) -> Result<Box<dyn Action>, Error> {
+    parse_flatten_keys_type("unflatten_keys", FlattenKeysType::Unflatten, val)
+}
+
+fn parse_flatten_keys_type(
+    name: &str,
+    r#type: FlattenKeysType,
+    val: &str,
+) -> Result<Box<dyn Action>, Error> {
+    let (action, separator) = match split_args(val).as_slice() {
+        [action] => (*action, ".".to_owned()),
+        [action, separator] => match serde_json::from_str::<String>(separator.trim()) {
+            Ok(separator) if !separator.is_empty() => (*action, separator),
+            _ => return Err(Error::InvalidQuotedValue(format!("{}({})", name, val))),
+        },
+        _ => return Err(Error::InvalidNumberOfProperties(name.to_owned())),
+    };
+    let action = Parser::parse_action(action)?;
+    Ok(Box::new(FlattenKeys::new(r#type, separator, action)))
+}
+
+pub(super) fn parse_format(val: &str) -> Result<Box<dyn Action>, Error> {
+    let mut args = split_args(val).into_iter();
+    let template: String = match args.next() {
+        Some(template) => serde_json::from_str(template.trim())
+            .map_err(|_| Error::InvalidQuotedValue(format!("format({})", val)))?,
+        None => return Err(Error::InvalidNumberOfProperties("format".to_owned())),
+    };
+    let args = args
+        .map(Parser::parse_action)
+        .collect::<Result<Vec<_>, _>>()?;
+    let segments =
+        parse_template(&template).ok_or_else(|| Error::InvalidFormat(template.clone()))?;
+    let valid = segments.iter().all(|s| match s {
+        FormatSegment::Arg(i) => *i < args.len(),
+        FormatSegment::Literal(_) => true,
+    });
+    if !valid {
+        return Err(Error::InvalidFormat(template));
+    }
+    Ok(Box::new(Format::new(segments, args)))
+}
+
+pub(super) fn parse_if(val: &str) -> Result<Box<dyn Action>, Error> {
+    let mut values = parse_actions(val)?.into_iter();
+    match (values.next(), values.next(), values.next(), values.next()) {
+        (Some(condition), Some(then), r#else, None) => {
+            Ok(Box::new(If::new(condition, then, r#else)))
+        }
+        _ => Err(Error::InvalidNumberOfProperties("if".to_owned())),
+    }
+}
+
+pub(super) fn parse_index(val: &str) -> Result<Box<dyn Action>, Error> {
+    if !val.trim().is_empty() {
+        return Err(Error::InvalidNumberOfProperties("index".to_owned()));
     }
+    Ok(Box::new(Index::new()))
+}
 
+pub(super) fn parse_join(val: &str) -> Result<Box<dyn Action>, Error> {
+    let (sep, val) = quoted_arg("join", val)?;
+    let values = parse_actions(val)?;
     if values.is_empty() {
         return Err(Error::InvalidNumberOfProperties("join".to_owned()));
     }
     Ok(Box::new(Join::new(sep, values)))
 }
 
+pub(super) fn parse_map(val: &str) -> Result<Box<dyn Action>, Error> {
+    let args = split_args(val);
+    if args.len() < 2 {
+        return Err(Error::InvalidNumberOfProperties("map".to_owned()));
+    }
+    let actions = args[1..]
+        .iter()
+        .map(|arg| match split_top_level(arg, "->").as_slice() {
+            [source, destination] => Parser::parse(source, destination),
+            [source] => Parser::parse(source, ""),
+            _ => Err(Error::InvalidMapping(arg.to_string())),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let action = Parser::parse_action(args[0])?;
+    Ok(Box::new(Map::new(action, actions)))
+}
+
+pub(super) fn parse_group_by(val: &str) -> Result<Box<dyn Action>, Error> {
+    let mut actions = parse_actions(val)?;
+    if actions.len() != 2 {
+        return Err(Error::InvalidNumberOfProperties("group_by".to_owned()));
+    }
+    let key = actions.pop().unwrap();
+    let action = actions.pop().unwrap();
+    Ok(Box::new(GroupBy::new(action, key)))
+}
+
+pub(super) fn parse_index_of(val: &str) -> Result<Box<dyn Action>, Error> {
+    let mut actions = parse_actions(val)?;
+    if actions.len() != 2 {
+        return Err(Error::InvalidNumberOfProperties("index_of".to_owned()));
+    }
+    let value = actions.pop().unwrap();
+    let action = actions.pop().unwrap();
+    Ok(Box::new(IndexOf::new(action, value)))
+}
+
+#[cfg(feature = "jmespath")]
+pub(super) fn parse_jmespath(val: &str) -> Result<Box<dyn Action>, Error> {
+    let (expression, action) = match split_args(val).as_slice() {
+        [expression] => (*expression, Parser::parse_action("")?),
+        [expression, action] => (*expression, Parser::parse_action(action.trim())?),
+        _ => return Err(Error::InvalidNumberOfProperties("jmespath".to_owned())),
+    };
+    let expression: String = serde_json::from_str(expression.trim())
+        .map_err(|_| Error::InvalidQuotedValue(format!("jmespath({})", val)))?;
+    Ok(Box::new(JmesPath::new(
+        JmesPathExpression::new(&expression)?,
+        action,
+    )))
+}
+
+#[cfg(feature = "jsonpath")]
+pub(super) fn parse_jsonpath(val: &str) -> Result<Box<dyn Action>, Error> {
+    let path: String = serde_json::from_str(val.trim())
+        .map_err(|_| Error::InvalidQuotedValue(format!("jsonpath({})", val)))?;
+    let namespace = crate::jsonpath::compile(&path)?;
+    check_namespace_depth(&path, namespace.len())?;
+    Ok(Box::new(Getter::new(namespace)))
+}
+
+pub(super) fn parse_keys(val: &str) -> Result<Box<dyn Action>, Error> {
+    let action = Parser::parse_action(val)?;
+    Ok(Box::new(Members::new(MembersType::Keys, action)))
+}
+
 pub(super) fn parse_len(val: &str) -> Result<Box<dyn Action>, Error> {
     let action = Parser::parse_action(val)?;
     Ok(Box::new(Len::new(action)))
 }
 
-pub(super) fn parse_sum(val: &str) -> Result<Box<dyn Action>, Error> {
-    let sub_matches = COMMA_SEP_RE.captures_iter(val);
-    let mut values = Vec::new();
-    for m in sub_matches {
-        match m.get(0) {
-            Some(m) => values.push(Parser::parse_action(m.as_str().trim())?),
-            None => continue,
+pub(super) fn parse_len_chars(val: &str) -> Result<Box<dyn Action>, Error> {
+    let action = Parser::parse_action(val)?;
+    Ok(Box::new(Len::with_type(LenType::Chars, action)))
+}
+
+#[cfg(feature = "graphemes")]
+pub(super) fn parse_len_graphemes(val: &str) -> Result<Box<dyn Action>, Error> {
+    let action = Parser::parse_action(val)?;
+    Ok(Box::new(Len::with_type(LenType::Graphemes, action)))
+}
+
+pub(super) fn parse_lookup(val: &str) -> Result<Box<dyn Action>, Error> {
+    let (table, val) = quoted_arg("lookup", val)?;
+    let action = Parser::parse_action(val.trim())?;
+    Ok(Box::new(Lookup::new(table, action)))
+}
+
+pub(super) fn parse_lower(val: &str) -> Result<Box<dyn Action>, Error> {
+    let action = Parser::parse_action(val)?;
+    Ok(Box::new(ChangeCase::new(ChangeCaseType::Lower, action)))
+}
+
+pub(super) fn parse_now(val: &str) -> Result<Box<dyn Action>, Error> {
+    let format = if val.trim().is_empty() {
+        TimeFormat::Rfc3339
+    } else {
+        let format: String = serde_json::from_str(val.trim())
+            .map_err(|_| Error::InvalidQuotedValue(format!("now({})", val)))?;
+        time_format(&format)?
+    };
+    Ok(Box::new(Now::new(format)))
+}
+
+fn time_format(format: &str) -> Result<TimeFormat, Error> {
+    match format {
+        "rfc3339" => Ok(TimeFormat::Rfc3339),
+        "unix_ms" => Ok(TimeFormat::UnixMs),
+        "unix" => Ok(TimeFormat::Unix),
+        _ => Err(Error::InvalidTimeFormat(format.to_owned())),
+    }
+}
+
+pub(super) fn parse_pad_start(val: &str) -> Result<Box<dyn Action>, Error> {
+    parse_pad("pad_start", PadType::Start, val)
+}
+
+pub(super) fn parse_pad_end(val: &str) -> Result<Box<dyn Action>, Error> {
+    parse_pad("pad_end", PadType::End, val)
+}
+
+fn parse_pad(name: &str, r#type: PadType, val: &str) -> Result<Box<dyn Action>, Error> {
+    match split_args(val).as_slice() {
+        [width, padding, action] => Ok(Box::new(Pad::new(
+            r#type,
+            serde_json::from_str(width.trim())?,
+            serde_json::from_str::<String>(padding.trim())
+                .map_err(|_| Error::InvalidQuotedValue(format!("{}({})", name, val)))?,
+            Parser::parse_action(action)?,
+        ))),
+        _ => Err(Error::InvalidNumberOfProperties(name.to_owned())),
+    }
+}
+
+pub(super) fn parse_pick(val: &str) -> Result<Box<dyn Action>, Error> {
+    parse_select_keys("pick", SelectKeysType::Pick, val)
+}
+
+pub(super) fn parse_omit(val: &str) -> Result<Box<dyn Action>, Error> {
+    parse_select_keys("omit", SelectKeysType::Omit, val)
+}
+
+fn parse_select_keys(
+    name: &str,
+    r#type: SelectKeysType,
+    val: &str,
+) -> Result<Box<dyn Action>, Error> {
+    let args = split_args(val);
+    if args.len() < 2 {
+        return Err(Error::InvalidNumberOfProperties(name.to_owned()));
+    }
+    let keys = args[1..]
+        .iter()
+        .map(|k| {
+            serde_json::from_str::<String>(k.trim())
+                .map_err(|_| Error::InvalidQuotedValue(format!("{}({})", name, val)))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let action = Parser::parse_action(args[0])?;
+    Ok(Box::new(SelectKeys::new(r#type, keys, action)))
+}
+
+pub(super) fn parse_rename_keys(val: &str) -> Result<Box<dyn Action>, Error> {
+    match split_args(val).as_slice() {
+        [action, mapping] => Ok(Box::new(RenameKeys::new(
+            serde_json::from_str(mapping.trim())?,
+            Parser::parse_action(action)?,
+        ))),
+        _ => Err(Error::InvalidNumberOfProperties("rename_keys".to_owned())),
+    }
+}
+
+pub(super) fn parse_prune(val: &str) -> Result<Box<dyn Action>, Error> {
+    let args = split_args(val);
+    let options = if args.len() == 1 {
+        PruneOptions::default()
+    } else {
+        let mut options = PruneOptions {
+            nulls: false,
+            empty_strings: false,
+            empty_arrays: false,
+            empty_objects: false,
         };
+        for option in args[1..].iter() {
+            let option: String = serde_json::from_str(option.trim())
+                .map_err(|_| Error::InvalidQuotedValue(format!("prune({})", val)))?;
+            match option.as_str() {
+                "nulls" => options.nulls = true,
+                "empty_strings" => options.empty_strings = true,
+                "empty_arrays" => options.empty_arrays = true,
+                "empty_objects" => options.empty_objects = true,
+                _ => return Err(Error::InvalidPruneOption(option)),
+            }
+        }
+        options
+    };
+    let action = Parser::parse_action(args[0])?;
+    Ok(Box::new(Prune::new(options, action)))
+}
+
+pub(super) fn parse_reduce(val: &str) -> Result<Box<dyn Action>, Error> {
+    let mut actions = parse_actions(val)?;
+    if actions.len() != 3 {
+        return Err(Error::InvalidNumberOfProperties("reduce".to_owned()));
+    }
+    let initial = actions.pop().unwrap();
+    let expression = actions.pop().unwrap();
+    let action = actions.pop().unwrap();
+    Ok(Box::new(Reduce::new(action, expression, initial)))
+}
+
+pub(super) fn parse_regex_extract(val: &str) -> Result<Box<dyn Action>, Error> {
+    let (pattern, val) = quoted_arg("regex_extract", val)?;
+    let pattern = Pattern::new(&pattern)?;
+    let args = split_args(val.trim());
+    let group = match args.as_slice() {
+        [_] => RegexGroup::default(),
+        [_, group] => match serde_json::from_str(group.trim()) {
+            Ok(Value::Number(n)) => match n.as_u64() {
+                Some(i) if (i as usize) < pattern.captures_len() => RegexGroup::Index(i as usize),
+                _ => return Err(Error::InvalidCaptureGroup(group.to_string())),
+            },
+            Ok(Value::String(name)) if pattern.capture_names().any(|n| n == Some(&name)) => {
+                RegexGroup::Name(name)
+            }
+            _ => return Err(Error::InvalidCaptureGroup(group.to_string())),
+        },
+        _ => return Err(Error::InvalidNumberOfProperties("regex_extract".to_owned())),
+    };
+    let action = Parser::parse_action(args[0])?;
+    Ok(Box::new(RegexExtract::new(pattern, group, action)))
+}
+
+pub(super) fn parse_regex_replace(val: &str) -> Result<Box<dyn Action>, Error> {
+    let (pattern, val) = quoted_arg("regex_replace", val)?;
+    let (replacement, val) = quoted_arg("regex_replace", val.trim_start())?;
+    let action = Parser::parse_action(val.trim())?;
+    Ok(Box::new(RegexReplace::new(
+        Pattern::new(&pattern)?,
+        replacement,
+        action,
+    )))
+}
+
+pub(super) fn parse_replace(val: &str) -> Result<Box<dyn Action>, Error> {
+    let (from, val) = quoted_arg("replace", val)?;
+    let (to, val) = quoted_arg("replace", val.trim_start())?;
+    let action = Parser::parse_action(val.trim())?;
+    Ok(Box::new(Replace::new(from, to, action)))
+}
+
+pub(super) fn parse_reverse(val: &str) -> Result<Box<dyn Action>, Error> {
+    let action = Parser::parse_action(val)?;
+    Ok(Box::new(Reverse::new(action)))
+}
+
+pub(super) fn parse_seq(val: &str) -> Result<Box<dyn Action>, Error> {
+    let name: String = serde_json::from_str(val.trim())
+        .map_err(|_| Error::InvalidQuotedValue(format!("seq({})", val)))?;
+    Ok(Box::new(Seq::new(name)))
+}
+
+pub(super) fn parse_slice(val: &str) -> Result<Box<dyn Action>, Error> {
+    match split_args(val).as_slice() {
+        [start, end, action] => Ok(Box::new(Slice::new(
+            serde_json::from_str(start.trim())?,
+            Some(serde_json::from_str(end.trim())?),
+            Parser::parse_action(action)?,
+        ))),
+        _ => Err(Error::InvalidNumberOfProperties("slice".to_owned())),
+    }
+}
+
+pub(super) fn parse_take(val: &str) -> Result<Box<dyn Action>, Error> {
+    match split_args(val).as_slice() {
+        [count, action] => Ok(Box::new(Slice::new(
+            0,
+            Some(serde_json::from_str(count.trim())?),
+            Parser::parse_action(action)?,
+        ))),
+        _ => Err(Error::InvalidNumberOfProperties("take".to_owned())),
+    }
+}
+
+pub(super) fn parse_skip(val: &str) -> Result<Box<dyn Action>, Error> {
+    match split_args(val).as_slice() {
+        [count, action] => Ok(Box::new(Slice::new(
+            serde_json::from_str(count.trim())?,
+            None,
+            Parser::parse_action(action)?,
+        ))),
+        _ => Err(Error::InvalidNumberOfProperties("skip".to_owned())),
     }
+}
+
+pub(super) fn parse_sort(val: &str) -> Result<Box<dyn Action>, Error> {
+    let (action, order) = match split_args(val).as_slice() {
+        [action] => (*action, SortOrder::Asc),
+        [action, order] => (*action, sort_order("sort", val, order)?),
+        _ => return Err(Error::InvalidNumberOfProperties("sort".to_owned())),
+    };
+    let action = Parser::parse_action(action)?;
+    Ok(Box::new(Sort::new(action, None, order)))
+}
+
+pub(super) fn parse_sort_by(val: &str) -> Result<Box<dyn Action>, Error> {
+    let (action, key, order) = match split_args(val).as_slice() {
+        [action, key] => (*action, *key, SortOrder::Asc),
+        [action, key, order] => (*action, *key, sort_order("sort_by", val, order)?),
+        _ => return Err(Error::InvalidNumberOfProperties("sort_by".to_owned())),
+    };
+    let action = Parser::parse_action(action)?;
+    let key = Parser::parse_action(key)?;
+    Ok(Box::new(Sort::new(action, Some(key), order)))
+}
+
+fn sort_order(name: &str, val: &str, order: &str) -> Result<SortOrder, Error> {
+    let order: String = serde_json::from_str(order.trim())
+        .map_err(|_| Error::InvalidQuotedValue(format!("{}({})", name, val)))?;
+    match order.as_str() {
+        "asc" => Ok(SortOrder::Asc),
+        "desc" => Ok(SortOrder::Desc),
+        _ => Err(Error::InvalidSortOrder(order)),
+    }
+}
 
+pub(super) fn parse_stringify(val: &str) -> Result<Box<dyn Action>, Error> {
+    let action = Parser::parse_action(val)?;
+    Ok(Box::new(Convert::new(ConvertType::Json, action)))
+}
+
+pub(super) fn parse_substring(val: &str) -> Result<Box<dyn Action>, Error> {
+    match split_args(val).as_slice() {
+        [start, end, action] => Ok(Box::new(Substring::new(
+            serde_json::from_str(start.trim())?,
+            serde_json::from_str(end.trim())?,
+            Parser::parse_action(action)?,
+        ))),
+        _ => Err(Error::InvalidNumberOfProperties("substring".to_owned())),
+    }
+}
+
+pub(super) fn parse_sum(val: &str) -> Result<Box<dyn Action>, Error> {
+    let values = parse_actions(val)?;
     if values.is_empty() {
         return Err(Error::InvalidNumberOfProperties("sum".to_owned()));
     }
     Ok(Box::new(Sum::new(values)))
 }
 
+pub(super) fn parse_switch(val: &str) -> Result<Box<dyn Action>, Error> {
+    let args = split_args(val);
+    if args.len() < 2 {
+        return Err(Error::InvalidNumberOfProperties("switch".to_owned()));
+    }
+    let input = Parser::parse_action(args[0])?;
+    let mut cases = Vec::new();
+    let mut default = None;
+    for (i, arg) in args.iter().enumerate().skip(1) {
+        match split_top_level(arg, "->").as_slice() {
+            [when, then] => cases.push(Case::new(
+                Parser::parse_action(when)?,
+                Parser::parse_action(then)?,
+            )),
+            [_] if i == args.len() - 1 => default = Some(Parser::parse_action(arg)?),
+            _ => return Err(Error::InvalidSwitchCase(arg.to_string())),
+        }
+    }
+    Ok(Box::new(Switch::new(input, cases, default)))
+}
+
+pub(super) fn parse_to_number(val: &str) -> Result<Box<dyn Action>, Error> {
+    let action = Parser::parse_action(val)?;
+    Ok(Box::new(Convert::new(ConvertType::Number, action)))
+}
+
+pub(super) fn parse_to_string(val: &str) -> Result<Box<dyn Action>, Error> {
+    let action = Parser::parse_action(val)?;
+    Ok(Box::new(Convert::new(ConvertType::String, action)))
+}
+
+pub(super) fn parse_to_bool(val: &str) -> Result<Box<dyn Action>, Error> {
+    let action = Parser::parse_action(val)?;
+    Ok(Box::new(Convert::new(ConvertType::Bool, action)))
+}
+
 pub(super) fn parse_trim(val: &str) -> Result<Box<dyn Action>, Error> {
     let action = Parser::parse_action(val)?;
     Ok(Box::new(Trim::new(TrimType::Trim, action)))
@@ -78,38 +854,270 @@ pub(super) fn parse_trim_end(val: &str) -> Result<Box<dyn Action>, Error> {
     Ok(Box::new(Trim::new(TrimType::TrimEnd, action)))
 }
 
+pub(super) fn parse_strict(val: &str) -> Result<Box<dyn Action>, Error> {
+    let val = val.trim();
+    let namespace = GetterNamespace::parse(val)?;
+    check_namespace_depth(val, namespace.len())?;
+    Ok(Box::new(Getter::new(namespace).with_strict(true)))
+}
+
 pub(super) fn parse_strip_prefix(val: &str) -> Result<Box<dyn Action>, Error> {
-    let sep_len;
-    let strip = match QUOTED_STR_RE.find(val) {
-        Some(cap) => {
-            let s = cap.as_str();
-            sep_len = s.len();
-            let s = s[..s.len() - 1].trim(); // strip ',' and trim any whitespace
-            s[1..s.len() - 1].to_string() // remove '"" double quotes from beginning and end.
-        }
-        None => {
-            return Err(Error::InvalidQuotedValue(format!("strip_prefix({})", val)));
+    let (strip, val) = quoted_arg("strip_prefix", val)?;
+    let action = Parser::parse_action(val.trim())?;
+    Ok(Box::new(Strip::new(StripType::StripPrefix, strip, action)))
+}
+
+pub(super) fn parse_strip_suffix(val: &str) -> Result<Box<dyn Action>, Error> {
+    let (strip, val) = quoted_arg("strip_suffix", val)?;
+    let action = Parser::parse_action(val.trim())?;
+    Ok(Box::new(Strip::new(StripType::StripSuffix, strip, action)))
+}
+
+pub(super) fn parse_truncate(val: &str) -> Result<Box<dyn Action>, Error> {
+    let (max, suffix, action) = match split_args(val).as_slice() {
+        [max, action] => (max.trim(), String::new(), action.trim()),
+        [max, suffix, action] => (
+            max.trim(),
+            serde_json::from_str::<String>(suffix.trim())
+                .map_err(|_| Error::InvalidQuotedValue(format!("truncate({})", val)))?,
+            action.trim(),
+        ),
+        _ => return Err(Error::InvalidNumberOfProperties("truncate".to_owned())),
+    };
+    Ok(Box::new(Truncate::new(
+        serde_json::from_str(max)?,
+        suffix,
+        Parser::parse_action(action)?,
+    )))
+}
+
+pub(super) fn parse_upper(val: &str) -> Result<Box<dyn Action>, Error> {
+    let action = Parser::parse_action(val)?;
+    Ok(Box::new(ChangeCase::new(ChangeCaseType::Upper, action)))
+}
+
+pub(super) fn parse_var(val: &str) -> Result<Box<dyn Action>, Error> {
+    let name: String = serde_json::from_str(val.trim())
+        .map_err(|_| Error::InvalidQuotedValue(format!("var({})", val)))?;
+    Ok(Box::new(Var::new(name)))
+}
+
+pub(super) fn parse_unique(val: &str) -> Result<Box<dyn Action>, Error> {
+    let action = Parser::parse_action(val)?;
+    Ok(Box::new(Unique::new(action, None)))
+}
+
+pub(super) fn parse_unique_by(val: &str) -> Result<Box<dyn Action>, Error> {
+    let mut actions = parse_actions(val)?;
+    if actions.len() != 2 {
+        return Err(Error::InvalidNumberOfProperties("unique_by".to_owned()));
+    }
+    let key = actions.pop().unwrap();
+    let action = actions.pop().unwrap();
+    Ok(Box::new(Unique::new(action, Some(key))))
+}
+
+pub(super) fn parse_values(val: &str) -> Result<Box<dyn Action>, Error> {
+    let action = Parser::parse_action(val)?;
+    Ok(Box::new(Members::new(MembersType::Values, action)))
+}
+
+pub(super) fn parse_when(val: &str) -> Result<Box<dyn Action>, Error> {
+    let mut values = parse_actions(val)?;
+    if values.len() != 2 {
+        return Err(Error::InvalidNumberOfProperties("when".to_owned()));
+    }
+    let action = values.pop().unwrap(); // unwrap safe, length checked above
+    let condition = values.pop().unwrap();
+    Ok(Box::new(When::new(condition, action)))
+}
+
+#[cfg(feature = "base64")]
+pub(super) fn parse_base64_encode(val: &str) -> Result<Box<dyn Action>, Error> {
+    let action = Parser::parse_action(val)?;
+    Ok(Box::new(Base64::new(Base64Type::Encode, action)))
+}
+
+#[cfg(feature = "base64")]
+pub(super) fn parse_base64_decode(val: &str) -> Result<Box<dyn Action>, Error> {
+    let action = Parser::parse_action(val)?;
+    Ok(Box::new(Base64::new(Base64Type::Decode, action)))
+}
+
+#[cfg(feature = "hash")]
+pub(super) fn parse_sha256(val: &str) -> Result<Box<dyn Action>, Error> {
+    let action = Parser::parse_action(val)?;
+    Ok(Box::new(Digest::new(DigestType::Sha256, action)))
+}
+
+#[cfg(feature = "hash")]
+pub(super) fn parse_hmac(val: &str) -> Result<Box<dyn Action>, Error> {
+    let (key, val) = quoted_arg("hmac", val)?;
+    let action = Parser::parse_action(val.trim())?;
+    Ok(Box::new(Hmac::new(key, action)))
+}
+
+#[cfg(feature = "hash")]
+pub(super) fn parse_md5(val: &str) -> Result<Box<dyn Action>, Error> {
+    let action = Parser::parse_action(val)?;
+    Ok(Box::new(Digest::new(DigestType::Md5, action)))
+}
+
+#[cfg(feature = "uuid")]
+pub(super) fn parse_uuid(val: &str) -> Result<Box<dyn Action>, Error> {
+    if !val.trim().is_empty() {
+        return Err(Error::InvalidNumberOfProperties("uuid".to_owned()));
+    }
+    Ok(Box::new(Uuid::new()))
+}
+
+#[cfg(feature = "rand")]
+pub(super) fn parse_random_int(val: &str) -> Result<Box<dyn Action>, Error> {
+    match split_args(val).as_slice() {
+        [min, max] => {
+            let min: i64 = serde_json::from_str(min.trim())?;
+            let max: i64 = serde_json::from_str(max.trim())?;
+            if min > max {
+                return Err(Error::InvalidRange(format!("random_int({})", val)));
+            }
+            Ok(Box::new(RandomInt::new(min, max)))
         }
+        _ => Err(Error::InvalidNumberOfProperties("random_int".to_owned())),
+    }
+}
+
+#[cfg(feature = "rand")]
+pub(super) fn parse_random_choice(val: &str) -> Result<Box<dyn Action>, Error> {
+    let action = Parser::parse_action(val)?;
+    Ok(Box::new(RandomChoice::new(action)))
+}
+
+#[cfg(feature = "inflector")]
+pub(super) fn parse_pluralize(val: &str) -> Result<Box<dyn Action>, Error> {
+    let mut values = parse_actions(val)?;
+    let count = if values.len() == 2 {
+        values.pop()
+    } else {
+        None
     };
+    match values.pop() {
+        Some(action) if values.is_empty() => Ok(Box::new(Inflect::new(
+            InflectType::Pluralize,
+            action,
+            count,
+        ))),
+        _ => Err(Error::InvalidNumberOfProperties("pluralize".to_owned())),
+    }
+}
 
-    let action = Parser::parse_action(val[sep_len..].trim())?;
-    Ok(Box::new(Strip::new(StripType::StripPrefix, strip, action)))
+#[cfg(feature = "inflector")]
+pub(super) fn parse_singularize(val: &str) -> Result<Box<dyn Action>, Error> {
+    let action = Parser::parse_action(val)?;
+    Ok(Box::new(Inflect::new(
+        InflectType::Singularize,
+        action,
+        None,
+    )))
 }
 
-pub(super) fn parse_strip_suffix(val: &str) -> Result<Box<dyn Action>, Error> {
-    let sep_len;
-    let strip = match QUOTED_STR_RE.find(val) {
+#[cfg(feature = "i18n")]
+pub(super) fn parse_format_number(val: &str) -> Result<Box<dyn Action>, Error> {
+    parse_localize("format_number", LocalizeType::Number, val)
+}
+
+#[cfg(feature = "i18n")]
+pub(super) fn parse_format_currency(val: &str) -> Result<Box<dyn Action>, Error> {
+    let (code, val) = quoted_arg("format_currency", val)?;
+    if !crate::i18n::is_currency(&code) {
+        return Err(Error::InvalidCurrency(code));
+    }
+    parse_localize("format_currency", LocalizeType::Currency(code), val)
+}
+
+#[cfg(feature = "i18n")]
+pub(super) fn parse_format_date(val: &str) -> Result<Box<dyn Action>, Error> {
+    parse_localize("format_date", LocalizeType::Date, val)
+}
+
+/// parses the optional leading quoted locale argument followed by the action.
+#[cfg(feature = "i18n")]
+fn parse_localize(name: &str, r#type: LocalizeType, val: &str) -> Result<Box<dyn Action>, Error> {
+    let (locale, action) = match split_args(val).as_slice() {
+        [action] => (None, Parser::parse_action(action)?),
+        [locale, action] => {
+            let locale: String = serde_json::from_str(locale.trim())
+                .map_err(|_| Error::InvalidQuotedValue(format!("{}({})", name, val)))?;
+            if crate::i18n::Locale::get(&locale).is_none() {
+                return Err(Error::InvalidLocale(locale));
+            }
+            (Some(locale), Parser::parse_action(action)?)
+        }
+        _ => return Err(Error::InvalidNumberOfProperties(name.to_owned())),
+    };
+    Ok(Box::new(Localize::new(r#type, locale, action)))
+}
+
+/// parses the leading quoted string argument, returning it along with the remaining arguments.
+fn quoted_arg<'a>(name: &str, val: &'a str) -> Result<(String, &'a str), Error> {
+    match QUOTED_STR_RE.find(val) {
         Some(cap) => {
             let s = cap.as_str();
-            sep_len = s.len();
+            let remaining = &val[s.len()..];
             let s = s[..s.len() - 1].trim(); // strip ',' and trim any whitespace
-            s[1..s.len() - 1].to_string() // remove '"" double quotes from beginning and end.
-        }
-        None => {
-            return Err(Error::InvalidQuotedValue(format!("strip_suffix({})", val)));
+            Ok((s[1..s.len() - 1].to_string(), remaining)) // remove '"" double quotes from beginning and end.
         }
-    };
+        None => Err(Error::InvalidQuotedValue(format!("{}({})", name, val))),
+    }
+}
 
-    let action = Parser::parse_action(val[sep_len..].trim())?;
-    Ok(Box::new(Strip::new(StripType::StripSuffix, strip, action)))
+/// parses comma separated values into their Actions.
+fn parse_actions(val: &str) -> Result<Vec<Box<dyn Action>>, Error> {
+    split_args(val)
+        .into_iter()
+        .map(Parser::parse_action)
+        .collect()
+}
+
+/// splits comma separated values, ignoring commas within quoted strings and nested Actions, eg.
+/// `join(",", a, b)` remains a single value.
+#[inline]
+fn split_args(val: &str) -> Vec<&str> {
+    split_top_level(val, ",")
+}
+
+/// splits the values by the separator, ignoring any within quoted strings and nested Actions.
+pub(super) fn split_top_level<'a>(val: &'a str, sep: &str) -> Vec<&'a str> {
+    let bytes = val.as_bytes();
+    let mut values = Vec::new();
+    let mut depth = 0_usize;
+    let mut quoted = false;
+    let mut escaped = false;
+    let mut start = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        i += 1;
+        if quoted {
+            match b {
+                _ if escaped => escaped = false,
+                b'\\' => escaped = true,
+                b'"' => quoted = false,
+                _ => {}
+            }
+            continue;
+        }
+        match b {
+            b'"' => quoted = true,
+            b'(' | b'[' | b'{' => depth += 1,
+            b')' | b']' | b'}' => depth = depth.saturating_sub(1),
+            _ if depth == 0 && bytes[i - 1..].starts_with(sep.as_bytes()) => {
+                values.push(val[start..i - 1].trim());
+                i += sep.len() - 1;
+                start = i;
+            }
+            _ => {}
+        }
+    }
+    values.push(val[start..].trim());
+    values
 }
@@ -35,6 +35,101 @@ pub enum Error {
     #[error("Setter namespace parsing error: {0}")]
     SetterNamespace(#[from] SetterNamespaceError),
 
+    #[cfg(feature = "jmespath")]
+    #[error("Invalid JMESPath expression: {0}")]
+    InvalidJmesPath(#[from] jmespath::JmespathError),
+
+    #[cfg(feature = "jsonpath")]
+    #[error(transparent)]
+    JsonPath(#[from] crate::jsonpath::Error),
+
+    #[error(
+        "Namespace depth of {} exceeds the maximum of {} for namespace: {}",
+        depth,
+        max,
+        ns
+    )]
+    NamespaceTooDeep {
+        ns: String,
+        depth: usize,
+        max: usize,
+    },
+
+    #[error("Reading file: '{0}' is not allowed while parsing")]
+    FileSystemDenied(String),
+
+    #[error("Failed to read file '{path}': {err}")]
+    ReadFile {
+        path: String,
+        #[source]
+        err: std::io::Error,
+    },
+
+    #[error("Invalid regular expression: {0}")]
+    InvalidRegex(#[from] regex::Error),
+
+    #[error("Invalid capture group: '{0}'. It must be the index or quoted name of a group within the regular expression.")]
+    InvalidCaptureGroup(String),
+
+    #[error("Invalid case type: '{0}'. It must be one of 'snake', 'camel', 'kebab' or 'pascal'.")]
+    InvalidCaseType(String),
+
+    #[error("Invalid format template: '{0}'. Placeholders must be balanced and refer to a supplied argument, use '{{{{' and '}}}}' for literal braces.")]
+    InvalidFormat(String),
+
+    #[error("Invalid time format: '{0}'. It must be one of 'rfc3339', 'unix_ms' or 'unix'.")]
+    InvalidTimeFormat(String),
+
+    #[error("Invalid time unit: '{0}'. It must be one of 's' or 'ms'.")]
+    InvalidTimeUnit(String),
+
+    #[error("Invalid duration: '{0}'. It must be one or more numbers followed by a unit of 'w', 'd', 'h', 'm', 's' or 'ms', optionally negative, eg. '-1h30m'.")]
+    InvalidDuration(String),
+
+    #[error("Invalid range: '{0}'. The minimum must not be greater than the maximum.")]
+    InvalidRange(String),
+
+    #[error("Invalid prune option: '{0}'. It must be one of 'nulls', 'empty_strings', 'empty_arrays' or 'empty_objects'.")]
+    InvalidPruneOption(String),
+
+    #[error("Invalid mapping: '{0}'. Mappings must be in the form 'source -> destination' or a source alone to set the element.")]
+    InvalidMapping(String),
+
+    #[error("Invalid sort order: '{0}'. It must be one of 'asc' or 'desc'.")]
+    InvalidSortOrder(String),
+
+    #[error("Invalid chunk size: '{0}'. It must be greater than 0.")]
+    InvalidChunkSize(String),
+
+    #[error("Invalid precision: '{0}'. It must be an integer from 0 to 15.")]
+    InvalidPrecision(String),
+
+    #[error("Invalid switch case: '{0}'. Cases must be in the form 'case -> result' with only the default, if any, last.")]
+    InvalidSwitchCase(String),
+
+    #[error(
+        "Empty alternative in: '{0}'. Every alternative separated by '|' must contain an action."
+    )]
+    EmptyAlternative(String),
+
+    #[error("Invalid locale: '{0}'. It must be a supported locale tag, eg. 'en-CA'.")]
+    InvalidLocale(String),
+
+    #[error("Invalid currency: '{0}'. It must be an ISO 4217 currency code, eg. 'CAD'.")]
+    InvalidCurrency(String),
+
+    #[error("No destination supplied for source: '{0}'")]
+    MissingDestination(String),
+
+    #[error("No spec could be resolved for include: '{0}'")]
+    UnresolvedInclude(String),
+
+    #[error("Include: '{0}' includes itself")]
+    IncludeCycle(String),
+
+    #[error("No value supplied for placeholder: '${{{0}}}'")]
+    MissingParam(String),
+
     #[error("{0}")]
     CustomActionParseError(String),
 }
@@ -8,13 +8,15 @@ pub use errors::Error;
 use crate::action::Action;
 use crate::actions::getter::namespace::Namespace as GetterNamespace;
 use crate::actions::setter::namespace::Namespace as SetterNamespace;
-use crate::actions::{Getter, Setter};
+use crate::actions::{Coalesce, Getter, MultiSetter, Setter, When};
 use once_cell::sync::Lazy;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
+use std::cell::Cell;
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
+use std::thread::LocalKey;
 
 /// This is a Regex used to parse comma separated values and is used as a helper within custom
 /// Action Parsers.
@@ -23,7 +25,10 @@ pub static COMMA_SEP_RE: Lazy<Regex> =
 
 /// This is a Regex used to get content within quoted strings and is used as a helper within custom
 /// Action Parsers.
-pub static QUOTED_STR_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"^"(.*?[^\\])"\s*,"#).unwrap());
+pub static QUOTED_STR_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"^"((?:[^"\\]|\\.)*)"\s*,"#).unwrap());
+
+static PARAM_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"\$(\$)?\{([a-zA-Z0-9_]+)\}"#).unwrap());
 
 static ACTION_RE: Lazy<Regex> = Lazy::new(|| {
     let r = format!(r#"(?P<action>{})\((?P<value>.*)\)"#, ACTION_NAME_BASE_REGEX);
@@ -32,10 +37,198 @@ static ACTION_RE: Lazy<Regex> = Lazy::new(|| {
 
 static ACTION_PARSERS: Lazy<Mutex<HashMap<String, Arc<ActionParserFn>>>> = Lazy::new(|| {
     let mut m: HashMap<String, Arc<ActionParserFn>> = HashMap::new();
+    m.insert("chunk".to_string(), Arc::new(action_parsers::parse_chunk));
+    m.insert("concat".to_string(), Arc::new(action_parsers::parse_concat));
+    m.insert("diff".to_string(), Arc::new(action_parsers::parse_diff));
+    m.insert(
+        "enumerate".to_string(),
+        Arc::new(action_parsers::parse_enumerate),
+    );
+    m.insert("env".to_string(), Arc::new(action_parsers::parse_env));
+    m.insert(
+        "starts_with".to_string(),
+        Arc::new(action_parsers::parse_starts_with),
+    );
+    m.insert(
+        "ends_with".to_string(),
+        Arc::new(action_parsers::parse_ends_with),
+    );
+    m.insert("and".to_string(), Arc::new(action_parsers::parse_and));
+    m.insert("or".to_string(), Arc::new(action_parsers::parse_or));
+    m.insert("not".to_string(), Arc::new(action_parsers::parse_not));
+    m.insert("eq".to_string(), Arc::new(action_parsers::parse_eq));
+    m.insert("ne".to_string(), Arc::new(action_parsers::parse_ne));
+    m.insert("gt".to_string(), Arc::new(action_parsers::parse_gt));
+    m.insert("gte".to_string(), Arc::new(action_parsers::parse_gte));
+    m.insert("lt".to_string(), Arc::new(action_parsers::parse_lt));
+    m.insert("lte".to_string(), Arc::new(action_parsers::parse_lte));
+    m.insert("filter".to_string(), Arc::new(action_parsers::parse_filter));
+    m.insert("first".to_string(), Arc::new(action_parsers::parse_first));
+    m.insert("last".to_string(), Arc::new(action_parsers::parse_last));
+    m.insert(
+        "flatten".to_string(),
+        Arc::new(action_parsers::parse_flatten),
+    );
+    m.insert(
+        "flatten_keys".to_string(),
+        Arc::new(action_parsers::parse_flatten_keys),
+    );
+    m.insert(
+        "unflatten_keys".to_string(),
+        Arc::new(action_parsers::parse_unflatten_keys),
+    );
+    m.insert("exists".to_string(), Arc::new(action_parsers::parse_exists));
+    m.insert("if".to_string(), Arc::new(action_parsers::parse_if));
+    m.insert("index".to_string(), Arc::new(action_parsers::parse_index));
     m.insert("join".to_string(), Arc::new(action_parsers::parse_join));
+    m.insert(
+        "coalesce".to_string(),
+        Arc::new(action_parsers::parse_coalesce),
+    );
+    m.insert(
+        "default".to_string(),
+        Arc::new(action_parsers::parse_default),
+    );
     m.insert("const".to_string(), Arc::new(action_parsers::parse_const));
+    m.insert(
+        "const_file".to_string(),
+        Arc::new(action_parsers::parse_const_file),
+    );
+    m.insert("map".to_string(), Arc::new(action_parsers::parse_map));
+    m.insert(
+        "group_by".to_string(),
+        Arc::new(action_parsers::parse_group_by),
+    );
+    m.insert(
+        "index_of".to_string(),
+        Arc::new(action_parsers::parse_index_of),
+    );
+    m.insert("keys".to_string(), Arc::new(action_parsers::parse_keys));
     m.insert("len".to_string(), Arc::new(action_parsers::parse_len));
+    m.insert(
+        "len_chars".to_string(),
+        Arc::new(action_parsers::parse_len_chars),
+    );
+    #[cfg(feature = "graphemes")]
+    m.insert(
+        "len_graphemes".to_string(),
+        Arc::new(action_parsers::parse_len_graphemes),
+    );
+    m.insert(
+        "regex_extract".to_string(),
+        Arc::new(action_parsers::parse_regex_extract),
+    );
+    m.insert(
+        "regex_replace".to_string(),
+        Arc::new(action_parsers::parse_regex_replace),
+    );
+    m.insert("reduce".to_string(), Arc::new(action_parsers::parse_reduce));
+    m.insert(
+        "rename_keys".to_string(),
+        Arc::new(action_parsers::parse_rename_keys),
+    );
+    m.insert(
+        "replace".to_string(),
+        Arc::new(action_parsers::parse_replace),
+    );
+    m.insert(
+        "stringify".to_string(),
+        Arc::new(action_parsers::parse_stringify),
+    );
+    m.insert(
+        "substring".to_string(),
+        Arc::new(action_parsers::parse_substring),
+    );
+    m.insert(
+        "convert_case".to_string(),
+        Arc::new(action_parsers::parse_convert_case),
+    );
+    m.insert(
+        "convert_keys".to_string(),
+        Arc::new(action_parsers::parse_convert_keys),
+    );
+    m.insert("format".to_string(), Arc::new(action_parsers::parse_format));
+    m.insert("lookup".to_string(), Arc::new(action_parsers::parse_lookup));
+    m.insert("now".to_string(), Arc::new(action_parsers::parse_now));
+    m.insert(
+        "date_add".to_string(),
+        Arc::new(action_parsers::parse_date_add),
+    );
+    m.insert(
+        "to_epoch".to_string(),
+        Arc::new(action_parsers::parse_to_epoch),
+    );
+    m.insert(
+        "from_epoch".to_string(),
+        Arc::new(action_parsers::parse_from_epoch),
+    );
+    m.insert(
+        "pad_start".to_string(),
+        Arc::new(action_parsers::parse_pad_start),
+    );
+    m.insert(
+        "pad_end".to_string(),
+        Arc::new(action_parsers::parse_pad_end),
+    );
+    m.insert("strict".to_string(), Arc::new(action_parsers::parse_strict));
     m.insert("sum".to_string(), Arc::new(action_parsers::parse_sum));
+    m.insert("omit".to_string(), Arc::new(action_parsers::parse_omit));
+    m.insert("pick".to_string(), Arc::new(action_parsers::parse_pick));
+    m.insert("prune".to_string(), Arc::new(action_parsers::parse_prune));
+    m.insert(
+        "reverse".to_string(),
+        Arc::new(action_parsers::parse_reverse),
+    );
+    m.insert("seq".to_string(), Arc::new(action_parsers::parse_seq));
+    m.insert("slice".to_string(), Arc::new(action_parsers::parse_slice));
+    m.insert("take".to_string(), Arc::new(action_parsers::parse_take));
+    m.insert("skip".to_string(), Arc::new(action_parsers::parse_skip));
+    m.insert("sort".to_string(), Arc::new(action_parsers::parse_sort));
+    m.insert(
+        "sort_by".to_string(),
+        Arc::new(action_parsers::parse_sort_by),
+    );
+    m.insert("sub".to_string(), Arc::new(action_parsers::parse_sub));
+    m.insert("mul".to_string(), Arc::new(action_parsers::parse_mul));
+    m.insert(
+        "product".to_string(),
+        Arc::new(action_parsers::parse_product),
+    );
+    m.insert("div".to_string(), Arc::new(action_parsers::parse_div));
+    m.insert("mod".to_string(), Arc::new(action_parsers::parse_mod));
+    m.insert("min".to_string(), Arc::new(action_parsers::parse_min));
+    m.insert("max".to_string(), Arc::new(action_parsers::parse_max));
+    m.insert("avg".to_string(), Arc::new(action_parsers::parse_avg));
+    m.insert("round".to_string(), Arc::new(action_parsers::parse_round));
+    m.insert("floor".to_string(), Arc::new(action_parsers::parse_floor));
+    m.insert("ceil".to_string(), Arc::new(action_parsers::parse_ceil));
+    m.insert("switch".to_string(), Arc::new(action_parsers::parse_switch));
+    m.insert(
+        "to_number".to_string(),
+        Arc::new(action_parsers::parse_to_number),
+    );
+    m.insert(
+        "to_string".to_string(),
+        Arc::new(action_parsers::parse_to_string),
+    );
+    m.insert(
+        "to_bool".to_string(),
+        Arc::new(action_parsers::parse_to_bool),
+    );
+    m.insert(
+        "truncate".to_string(),
+        Arc::new(action_parsers::parse_truncate),
+    );
+    m.insert("upper".to_string(), Arc::new(action_parsers::parse_upper));
+    m.insert("lower".to_string(), Arc::new(action_parsers::parse_lower));
+    m.insert("unique".to_string(), Arc::new(action_parsers::parse_unique));
+    m.insert(
+        "unique_by".to_string(),
+        Arc::new(action_parsers::parse_unique_by),
+    );
+    m.insert("values".to_string(), Arc::new(action_parsers::parse_values));
+    m.insert("var".to_string(), Arc::new(action_parsers::parse_var));
+    m.insert("when".to_string(), Arc::new(action_parsers::parse_when));
     m.insert("trim".to_string(), Arc::new(action_parsers::parse_trim));
     m.insert(
         "trim_start".to_string(),
@@ -53,9 +246,77 @@ static ACTION_PARSERS: Lazy<Mutex<HashMap<String, Arc<ActionParserFn>>>> = Lazy:
         "strip_suffix".to_string(),
         Arc::new(action_parsers::parse_strip_suffix),
     );
+    #[cfg(feature = "base64")]
+    m.insert(
+        "base64_encode".to_string(),
+        Arc::new(action_parsers::parse_base64_encode),
+    );
+    #[cfg(feature = "base64")]
+    m.insert(
+        "base64_decode".to_string(),
+        Arc::new(action_parsers::parse_base64_decode),
+    );
+    #[cfg(feature = "hash")]
+    m.insert("sha256".to_string(), Arc::new(action_parsers::parse_sha256));
+    #[cfg(feature = "hash")]
+    m.insert("md5".to_string(), Arc::new(action_parsers::parse_md5));
+    #[cfg(feature = "hash")]
+    m.insert("hmac".to_string(), Arc::new(action_parsers::parse_hmac));
+    #[cfg(feature = "uuid")]
+    m.insert("uuid".to_string(), Arc::new(action_parsers::parse_uuid));
+    #[cfg(feature = "rand")]
+    m.insert(
+        "random_int".to_string(),
+        Arc::new(action_parsers::parse_random_int),
+    );
+    #[cfg(feature = "rand")]
+    m.insert(
+        "random_choice".to_string(),
+        Arc::new(action_parsers::parse_random_choice),
+    );
+    #[cfg(feature = "inflector")]
+    m.insert(
+        "pluralize".to_string(),
+        Arc::new(action_parsers::parse_pluralize),
+    );
+    #[cfg(feature = "inflector")]
+    m.insert(
+        "singularize".to_string(),
+        Arc::new(action_parsers::parse_singularize),
+    );
+    #[cfg(feature = "jmespath")]
+    m.insert(
+        "jmespath".to_string(),
+        Arc::new(action_parsers::parse_jmespath),
+    );
+    #[cfg(feature = "jsonpath")]
+    m.insert(
+        "jsonpath".to_string(),
+        Arc::new(action_parsers::parse_jsonpath),
+    );
+    #[cfg(feature = "i18n")]
+    m.insert(
+        "format_number".to_string(),
+        Arc::new(action_parsers::parse_format_number),
+    );
+    #[cfg(feature = "i18n")]
+    m.insert(
+        "format_currency".to_string(),
+        Arc::new(action_parsers::parse_format_currency),
+    );
+    #[cfg(feature = "i18n")]
+    m.insert(
+        "format_date".to_string(),
+        Arc::new(action_parsers::parse_format_date),
+    );
     Mutex::new(m)
 });
 
+thread_local! {
+    static MAX_NAMESPACE_DEPTH: Cell<usize> = const { Cell::new(usize::MAX) };
+    static ALLOW_FILE_SYSTEM: Cell<bool> = const { Cell::new(true) };
+}
+
 static ACTION_NAME_RE: Lazy<Regex> = Lazy::new(|| {
     let r = format!("^{}$", ACTION_NAME_BASE_REGEX);
     Regex::new(&r).unwrap()
@@ -73,7 +334,13 @@ pub type ActionParserFn = dyn Fn(&str) -> Result<Box<dyn Action>, Error> + 'stat
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct Parsable<'a> {
     source: Cow<'a, str>,
-    destination: Cow<'a, str>,
+    destination: Destination<'a>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    condition: Option<Cow<'a, str>>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pointer: bool,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    skip_nulls: bool,
 }
 
 impl<'a> Parsable<'a> {
@@ -83,9 +350,116 @@ impl<'a> Parsable<'a> {
     {
         Parsable {
             source: source.into(),
-            destination: destination.into(),
+            destination: Destination::Single(destination.into()),
+            condition: None,
+            pointer: false,
+            skip_nulls: false,
         }
     }
+
+    /// creates a Parsable whose source is evaluated once and written to every one of the
+    /// destinations.
+    pub fn new_multi<S>(source: S, destinations: &[S]) -> Self
+    where
+        S: Into<Cow<'a, str>> + Clone,
+    {
+        Parsable {
+            source: source.into(),
+            destination: Destination::Multi(destinations.iter().cloned().map(Into::into).collect()),
+            condition: None,
+            pointer: false,
+            skip_nulls: false,
+        }
+    }
+
+    /// sets a condition, evaluated against the source, which skips the action unless it returns a
+    /// Value other than `null` or `false`.
+    pub fn with_condition<S>(mut self, condition: S) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        self.condition = Some(condition.into());
+        self
+    }
+
+    /// sets whether the destination(s) and, when it starts with `/`, the source are JSON Pointers
+    /// (RFC 6901) eg. `/nested/inner/key` rather than the transformation syntax, see
+    /// [parse_pointer](struct.Parser.html#method.parse_pointer).
+    pub fn with_pointer(mut self, pointer: bool) -> Self {
+        self.pointer = pointer;
+        self
+    }
+
+    /// sets whether the destination(s) are left unset when the source returns `null` or no Value,
+    /// instead of writing `null`.
+    pub fn with_skip_nulls(mut self, skip_nulls: bool) -> Self {
+        self.skip_nulls = skip_nulls;
+        self
+    }
+}
+
+impl Parsable<'_> {
+    fn substitute(&self, params: &HashMap<String, String>) -> Result<Parsable<'_>, Error> {
+        Ok(Parsable {
+            source: substitute(&self.source, params)?,
+            destination: match &self.destination {
+                Destination::Single(d) => Destination::Single(substitute(d, params)?),
+                Destination::Multi(d) => Destination::Multi(
+                    d.iter()
+                        .map(|d| substitute(d, params))
+                        .collect::<Result<_, _>>()?,
+                ),
+            },
+            condition: match &self.condition {
+                Some(c) => Some(substitute(c, params)?),
+                None => None,
+            },
+            pointer: self.pointer,
+            skip_nulls: self.skip_nulls,
+        })
+    }
+}
+
+fn substitute<'a>(s: &'a str, params: &HashMap<String, String>) -> Result<Cow<'a, str>, Error> {
+    if !s.contains("${") {
+        return Ok(Cow::Borrowed(s));
+    }
+    let mut result = String::with_capacity(s.len());
+    let mut last = 0;
+    for caps in PARAM_RE.captures_iter(s) {
+        let m = caps.get(0).unwrap(); // unwrap safe, always has the entire match
+        let name = &caps[2];
+        result.push_str(&s[last..m.start()]);
+        if caps.get(1).is_some() {
+            result.push_str(&m.as_str()[1..]);
+        } else {
+            match params.get(name) {
+                Some(v) => result.push_str(v),
+                None => return Err(Error::MissingParam(name.to_owned())),
+            }
+        }
+        last = m.end();
+    }
+    result.push_str(&s[last..]);
+    Ok(Cow::Owned(result))
+}
+
+/// This type represents a single entry of a serialized transformation spec, being either a
+/// [Parsable](struct.Parsable.html) or an include of another named spec eg. `{"include":"audit"}`.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum SpecEntry<'a> {
+    Include { include: Cow<'a, str> },
+    Parsable(Parsable<'a>),
+}
+
+/// This type represents the destination syntax of a [Parsable](struct.Parsable.html), which is
+/// either a single destination or, when serialized as a list, multiple destinations.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum Destination<'a> {
+    Single(Cow<'a, str>),
+    Multi(Vec<Cow<'a, str>>),
 }
 
 /// This type represents a set of static methods for parsing transformation syntax into
@@ -118,22 +492,192 @@ impl Parser {
         Ok(())
     }
 
+    /// with_max_namespace_depth limits the number of namespace segments allowed in any Getter or
+    /// Setter syntax parsed within f, eg. `person.addresses[0].street` has a depth of 4.
+    /// NOTE: the limit only applies to parsing on the current thread for the duration of f,
+    /// otherwise parsing is unlimited.
+    ///
+    /// To also enforce the depth of deserialized transformations at apply time see
+    /// [Limits](../context/struct.Limits.html).
+    ///
+    /// ```rust
+    /// use proteus::{Parsable, Parser};
+    ///
+    /// let res = Parser::with_max_namespace_depth(2, || {
+    ///     Parser::parse_multi(&[Parsable::new("person.addresses[0].street", "street")])
+    /// });
+    /// assert!(res.is_err());
+    /// ```
+    pub fn with_max_namespace_depth<T, F>(depth: usize, f: F) -> T
+    where
+        F: FnOnce() -> T,
+    {
+        scoped(&MAX_NAMESPACE_DEPTH, depth, f)
+    }
+
+    /// with_file_system sets whether actions parsed within f, such as `const_file`, may read files
+    /// from the host while parsing.
+    /// NOTE: the setting only applies to parsing on the current thread for the duration of f,
+    /// otherwise reading files is allowed.
+    ///
+    /// ```rust
+    /// use proteus::{Parsable, Parser};
+    ///
+    /// let res = Parser::with_file_system(false, || {
+    ///     Parser::parse_multi(&[Parsable::new(r#"const_file("legal.txt")"#, "legal")])
+    /// });
+    /// assert!(res.is_err());
+    /// ```
+    pub fn with_file_system<T, F>(allowed: bool, f: F) -> T
+    where
+        F: FnOnce() -> T,
+    {
+        scoped(&ALLOW_FILE_SYSTEM, allowed, f)
+    }
+
     /// parses a single transformation action to be taken with the provided source & destination.
     pub fn parse(source: &str, destination: &str) -> Result<Box<dyn Action>, Error> {
-        let set = SetterNamespace::parse(destination)?;
-        let action = Parser::parse_action(source)?;
-        Ok(Box::new(Setter::new(set, action)))
+        Parser::parse_single(source, destination, false, false)
+    }
+
+    /// parses a single transformation action to be taken with the provided source & destination,
+    /// where the destination is a JSON Pointer (RFC 6901) eg. `/nested/inner/key`. The source is
+    /// also a JSON Pointer when empty or starting with `/`, otherwise it is parsed as an
+    /// [Action](action/trait.Action.html) eg. `const("value")`.
+    ///
+    /// `~1` and `~0` are unescaped to `/` and `~` respectively. As a JSON Pointer does not
+    /// distinguish Object keys from Array indexes, tokens which are a valid Array index eg. `0` are
+    /// treated as an Array index. Within the destination `-` appends to the Array.
+    ///
+    /// ```rust
+    /// use proteus::Parser;
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let actions = Parser::parse_pointer("/nested/a~1b", "/flat/-")?;
+    ///     let expected = Parser::parse(r#"nested.["a/b"]"#, "flat[]")?;
+    ///     assert_eq!(format!("{:?}", actions), format!("{:?}", expected));
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn parse_pointer(source: &str, destination: &str) -> Result<Box<dyn Action>, Error> {
+        Parser::parse_single(source, destination, true, false)
+    }
+
+    /// parses a single transformation action whose source is evaluated once and set to each of the
+    /// provided destinations.
+    pub fn parse_fan_out<S>(source: &str, destinations: &[S]) -> Result<Box<dyn Action>, Error>
+    where
+        S: AsRef<str>,
+    {
+        Parser::parse_multi_destination(source, destinations, false, false)
+    }
+
+    fn parse_single(
+        source: &str,
+        destination: &str,
+        pointer: bool,
+        skip_nulls: bool,
+    ) -> Result<Box<dyn Action>, Error> {
+        let set = parse_destination(destination, pointer)?;
+        let action = Parser::parse_source(source, pointer)?;
+        Ok(Box::new(
+            Setter::new(set, action).with_skip_nulls(skip_nulls),
+        ))
+    }
+
+    fn parse_multi_destination<S>(
+        source: &str,
+        destinations: &[S],
+        pointer: bool,
+        skip_nulls: bool,
+    ) -> Result<Box<dyn Action>, Error>
+    where
+        S: AsRef<str>,
+    {
+        match destinations {
+            [] => Err(Error::MissingDestination(source.to_owned())),
+            [destination] => {
+                Parser::parse_single(source, destination.as_ref(), pointer, skip_nulls)
+            }
+            _ => {
+                let mut namespaces = Vec::with_capacity(destinations.len());
+                for destination in destinations {
+                    namespaces.push(parse_destination(destination.as_ref(), pointer)?);
+                }
+                let action = Parser::parse_source(source, pointer)?;
+                Ok(Box::new(
+                    MultiSetter::new(namespaces, action).with_skip_nulls(skip_nulls),
+                ))
+            }
+        }
+    }
+
+    fn parse_source(source: &str, pointer: bool) -> Result<Box<dyn Action>, Error> {
+        if pointer && (source.is_empty() || source.starts_with('/')) {
+            let get = GetterNamespace::parse_pointer(source)?;
+            check_namespace_depth(source, get.len())?;
+            return Ok(Box::new(Getter::new(get)));
+        }
+        Parser::parse_action(source)
     }
 
     /// parses a set of transformation actions into [Action](action/trait.Action.html)'s.
     pub fn parse_multi(parsables: &[Parsable]) -> Result<Vec<Box<dyn Action>>, Error> {
         let mut vec = Vec::new();
         for p in parsables.iter() {
-            vec.push(Parser::parse(&p.source, &p.destination)?);
+            let action = match &p.destination {
+                Destination::Single(destination) => {
+                    Parser::parse_single(&p.source, destination, p.pointer, p.skip_nulls)?
+                }
+                Destination::Multi(destinations) => Parser::parse_multi_destination(
+                    &p.source,
+                    destinations,
+                    p.pointer,
+                    p.skip_nulls,
+                )?,
+            };
+            let action = match &p.condition {
+                Some(condition) => Box::new(When::new(Parser::parse_action(condition)?, action)),
+                None => action,
+            };
+            vec.push(action);
         }
         Ok(vec)
     }
 
+    /// parses a set of transformation actions into [Action](action/trait.Action.html)'s after
+    /// substituting each `${NAME}` placeholder within their source, destination and condition
+    /// with the corresponding value from params. `$${NAME}` escapes a placeholder, leaving the
+    /// literal `${NAME}`.
+    ///
+    /// ```rust
+    /// use proteus::{Parsable, Parser};
+    /// use std::collections::HashMap;
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let mut params = HashMap::new();
+    ///     params.insert("TENANT_PREFIX".to_owned(), "acme".to_owned());
+    ///
+    ///     let actions = Parser::parse_multi_with_params(
+    ///         &[Parsable::new("${TENANT_PREFIX}_id", "id")],
+    ///         &params,
+    ///     )?;
+    ///     let expected = Parser::parse_multi(&[Parsable::new("acme_id", "id")])?;
+    ///     assert_eq!(format!("{:?}", actions), format!("{:?}", expected));
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn parse_multi_with_params(
+        parsables: &[Parsable],
+        params: &HashMap<String, String>,
+    ) -> Result<Vec<Box<dyn Action>>, Error> {
+        let parsables = parsables
+            .iter()
+            .map(|p| p.substitute(params))
+            .collect::<Result<Vec<_>, _>>()?;
+        Parser::parse_multi(&parsables)
+    }
+
     /// parses a set of transformation actions into [Action](action/trait.Action.html)'s from a JSON
     /// string of serialized [Parsable](struct.Parsable.html) structs.
     pub fn parse_multi_from_str(s: &str) -> Result<Vec<Box<dyn Action>>, Error> {
@@ -141,13 +685,56 @@ impl Parser {
         Parser::parse_multi(&parsables)
     }
 
+    /// parses a set of transformation actions into [Action](action/trait.Action.html)'s from a JSON
+    /// string of serialized [SpecEntry](enum.SpecEntry.html)'s, replacing each include with the
+    /// Parsables of the named spec returned by the resolver.
+    ///
+    /// ```rust
+    /// use proteus::Parser;
+    /// use std::collections::HashMap;
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let mut specs = HashMap::new();
+    ///     specs.insert("audit", r#"[{"source":"created_at","destination":"audit.created"}]"#);
+    ///
+    ///     let actions = Parser::parse_multi_with_resolver(
+    ///         r#"[{"source":"id","destination":"id"},{"include":"audit"}]"#,
+    ///         |name| specs.get(name).map(|s| s.to_string()),
+    ///     )?;
+    ///     assert_eq!(actions.len(), 2);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn parse_multi_with_resolver<F>(s: &str, resolver: F) -> Result<Vec<Box<dyn Action>>, Error>
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        let mut parsables = Vec::new();
+        resolve_spec(s, &resolver, &mut Vec::new(), &mut parsables)?;
+        Parser::parse_multi(&parsables)
+    }
+
     /// parses an [Action](action/trait.Action.html) given the provided str. This is primarily used
     /// as a helper in custom Action Parsers.
     pub fn parse_action(source: &str) -> Result<Box<dyn Action>, Error> {
+        // alternatives eg. 'first_name | given_name | const("unknown")' are parsed into a
+        // coalesce of each alternative, in order.
+        let alternatives = action_parsers::split_top_level(source, "|");
+        if alternatives.len() > 1 {
+            if alternatives.iter().any(|alt| alt.is_empty()) {
+                return Err(Error::EmptyAlternative(source.to_owned()));
+            }
+            let actions = alternatives
+                .into_iter()
+                .map(Parser::parse_action)
+                .collect::<Result<Vec<_>, _>>()?;
+            return Ok(Box::new(Coalesce::new(actions)));
+        }
         // edge case where there is no action but it looks like there's one inside of an
         // explicit key eg. '["const()"]'
         if source.starts_with(r#"[""#) {
             let get = GetterNamespace::parse(source)?;
+            check_namespace_depth(source, get.len())?;
             return Ok(Box::new(Getter::new(get)));
         }
         match ACTION_RE.captures(source) {
@@ -155,28 +742,99 @@ impl Parser {
                 None => Err(Error::MissingActionName {}),
                 Some(key) => {
                     let key = key.as_str();
-                    let parse_fn;
-                    match ACTION_PARSERS.lock().unwrap().get(key) {
+                    let parse_fn = match ACTION_PARSERS.lock().unwrap().get(key) {
                         None => return Err(Error::InvalidActionName(key.to_owned())),
-                        Some(f) => {
-                            parse_fn = f.clone();
-                        }
+                        Some(f) => f.clone(),
                     };
                     parse_fn(caps.name(ACTION_VALUE).unwrap().as_str()) // unwrap safe, has value or never would have match ACTION_RE regex
                 }
             },
             None => {
                 let get = GetterNamespace::parse(source)?;
+                check_namespace_depth(source, get.len())?;
                 Ok(Box::new(Getter::new(get)))
             }
         }
     }
 }
 
+fn resolve_spec<F>(
+    s: &str,
+    resolver: &F,
+    includes: &mut Vec<String>,
+    parsables: &mut Vec<Parsable<'static>>,
+) -> Result<(), Error>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    let entries: Vec<SpecEntry> = serde_json::from_str(s)?;
+    for entry in entries {
+        match entry {
+            SpecEntry::Parsable(p) => parsables.push(p),
+            SpecEntry::Include { include } => {
+                let include = include.into_owned();
+                if includes.contains(&include) {
+                    return Err(Error::IncludeCycle(include));
+                }
+                let spec = match resolver(&include) {
+                    Some(spec) => spec,
+                    None => return Err(Error::UnresolvedInclude(include)),
+                };
+                includes.push(include);
+                resolve_spec(&spec, resolver, includes, parsables)?;
+                includes.pop();
+            }
+        }
+    }
+    Ok(())
+}
+
+fn parse_destination(destination: &str, pointer: bool) -> Result<Vec<SetterNamespace>, Error> {
+    let set = if pointer {
+        SetterNamespace::parse_pointer(destination)?
+    } else {
+        SetterNamespace::parse(destination)?
+    };
+    check_namespace_depth(destination, set.len())?;
+    Ok(set)
+}
+
+/// sets the thread local setting to value for the duration of f, restoring the previous value
+/// afterwards even if f panics.
+fn scoped<V, T, F>(key: &'static LocalKey<Cell<V>>, value: V, f: F) -> T
+where
+    V: Copy + 'static,
+    F: FnOnce() -> T,
+{
+    struct Reset<V: Copy + 'static>(&'static LocalKey<Cell<V>>, V);
+
+    impl<V: Copy + 'static> Drop for Reset<V> {
+        fn drop(&mut self) {
+            self.0.with(|cell| cell.set(self.1));
+        }
+    }
+
+    let _reset = Reset(key, key.with(|cell| cell.replace(value)));
+    f()
+}
+
+#[inline]
+fn check_namespace_depth(ns: &str, depth: usize) -> Result<(), Error> {
+    let max = MAX_NAMESPACE_DEPTH.with(Cell::get);
+    if depth > max {
+        return Err(Error::NamespaceTooDeep {
+            ns: ns.to_owned(),
+            depth,
+            max,
+        });
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::actions::Constant;
+    use crate::actions::{Constant, Join, Sum};
 
     #[test]
     fn direct_getter() -> Result<(), Box<dyn std::error::Error>> {
@@ -215,6 +873,89 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn fan_out_serialize_deserialize() -> Result<(), Box<dyn std::error::Error>> {
+        let parsables = vec![
+            Parsable::new_multi("id", &["user.id", "ids[]"]),
+            Parsable::new("name", "user.name"),
+        ];
+        let serialized = serde_json::to_string(&parsables)?;
+        let expected = r#"[{"source":"id","destination":["user.id","ids[]"]},{"source":"name","destination":"user.name"}]"#;
+        assert_eq!(expected, serialized);
+
+        let deserialized: Vec<Parsable> = serde_json::from_str(&serialized)?;
+        assert_eq!(parsables, deserialized);
+
+        let res = Parser::parse_multi(&[Parsable::new_multi("id", &[])]);
+        assert!(matches!(res, Err(Error::MissingDestination(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn condition_serialize_deserialize() -> Result<(), Box<dyn std::error::Error>> {
+        let parsables = vec![Parsable::new("name", "user.name").with_condition("active")];
+        let serialized = serde_json::to_string(&parsables)?;
+        let expected = r#"[{"source":"name","destination":"user.name","condition":"active"}]"#;
+        assert_eq!(expected, serialized);
+
+        let deserialized: Vec<Parsable> = serde_json::from_str(&serialized)?;
+        assert_eq!(parsables, deserialized);
+        Ok(())
+    }
+
+    #[test]
+    fn include() -> Result<(), Box<dyn std::error::Error>> {
+        let resolver = |name: &str| match name {
+            "audit" => Some(
+                r#"[{"source":"created_at","destination":"audit.created"},{"include":"user"}]"#
+                    .to_owned(),
+            ),
+            "user" => Some(r#"[{"source":"user_id","destination":"audit.user"}]"#.to_owned()),
+            "cycle" => Some(r#"[{"include":"audit"},{"include":"cycle"}]"#.to_owned()),
+            _ => None,
+        };
+        let actions = Parser::parse_multi_with_resolver(
+            r#"[{"source":"id","destination":"id"},{"include":"audit"}]"#,
+            resolver,
+        )?;
+        let expected = Parser::parse_multi(&[
+            Parsable::new("id", "id"),
+            Parsable::new("created_at", "audit.created"),
+            Parsable::new("user_id", "audit.user"),
+        ])?;
+        assert_eq!(format!("{:?}", actions), format!("{:?}", expected));
+
+        let res = Parser::parse_multi_with_resolver(r#"[{"include":"cycle"}]"#, resolver);
+        assert!(matches!(res, Err(Error::IncludeCycle(name)) if name == "cycle"));
+
+        let res = Parser::parse_multi_with_resolver(r#"[{"include":"missing"}]"#, resolver);
+        assert!(matches!(res, Err(Error::UnresolvedInclude(name)) if name == "missing"));
+        Ok(())
+    }
+
+    #[test]
+    fn params() -> Result<(), Box<dyn std::error::Error>> {
+        let mut params = HashMap::new();
+        params.insert("PREFIX".to_owned(), "acme".to_owned());
+        params.insert("DEST".to_owned(), "tenant".to_owned());
+        let parsables = vec![
+            Parsable::new(r#"join("$${PREFIX}", ${PREFIX}_id, name)"#, "${DEST}.id")
+                .with_condition("${PREFIX}_active"),
+            Parsable::new_multi("${PREFIX}_name", &["${DEST}.name", "name"]),
+        ];
+        let actions = Parser::parse_multi_with_params(&parsables, &params)?;
+        let expected = Parser::parse_multi(&[
+            Parsable::new(r#"join("${PREFIX}", acme_id, name)"#, "tenant.id")
+                .with_condition("acme_active"),
+            Parsable::new_multi("acme_name", &["tenant.name", "name"]),
+        ])?;
+        assert_eq!(format!("{:?}", actions), format!("{:?}", expected));
+
+        let res = Parser::parse_multi_with_params(&[Parsable::new("${MISSING}", "id")], &params);
+        assert!(matches!(res, Err(Error::MissingParam(name)) if name == "MISSING"));
+        Ok(())
+    }
+
     #[test]
     fn parser_from_str() -> Result<(), Box<dyn std::error::Error>> {
         let parsables = vec![
@@ -227,13 +968,64 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn namespace_depth() -> Result<(), Box<dyn std::error::Error>> {
+        let deep = vec!["a"; 1001].join(".");
+        let res = Parser::with_max_namespace_depth(1000, || Parser::parse(&deep, "new"));
+        assert!(matches!(
+            res,
+            Err(Error::NamespaceTooDeep { depth: 1001, .. })
+        ));
+        let res = Parser::with_max_namespace_depth(1000, || {
+            assert!(Parser::with_max_namespace_depth(1001, || Parser::parse(&deep, "new")).is_ok());
+            Parser::parse("key", &deep)
+        });
+        assert!(matches!(
+            res,
+            Err(Error::NamespaceTooDeep { depth: 1001, .. })
+        ));
+        assert!(Parser::parse(&deep, &deep).is_ok());
+        Ok(())
+    }
+
+    #[test]
+    fn nested_arguments() -> Result<(), Box<dyn std::error::Error>> {
+        let action = Parser::parse_action(r#"join("-", join(",", a, b), const("x, y"))"#)?;
+        let expected = Join::new(
+            "-".to_owned(),
+            vec![
+                Box::new(Join::new(
+                    ",".to_owned(),
+                    vec![
+                        Box::new(Getter::new(GetterNamespace::parse("a")?)),
+                        Box::new(Getter::new(GetterNamespace::parse("b")?)),
+                    ],
+                )),
+                Box::new(Constant::new("x, y".into())),
+            ],
+        );
+        assert_eq!(format!("{:?}", action), format!("{:?}", expected));
+
+        let action = Parser::parse_action("sum(a, sum(b, c), const([1, 2]))")?;
+        let expected = Sum::new(vec![
+            Box::new(Getter::new(GetterNamespace::parse("a")?)),
+            Box::new(Sum::new(vec![
+                Box::new(Getter::new(GetterNamespace::parse("b")?)),
+                Box::new(Getter::new(GetterNamespace::parse("c")?)),
+            ])),
+            Box::new(Constant::new(serde_json::json!([1, 2]))),
+        ]);
+        assert_eq!(format!("{:?}", action), format!("{:?}", expected));
+        Ok(())
+    }
+
     #[test]
     fn join() -> Result<(), Box<dyn std::error::Error>> {
         let action = Parser::parse(
             r#"join(",_" , first_name, last_name, const("Dean Karn"))"#,
             "full_name",
         )?;
-        let expected = "Setter { namespace: [Object { id: \"full_name\" }], child: Join { sep: \",_\", values: [Getter { namespace: [Object { id: \"first_name\" }] }, Getter { namespace: [Object { id: \"last_name\" }] }, Constant { value: String(\"Dean Karn\") }] } }";
+        let expected = "Setter { namespace: [Object { id: \"full_name\" }], child: Join { sep: \",_\", values: [Getter { namespace: [Object { id: \"first_name\" }], strict: false }, Getter { namespace: [Object { id: \"last_name\" }], strict: false }, Constant { value: String(\"Dean Karn\") }] }, skip_nulls: false }";
         assert_eq!(format!("{:?}", action), expected.to_string());
         Ok(())
     }
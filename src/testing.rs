@@ -0,0 +1,298 @@
+//! Helpers for testing transformations against fixtures.
+//!
+//! ```rust
+//! use proteus::{actions, assert_transform, TransformBuilder};
+//! use serde_json::json;
+//!
+//! fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let trans = TransformBuilder::default()
+//!         .add_actions(actions!(("user_id", "id"))?)
+//!         .build()?;
+//!     assert_transform!(trans, json!({"user_id": "111"}), json!({"id": "111"}));
+//!     Ok(())
+//! }
+//! ```
+
+use crate::transformer::Transformer;
+use serde_json::Value;
+use std::fmt::Write;
+use std::path::Path;
+
+/// This macro applies the [Transformer](../transformer/struct.Transformer.html) to the input and
+/// panics with a readable diff if the output does not match what's expected.
+///
+/// see [assert_transform](testing/fn.assert_transform.html).
+#[macro_export]
+macro_rules! assert_transform {
+    ($trans:expr, $input:expr, $expected:expr) => {
+        $crate::testing::assert_transform(&$trans, &$input, &$expected)
+    };
+}
+
+/// The environment variable which, when set, causes [assert_golden](fn.assert_golden.html) to
+/// regenerate the expected output files instead of comparing against them.
+pub const UPDATE_GOLDEN_ENV: &str = "PROTEUS_UPDATE_GOLDEN";
+
+const GOLDEN_INPUT_SUFFIX: &str = ".input.json";
+const GOLDEN_EXPECTED_SUFFIX: &str = ".expected.json";
+
+/// applies the transformer to the input and panics with a readable diff if the output does not
+/// match the expected Value.
+pub fn assert_transform(trans: &Transformer, input: &Value, expected: &Value) {
+    let actual = match trans.apply(input) {
+        Ok(v) => v,
+        Err(e) => panic!("transformation failed: {}", e),
+    };
+    if let Some(msg) = mismatch(expected, &actual) {
+        panic!("{}", msg);
+    }
+}
+
+/// applies the transformer to every `<name>.input.json` file within the directory and compares the
+/// output against the corresponding `<name>.expected.json` file, panicking with a readable diff
+/// for every file that does not match, or if the directory contains no input files.
+///
+/// When the `PROTEUS_UPDATE_GOLDEN` environment variable is set the expected files are instead
+/// (re)generated from the current output.
+pub fn assert_golden<P: AsRef<Path>>(trans: &Transformer, dir: P) {
+    let update = std::env::var_os(UPDATE_GOLDEN_ENV).is_some();
+    if let Err(msg) = golden(trans, dir.as_ref(), update) {
+        panic!("{}", msg);
+    }
+}
+
+fn golden(trans: &Transformer, dir: &Path, update: bool) -> Result<(), String> {
+    let entries = std::fs::read_dir(dir)
+        .map_err(|e| format!("failed to read golden directory {}: {}", dir.display(), e))?;
+    let mut inputs = Vec::new();
+    for entry in entries {
+        let path = entry
+            .map_err(|e| format!("failed to read golden directory {}: {}", dir.display(), e))?
+            .path();
+        if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
+            if let Some(name) = name.strip_suffix(GOLDEN_INPUT_SUFFIX) {
+                inputs.push((name.to_owned(), path.clone()));
+            }
+        }
+    }
+    if inputs.is_empty() {
+        return Err(format!(
+            "no {} files found in golden directory {}",
+            GOLDEN_INPUT_SUFFIX,
+            dir.display()
+        ));
+    }
+    inputs.sort();
+
+    let mut failures = String::new();
+    for (name, input) in inputs {
+        let expected_path = dir.join(format!("{}{}", name, GOLDEN_EXPECTED_SUFFIX));
+        let input = match load_fixture(&input) {
+            Ok(v) => v,
+            Err(e) => {
+                let _ = writeln!(failures, "{}: {}", name, e);
+                continue;
+            }
+        };
+        let actual = match trans.apply(&input) {
+            Ok(v) => v,
+            Err(e) => {
+                let _ = writeln!(failures, "{}: transformation failed: {}", name, e);
+                continue;
+            }
+        };
+        if update {
+            let data = serde_json::to_string_pretty(&actual).unwrap_or_default() + "\n";
+            std::fs::write(&expected_path, data).map_err(|e| {
+                format!(
+                    "failed to write golden file {}: {}",
+                    expected_path.display(),
+                    e
+                )
+            })?;
+            continue;
+        }
+        if !expected_path.exists() {
+            let _ = writeln!(
+                failures,
+                "{}: missing expected file {}, set {} to generate it",
+                name,
+                expected_path.display(),
+                UPDATE_GOLDEN_ENV
+            );
+            continue;
+        }
+        match load_fixture(&expected_path) {
+            Ok(expected) => {
+                if let Some(msg) = mismatch(&expected, &actual) {
+                    let _ = writeln!(failures, "{}: {}", name, msg);
+                }
+            }
+            Err(e) => {
+                let _ = writeln!(failures, "{}: {}", name, e);
+            }
+        }
+    }
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(failures)
+    }
+}
+
+fn mismatch(expected: &Value, actual: &Value) -> Option<String> {
+    let differences = diff(expected, actual);
+    if differences.is_empty() {
+        return None;
+    }
+    let mut msg = String::from("transformation output does not match expected:\n");
+    for d in differences {
+        let _ = writeln!(msg, "  {}", d);
+    }
+    let _ = write!(
+        msg,
+        "expected: {}\nfound: {}",
+        serde_json::to_string_pretty(expected).unwrap_or_default(),
+        serde_json::to_string_pretty(actual).unwrap_or_default()
+    );
+    Some(msg)
+}
+
+/// loads and parses a JSON fixture file, returning a descriptive error if it cannot be read or is
+/// invalid JSON.
+pub fn load_fixture<P: AsRef<Path>>(path: P) -> Result<Value, String> {
+    let path = path.as_ref();
+    let data = std::fs::read(path)
+        .map_err(|e| format!("failed to read fixture {}: {}", path.display(), e))?;
+    serde_json::from_slice(&data)
+        .map_err(|e| format!("failed to parse fixture {}: {}", path.display(), e))
+}
+
+/// returns a human readable line, keyed by JSON Pointer, for each difference between the
+/// expected and actual Values.
+pub fn diff(expected: &Value, actual: &Value) -> Vec<String> {
+    let mut differences = Vec::new();
+    diff_at(String::new(), expected, actual, &mut differences);
+    differences
+}
+
+fn diff_at(pointer: String, expected: &Value, actual: &Value, differences: &mut Vec<String>) {
+    match (expected, actual) {
+        (Value::Object(e), Value::Object(a)) => {
+            for (k, ev) in e {
+                let p = format!("{}/{}", pointer, k.replace('~', "~0").replace('/', "~1"));
+                match a.get(k) {
+                    Some(av) => diff_at(p, ev, av, differences),
+                    None => differences.push(format!("{}: missing, expected {}", p, ev)),
+                }
+            }
+            for (k, av) in a {
+                if !e.contains_key(k) {
+                    let p = format!("{}/{}", pointer, k.replace('~', "~0").replace('/', "~1"));
+                    differences.push(format!("{}: unexpected {}", p, av));
+                }
+            }
+        }
+        (Value::Array(e), Value::Array(a)) => {
+            for (i, ev) in e.iter().enumerate() {
+                let p = format!("{}/{}", pointer, i);
+                match a.get(i) {
+                    Some(av) => diff_at(p, ev, av, differences),
+                    None => differences.push(format!("{}: missing, expected {}", p, ev)),
+                }
+            }
+            for (i, av) in a.iter().enumerate().skip(e.len()) {
+                differences.push(format!("{}/{}: unexpected {}", pointer, i, av));
+            }
+        }
+        _ => {
+            if expected != actual {
+                differences.push(format!(
+                    "{}: expected {}, found {}",
+                    pointer, expected, actual
+                ));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Parsable, Parser, TransformBuilder};
+    use serde_json::json;
+
+    #[test]
+    fn matching() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[Parsable::new("user_id", "id")])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        assert_transform!(trans, json!({"user_id": "111"}), json!({"id": "111"}));
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "/name: missing")]
+    fn not_matching() {
+        let actions = Parser::parse_multi(&[Parsable::new("user_id", "id")]).unwrap();
+        let trans = TransformBuilder::default()
+            .add_actions(actions)
+            .build()
+            .unwrap();
+        assert_transform!(
+            trans,
+            json!({"user_id": "111"}),
+            json!({"id": "111", "name": "Dean Karn"})
+        );
+    }
+
+    #[test]
+    fn golden_files() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = std::env::temp_dir().join(format!("proteus_golden_{}", std::process::id()));
+        std::fs::create_dir_all(&dir)?;
+
+        let actions = Parser::parse_multi(&[Parsable::new("user_id", "id")])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let res = golden(&trans, &dir, false);
+        assert!(res.unwrap_err().contains("no .input.json files found"));
+        assert!(golden(&trans, &dir.join("typo"), false).is_err());
+
+        std::fs::write(dir.join("a.input.json"), r#"{"user_id": "111"}"#)?;
+        std::fs::write(dir.join("b.input.json"), r#"{"user_id": "222"}"#)?;
+        let res = golden(&trans, &dir, false);
+        assert!(res.unwrap_err().contains("missing expected file"));
+
+        golden(&trans, &dir, true)?;
+        assert_eq!(
+            json!({"id": "222"}),
+            load_fixture(dir.join("b.expected.json"))?
+        );
+        golden(&trans, &dir, false)?;
+
+        std::fs::write(dir.join("b.expected.json"), r#"{"id": "333"}"#)?;
+        let res = golden(&trans, &dir, false).unwrap_err();
+        assert!(res.starts_with("b: "));
+        assert!(res.contains("/id: expected \"333\", found \"222\""));
+
+        std::fs::write(dir.join("b.expected.json"), "{")?;
+        let res = golden(&trans, &dir, false).unwrap_err();
+        assert!(res.starts_with("b: failed to parse fixture"));
+        assert!(load_fixture(dir.join("c.input.json")).is_err());
+
+        std::fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn differences() {
+        let expected = json!({"a/b": {"c": true}, "id": 1, "tags": ["a", "b"]});
+        let actual = json!({"a/b": {"c": true, "d": null}, "id": 2, "tags": ["a"]});
+        assert_eq!(
+            diff(&expected, &actual),
+            vec![
+                "/a~1b/d: unexpected null",
+                "/id: expected 1, found 2",
+                "/tags/1: missing, expected \"b\"",
+            ]
+        );
+    }
+}
@@ -1,22 +1,56 @@
 //! builder and finalized transformer representations..
 
 use crate::action::Action;
+use crate::canonical;
+use crate::context::{
+    Clock, Context, DefaultRandom, Keys, Limits, LookupTables, Policy, RandomSource, Sequences,
+    SystemClock,
+};
 use crate::errors::Error;
-use serde::de::DeserializeOwned;
-use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use crate::lineage::{self, Lineage};
+use serde::de::{self, DeserializeOwned, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{Map, Value};
 use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt;
+use std::io::{Read, Write};
+use std::sync::Arc;
 
 /// This type provides the ability to create a [Transformer](struct.Transformer.html) for use.
 #[derive(Debug)]
 pub struct TransformBuilder {
     actions: Vec<Box<dyn Action>>,
+    clock: Arc<dyn Clock>,
+    random: Arc<dyn RandomSource>,
+    keys: Keys,
+    lookup_tables: LookupTables,
+    limits: Limits,
+    policy: Policy,
+    sort_keys: bool,
+    null_for_missing: bool,
+    skip_nulls: bool,
+    strict: bool,
+    #[cfg(feature = "i18n")]
+    locale: String,
 }
 
 impl Default for TransformBuilder {
     fn default() -> Self {
         TransformBuilder {
             actions: Vec::new(),
+            clock: default_clock(),
+            random: default_random(),
+            keys: Keys::default(),
+            lookup_tables: LookupTables::default(),
+            limits: Limits::default(),
+            policy: Policy::default(),
+            sort_keys: false,
+            null_for_missing: false,
+            skip_nulls: false,
+            strict: false,
+            #[cfg(feature = "i18n")]
+            locale: default_locale(),
         }
     }
 }
@@ -34,22 +68,289 @@ impl TransformBuilder {
         self
     }
 
+    /// sets the [Clock](../context/trait.Clock.html) used by time based actions, defaulting to the
+    /// system time. A [FixedClock](../context/struct.FixedClock.html) can be used to make the
+    /// output deterministic.
+    pub fn clock<C>(mut self, clock: C) -> Self
+    where
+        C: Clock + 'static,
+    {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// sets the [RandomSource](../context/trait.RandomSource.html) used by generator actions. A
+    /// [SeededRandom](../context/struct.SeededRandom.html) can be used to make the output
+    /// deterministic.
+    pub fn random_source<R>(mut self, random: R) -> Self
+    where
+        R: RandomSource + 'static,
+    {
+        self.random = Arc::new(random);
+        self
+    }
+
+    /// registers the secret key, by id, used by keyed actions such as `hmac("id", email)`, keeping
+    /// the secret out of the transformation itself.
+    pub fn key<I, K>(mut self, id: I, key: K) -> Self
+    where
+        I: Into<String>,
+        K: Into<Vec<u8>>,
+    {
+        self.keys.insert(id, key);
+        self
+    }
+
+    /// registers a named lookup table used by the `lookup` action, eg.
+    /// `lookup("country_names", country_code)`.
+    pub fn add_lookup_table<N>(mut self, name: N, table: HashMap<String, Value>) -> Self
+    where
+        N: Into<String>,
+    {
+        self.lookup_tables.insert(name, table);
+        self
+    }
+
+    /// sets the resource [Limits](../context/struct.Limits.html) enforced while applying the
+    /// transformation, which is recommended when applying user supplied transformations.
+    pub fn limits(mut self, limits: Limits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// sets the runtime [Policy](../context/struct.Policy.html) of which side-effecting actions are
+    /// allowed to be applied.
+    pub fn policy(mut self, policy: Policy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// sets whether all Object keys of the destination are sorted alphabetically, recursively,
+    /// once all actions have been applied, for deterministic output.
+    ///
+    /// NOTE: this only has an effect when the `preserve_order` feature, or serde_json's, is enabled,
+    /// otherwise Object keys are always sorted.
+    pub fn sort_keys(mut self, sort_keys: bool) -> Self {
+        self.sort_keys = sort_keys;
+        self
+    }
+
+    /// sets whether an explicit `null` is set to the destination when a source returns no Value,
+    /// eg. when the source path is missing, instead of leaving the destination unset.
+    ///
+    /// NOTE: destinations merging into an Object or Array, eg. `{}`, `[+]` or `[=key]`, are always
+    /// left unset.
+    pub fn null_for_missing(mut self, null_for_missing: bool) -> Self {
+        self.null_for_missing = null_for_missing;
+        self
+    }
+
+    /// sets whether the destination is left unset when a source returns `null` or no Value,
+    /// instead of writing `null`, keeping the output free of `null` values. This takes precedence
+    /// over `null_for_missing(..)`.
+    pub fn skip_nulls(mut self, skip_nulls: bool) -> Self {
+        self.skip_nulls = skip_nulls;
+        self
+    }
+
+    /// sets whether every Getter returns an error when its path is missing or the source is of the
+    /// wrong type, instead of returning no Value.
+    ///
+    /// NOTE: Getters within actions relying on missing Values, such as `exists`, `coalesce`, `|`
+    /// and conditions, are not made strict; wrap them in `strict(..)` to do so.
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// sets the locale tag, eg. `en-CA`, used by locale-aware actions when none is supplied,
+    /// defaulting to `en-US`.
+    #[cfg(feature = "i18n")]
+    pub fn locale<L>(mut self, locale: L) -> Self
+    where
+        L: Into<String>,
+    {
+        self.locale = locale.into();
+        self
+    }
+
     /// creates the final [Transformer](struct.Transformer.html) representation.
     pub fn build(self) -> Result<Transformer, Error> {
-        // Error return value is reserved for future optimization during the build phase.
+        #[cfg(feature = "i18n")]
+        if crate::i18n::Locale::get(&self.locale).is_none() {
+            return Err(Error::UnknownLocale(self.locale));
+        }
         Ok(Transformer {
             actions: self.actions,
+            clock: self.clock,
+            random: self.random,
+            keys: self.keys,
+            lookup_tables: self.lookup_tables,
+            sequences: Sequences::default(),
+            limits: self.limits,
+            policy: self.policy,
+            sort_keys: self.sort_keys,
+            null_for_missing: self.null_for_missing,
+            skip_nulls: self.skip_nulls,
+            strict: self.strict,
+            #[cfg(feature = "i18n")]
+            locale: self.locale,
         })
     }
 }
 
+/// returns a span for the single action, named by its type, with the source paths it reads and the
+/// destination paths it writes.
+#[cfg(feature = "tracing")]
+fn action_span(action: &[Box<dyn Action>]) -> tracing::Span {
+    let span = tracing::debug_span!(
+        "action",
+        name = action[0].typetag_name(),
+        source = tracing::field::Empty,
+        destination = tracing::field::Empty
+    );
+    if !span.is_disabled() {
+        let mut sources = Vec::new();
+        let mut destinations = Vec::new();
+        for l in lineage::lineage(action) {
+            for source in l.sources {
+                if !sources.contains(&source) {
+                    sources.push(source);
+                }
+            }
+            destinations.push(l.destination);
+        }
+        span.record("source", sources.join(", ").as_str());
+        span.record("destination", destinations.join(", ").as_str());
+    }
+    span
+}
+
+fn default_clock() -> Arc<dyn Clock> {
+    Arc::new(SystemClock)
+}
+
+fn default_random() -> Arc<dyn RandomSource> {
+    Arc::new(DefaultRandom::default())
+}
+
+#[cfg(feature = "i18n")]
+fn default_locale() -> String {
+    crate::i18n::DEFAULT_LOCALE.to_owned()
+}
+
 /// This type represents a realized transformation which can be used on data.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Transformer {
     actions: Vec<Box<dyn Action>>,
+    #[serde(skip, default = "default_clock")]
+    clock: Arc<dyn Clock>,
+    #[serde(skip, default = "default_random")]
+    random: Arc<dyn RandomSource>,
+    #[serde(skip)]
+    keys: Keys,
+    #[serde(skip)]
+    lookup_tables: LookupTables,
+    #[serde(skip)]
+    sequences: Sequences,
+    #[serde(skip)]
+    limits: Limits,
+    #[serde(skip)]
+    policy: Policy,
+    #[serde(skip)]
+    sort_keys: bool,
+    #[serde(skip)]
+    null_for_missing: bool,
+    #[serde(skip)]
+    skip_nulls: bool,
+    #[serde(skip)]
+    strict: bool,
+    #[cfg(feature = "i18n")]
+    #[serde(skip, default = "default_locale")]
+    locale: String,
 }
 
 impl Transformer {
+    /// registers the secret key, by id, used by keyed actions, useful for deserialized
+    /// transformations.
+    pub fn with_key<I, K>(mut self, id: I, key: K) -> Self
+    where
+        I: Into<String>,
+        K: Into<Vec<u8>>,
+    {
+        self.keys.insert(id, key);
+        self
+    }
+
+    /// registers a named lookup table used by the `lookup` action, useful for deserialized
+    /// transformations.
+    pub fn with_lookup_table<N>(mut self, name: N, table: HashMap<String, Value>) -> Self
+    where
+        N: Into<String>,
+    {
+        self.lookup_tables.insert(name, table);
+        self
+    }
+
+    /// sets the resource [Limits](../context/struct.Limits.html), useful for deserialized
+    /// transformations.
+    pub fn with_limits(mut self, limits: Limits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// sets the runtime [Policy](../context/struct.Policy.html), useful for deserialized
+    /// transformations.
+    pub fn with_policy(mut self, policy: Policy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// sets whether all Object keys of the destination are sorted alphabetically, useful for
+    /// deserialized transformations.
+    pub fn with_sort_keys(mut self, sort_keys: bool) -> Self {
+        self.sort_keys = sort_keys;
+        self
+    }
+
+    /// sets whether an explicit `null` is set to the destination for missing source Values, useful
+    /// for deserialized transformations.
+    pub fn with_null_for_missing(mut self, null_for_missing: bool) -> Self {
+        self.null_for_missing = null_for_missing;
+        self
+    }
+
+    /// sets whether the destination is left unset when a source returns `null`, useful for
+    /// deserialized transformations.
+    pub fn with_skip_nulls(mut self, skip_nulls: bool) -> Self {
+        self.skip_nulls = skip_nulls;
+        self
+    }
+
+    /// sets whether every Getter returns an error when its path is missing or the source is of the
+    /// wrong type, useful for deserialized transformations.
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// sets the locale tag, eg. `en-CA`, used by locale-aware actions when none is supplied,
+    /// useful for deserialized transformations.
+    #[cfg(feature = "i18n")]
+    pub fn with_locale<L>(mut self, locale: L) -> Self
+    where
+        L: Into<String>,
+    {
+        self.locale = locale.into();
+        self
+    }
+
+    /// returns the [Lineage](../lineage/struct.Lineage.html) of every destination path written by
+    /// the transformation.
+    pub fn lineage(&self) -> Vec<Lineage> {
+        lineage::lineage(&self.actions)
+    }
+
     /// directly applies the transform actions, in order, on the source and sets directly on the
     /// provided destination.
     ///
@@ -60,8 +361,52 @@ impl Transformer {
         source: &Value,
         destination: &mut Value,
     ) -> Result<(), Error> {
+        self.apply_with_vars(source, destination, &HashMap::new())
+    }
+
+    /// applies the transform actions, in order, on the source and returns a final Value, making
+    /// the context variables available to the `var` action eg. `var("tenant_id")`.
+    ///
+    /// This allows runtime values to be injected without modifying the source.
+    #[inline]
+    pub fn apply_with_context(
+        &self,
+        source: &Value,
+        context: &HashMap<String, Value>,
+    ) -> Result<Value, Error> {
+        let mut value = Value::Null;
+        self.apply_with_vars(source, &mut value, context)?;
+        Ok(value)
+    }
+
+    fn apply_with_vars(
+        &self,
+        source: &Value,
+        destination: &mut Value,
+        vars: &HashMap<String, Value>,
+    ) -> Result<(), Error> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!("apply", actions = self.actions.len()).entered();
+
+        let ctx = Context::new(self.clock.as_ref(), self.random.as_ref())
+            .with_keys(&self.keys)
+            .with_lookup_tables(&self.lookup_tables)
+            .with_vars(vars)
+            .with_sequences(&self.sequences)
+            .with_limits(self.limits)
+            .with_policy(self.policy)
+            .with_null_for_missing(self.null_for_missing)
+            .with_skip_nulls(self.skip_nulls)
+            .with_strict(self.strict);
+        #[cfg(feature = "i18n")]
+        let ctx = ctx.with_locale(&self.locale);
         for a in self.actions.iter() {
-            a.apply(source, destination)?;
+            #[cfg(feature = "tracing")]
+            let _span = action_span(std::slice::from_ref(a)).entered();
+            a.apply(source, destination, &ctx)?;
+        }
+        if self.sort_keys {
+            sort_keys(destination);
         }
         Ok(())
     }
@@ -74,6 +419,66 @@ impl Transformer {
         Ok(value)
     }
 
+    /// applies the transform actions, in order, on the source and returns the top-level Object.
+    ///
+    /// An empty Map is returned when no actions set any data and an error when the top-level
+    /// Value is not an Object.
+    #[inline]
+    pub fn apply_to_map(&self, source: &Value) -> Result<Map<String, Value>, Error> {
+        match self.apply(source)? {
+            Value::Object(o) => Ok(o),
+            Value::Null => Ok(Map::new()),
+            v => Err(Error::NonObjectRoot(v.to_string())),
+        }
+    }
+
+    /// applies the transform actions, in order, on the source and returns the result serialized as
+    /// a JSON string.
+    #[inline]
+    pub fn apply_to_string(&self, source: &Value) -> Result<String, Error> {
+        Ok(serde_json::to_string(&self.apply(source)?)?)
+    }
+
+    /// applies the transform actions, in order, on the source and returns the result serialized as
+    /// a pretty-printed JSON string.
+    #[inline]
+    pub fn apply_to_string_pretty(&self, source: &Value) -> Result<String, Error> {
+        Ok(serde_json::to_string_pretty(&self.apply(source)?)?)
+    }
+
+    /// applies the transform actions, in order, on the source and returns the result serialized as
+    /// canonical JSON, see [canonical](../canonical/index.html).
+    #[inline]
+    pub fn apply_canonical_string(&self, source: &Value) -> Result<String, Error> {
+        Ok(canonical::to_string(&self.apply(source)?))
+    }
+
+    /// applies the transform actions, in order, on each element of a top-level JSON Array read
+    /// from the reader, writing each result to the writer as the elements of a JSON Array.
+    ///
+    /// Only a single element is held in memory at a time, allowing huge Arrays to be processed.
+    /// As many small reads are made, the reader should be buffered eg. `std::io::BufReader`.
+    pub fn apply_stream<R, W>(&self, reader: R, mut writer: W) -> Result<(), Error>
+    where
+        R: Read,
+        W: Write,
+    {
+        let mut de = serde_json::Deserializer::from_reader(reader);
+        writer.write_all(b"[").map_err(serde_json::Error::io)?;
+        let mut visitor = StreamVisitor {
+            trans: self,
+            writer: &mut writer,
+            error: None,
+        };
+        if let Err(e) = de.deserialize_seq(&mut visitor) {
+            return Err(visitor.error.take().unwrap_or(Error::JSONError(e)));
+        }
+        de.end()?;
+        writer.write_all(b"]").map_err(serde_json::Error::io)?;
+        writer.flush().map_err(serde_json::Error::io)?;
+        Ok(())
+    }
+
     /// applies the transform actions, in order, on the source slice.
     ///
     /// The source string MUST be valid utf-8 JSON.
@@ -120,10 +525,92 @@ impl Transformer {
     }
 }
 
+struct StreamVisitor<'a, W> {
+    trans: &'a Transformer,
+    writer: &'a mut W,
+    error: Option<Error>,
+}
+
+impl<'de, W> Visitor<'de> for &mut StreamVisitor<'_, W>
+where
+    W: Write,
+{
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a JSON Array")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut first = true;
+        while let Some(element) = seq.next_element::<Value>()? {
+            let res = self.trans.apply(&element).and_then(|v| {
+                if !first {
+                    self.writer.write_all(b",").map_err(serde_json::Error::io)?;
+                }
+                Ok(serde_json::to_writer(&mut *self.writer, &v)?)
+            });
+            if let Err(e) = res {
+                let msg = e.to_string();
+                self.error = Some(e);
+                return Err(de::Error::custom(msg));
+            }
+            first = false;
+        }
+        Ok(())
+    }
+}
+
+/// sorts the keys of all Objects within the Value alphabetically. This is only required when
+/// serde_json's `preserve_order` feature is enabled, otherwise keys are already sorted.
+fn sort_keys(value: &mut Value) {
+    match value {
+        Value::Object(o) => {
+            o.values_mut().for_each(sort_keys);
+            let sorted = o.keys().zip(o.keys().skip(1)).all(|(a, b)| a <= b);
+            if !sorted {
+                let mut entries: Vec<(String, Value)> = std::mem::take(o).into_iter().collect();
+                entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+                o.extend(entries);
+            }
+        }
+        Value::Array(arr) => arr.iter_mut().for_each(sort_keys),
+        _ => {}
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{Parsable, Parser, TransformBuilder};
+    use super::Transformer;
+    use crate::action::Action;
+    use crate::actions::getter::Error as GetterError;
+    use crate::actions::setter::namespace::Namespace;
+    use crate::actions::Setter;
+    use crate::context::{Capability, Context, Limit, Limits, Policy};
+    use crate::{Error, Parsable, Parser, TransformBuilder};
+    use serde::{Deserialize, Serialize};
     use serde_json::{json, Value};
+    use std::borrow::Cow;
+    use std::collections::HashMap;
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct EnvironmentAction;
+
+    #[typetag::serde]
+    impl Action for EnvironmentAction {
+        fn apply<'a>(
+            &'a self,
+            _source: &'a Value,
+            _destination: &mut Value,
+            ctx: &Context,
+        ) -> Result<Option<Cow<'a, Value>>, Error> {
+            ctx.require(Capability::Environment)?;
+            Ok(Some(Cow::Owned(Value::String("value".into()))))
+        }
+    }
 
     #[test]
     fn constant() -> Result<(), Box<dyn std::error::Error>> {
@@ -177,7 +664,7 @@ mod tests {
         let mut destination = json!([null, null, [null]]);
 
         let res = trans.apply_to_destination(&source, &mut destination);
-        assert!(!res.is_err());
+        assert!(res.is_ok());
         assert!(destination.is_array());
 
         let expected = json!([null, null, [null, "Dean Karn"]]);
@@ -200,7 +687,7 @@ mod tests {
         let source = "".into();
         let mut destination = json!([null, null, {"id":"id"}]);
         let res = trans.apply_to_destination(&source, &mut destination);
-        assert!(!res.is_err());
+        assert!(res.is_ok());
         assert!(destination.is_array());
 
         let expected = json!([null, null, "Dean Karn"]);
@@ -226,7 +713,7 @@ mod tests {
         let source = "".into();
         let mut destination = Value::Array(vec!["test".into()]);
         let res = trans.apply_to_destination(&source, &mut destination);
-        assert!(!res.is_err());
+        assert!(res.is_ok());
         assert!(destination.is_array());
 
         let expected = json!(["test", [null, "Dean Karn"]]);
@@ -309,6 +796,19 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_join_array() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new(r#"join(", ", tags)"#, "tags"),
+            Parsable::new(r#"join("-", const("x"), ids, missing)"#, "ids"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let input = json!({"tags": ["a", "b", "", "c"], "ids": [1, 2]});
+        let expected = json!({"tags": "a, b, c", "ids": "x-1-2"});
+        assert_eq!(expected, trans.apply(&input)?);
+        Ok(())
+    }
+
     #[test]
     fn test_explicit_key() -> Result<(), Box<dyn std::error::Error>> {
         let action = Parser::parse(r#"["name(1)"]"#, r#"["my name is ([2][])"]"#)?;
@@ -422,106 +922,2544 @@ mod tests {
     }
 
     #[test]
-    fn transformer_serialization() -> Result<(), Box<dyn std::error::Error>> {
+    fn upsert_array() -> Result<(), Box<dyn std::error::Error>> {
         let actions = Parser::parse_multi(&[
-            Parsable::new("person.name", "person[0]"),
-            Parsable::new("person.metadata", "person[0]"),
+            Parsable::new("user", "users[=id]"),
+            Parsable::new("new_user", "users[=id]"),
         ])?;
         let trans = TransformBuilder::default().add_actions(actions).build()?;
-        let res = serde_json::to_string(&trans)?;
-        assert_eq!(res, "{\"actions\":[{\"type\":\"Setter\",\"namespace\":[{\"Object\":{\"id\":\"person\"}},{\"Array\":{\"index\":0}}],\"child\":{\"type\":\"Getter\",\"namespace\":[{\"Object\":{\"id\":\"person\"}},{\"Object\":{\"id\":\"name\"}}]}},{\"type\":\"Setter\",\"namespace\":[{\"Object\":{\"id\":\"person\"}},{\"Array\":{\"index\":0}}],\"child\":{\"type\":\"Getter\",\"namespace\":[{\"Object\":{\"id\":\"person\"}},{\"Object\":{\"id\":\"metadata\"}}]}}]}");
+        let source = json!({
+            "user": {"id": 2, "name": "Joeybloggs"},
+            "new_user": {"id": 3, "name": "Jane"}
+        });
+        let mut destination = json!({"users": [
+            {"id": 1, "name": "Dean Karn"},
+            {"id": 2, "name": "Joey", "age": 10}
+        ]});
+        trans.apply_to_destination(&source, &mut destination)?;
+        let expected = json!({"users": [
+            {"id": 1, "name": "Dean Karn"},
+            {"id": 2, "name": "Joeybloggs", "age": 10},
+            {"id": 3, "name": "Jane"}
+        ]});
+        assert_eq!(expected, destination);
+
+        let destination = trans.apply(&source)?;
+        let expected = json!({"users": [
+            {"id": 2, "name": "Joeybloggs"},
+            {"id": 3, "name": "Jane"}
+        ]});
+        assert_eq!(expected, destination);
+
+        let action = Parser::parse("user.name", "users[=id]")?;
+        let trans = TransformBuilder::default().add_action(action).build()?;
+        assert!(trans.apply(&source).is_err());
+
+        let action = Parser::parse(r#"const({"name":"Dean Karn"})"#, "users[=id]")?;
+        let trans = TransformBuilder::default().add_action(action).build()?;
+        assert!(trans.apply(&source).is_err());
         Ok(())
     }
 
     #[test]
-    fn test_set_and_get_top_level() -> Result<(), Box<dyn std::error::Error>> {
-        let actions = Parser::parse_multi(&[Parsable::new("", "")])?;
-        let trans = TransformBuilder::default().add_actions(actions).build()?;
-        let input = json!({
-            "existing_key":"my_val1",
-            "my_array":["idx_0_value"]
-        });
-        let expected = json!({"existing_key":"my_val1","my_array":["idx_0_value"]});
-        let output = trans.apply(&input)?;
-        assert_eq!(expected, output);
+    fn union_array() -> Result<(), Box<dyn std::error::Error>> {
+        let action = Parser::parse("items", "items[~id]")?;
+        let trans = TransformBuilder::default().add_action(action).build()?;
+        let source = json!({"items": [
+            {"id": 2, "qty": 5},
+            {"id": 3, "name": "Widget", "qty": 1}
+        ]});
+        let mut destination = json!({"items": [
+            {"id": 1, "name": "Sprocket", "qty": 1},
+            {"id": 2, "name": "Gear", "qty": 2}
+        ]});
+        trans.apply_to_destination(&source, &mut destination)?;
+        let expected = json!({"items": [
+            {"id": 1, "name": "Sprocket", "qty": 1},
+            {"id": 2, "name": "Gear", "qty": 5},
+            {"id": 3, "name": "Widget", "qty": 1}
+        ]});
+        assert_eq!(expected, destination);
+        assert_eq!(json!({"items": source["items"]}), trans.apply(&source)?);
+
+        let res = trans.apply(&json!({"items": [{"name": "Gear"}]}));
+        assert!(res.is_err());
+        let res = trans.apply(&json!({"items": {"id": 1}}));
+        assert!(res.is_err());
         Ok(())
     }
 
     #[test]
-    fn test_sum() -> Result<(), Box<dyn std::error::Error>> {
+    fn pairwise_array() -> Result<(), Box<dyn std::error::Error>> {
         let actions = Parser::parse_multi(&[
-            Parsable::new(r#"sum(const(1.1), arr, len(obj))"#, "sum"),
-            Parsable::new("sum(len(arr))", "sum2"),
+            Parsable::new("addresses[*].street", "locations[*].road"),
+            Parsable::new("addresses[*].postal", "locations[*].code"),
         ])?;
         let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let source = json!({"addresses": [
+            {"street": "26 Here Blvd", "postal": "123456"},
+            {"street": "26 Lakeside Cottage Lane."}
+        ]});
+        let destination = trans.apply(&source)?;
+        let expected = json!({"locations": [
+            {"road": "26 Here Blvd", "code": "123456"},
+            {"road": "26 Lakeside Cottage Lane.", "code": null}
+        ]});
+        assert_eq!(expected, destination);
 
-        let input = json!({
-            "arr": [1, 2, 3],
-            "obj": {"key":"value"}
-        });
-        let expected = json!({"sum":8.1, "sum2": 3});
-        let output = trans.apply(&input)?;
-        assert_eq!(expected, output);
+        let action = Parser::parse("addresses[*].street", "[*]")?;
+        let trans = TransformBuilder::default().add_action(action).build()?;
+        let mut destination = json!(["a", "b", "c"]);
+        trans.apply_to_destination(&source, &mut destination)?;
+        let expected = json!(["26 Here Blvd", "26 Lakeside Cottage Lane.", "c"]);
+        assert_eq!(expected, destination);
 
-        let actions = Parser::parse_multi(&[Parsable::new("sum()", "sum")])?;
+        let action = Parser::parse("addresses[0].street", "locations[*].road")?;
+        let trans = TransformBuilder::default().add_action(action).build()?;
+        assert!(trans.apply(&source).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn fan_out() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi_from_str(
+            r#"[{"source":"join(\" \", first_name, last_name)","destination":["full_name","user.name","names[]"]}]"#,
+        )?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let source = json!({"first_name": "Dean", "last_name": "Karn"});
+        let destination = trans.apply(&source)?;
+        let expected = json!({"full_name": "Dean Karn", "user": {"name": "Dean Karn"}, "names": ["Dean Karn"]});
+        assert_eq!(expected, destination);
+        Ok(())
+    }
+
+    #[test]
+    fn condition() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi_from_str(
+            r#"[
+                {"source":"name","destination":"name"},
+                {"source":"email","destination":"email","condition":"verified"},
+                {"source":"phone","destination":"phone","condition":"phone_verified"}
+            ]"#,
+        )?;
         let trans = TransformBuilder::default().add_actions(actions).build()?;
 
-        let input = json!([1, 2, 3]);
-        let expected = json!({"sum":6});
-        let output = trans.apply(&input)?;
-        assert_eq!(expected, output);
+        let source =
+            json!({"name": "Dean", "email": "dean@example.com", "verified": true, "phone": "555"});
+        let expected = json!({"name": "Dean", "email": "dean@example.com"});
+        assert_eq!(expected, trans.apply(&source)?);
 
+        let source = json!({"name": "Dean", "email": "dean@example.com", "verified": false, "phone": "555", "phone_verified": "yes"});
+        let expected = json!({"name": "Dean", "phone": "555"});
+        assert_eq!(expected, trans.apply(&source)?);
         Ok(())
     }
 
     #[test]
-    fn test_len() -> Result<(), Box<dyn std::error::Error>> {
+    fn test_const_file() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = std::env::temp_dir().join(format!("proteus_const_file_{}", std::process::id()));
+        std::fs::create_dir_all(&dir)?;
+        let json_path = dir.join("meta.json");
+        let text_path = dir.join("legal.txt");
+        std::fs::write(&json_path, r#"{"version": 2, "tags": ["a", "b"]}"#)?;
+        std::fs::write(&text_path, "All rights reserved.")?;
+
         let actions = Parser::parse_multi(&[
-            Parsable::new("len()", "len1"),
-            Parsable::new("len(arr)", "len2"),
-            Parsable::new("len(obj)", "len3"),
-            Parsable::new("len(obj.key)", "len4"),
+            Parsable::new(
+                format!("const_file({})", serde_json::to_string(&json_path)?),
+                "meta".into(),
+            ),
+            Parsable::new(
+                format!("const_file({})", serde_json::to_string(&text_path)?),
+                "legal".into(),
+            ),
         ])?;
+        std::fs::remove_dir_all(&dir)?;
+
         let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let expected =
+            json!({"meta": {"version": 2, "tags": ["a", "b"]}, "legal": "All rights reserved."});
+        assert_eq!(expected, trans.apply(&json!({}))?);
 
-        let input = json!({
-            "arr": [1, 2, 3],
-            "obj": {"key":"value"}
+        let serialized = serde_json::to_string(&trans)?;
+        let trans: Transformer = serde_json::from_str(&serialized)?;
+        assert_eq!(expected, trans.apply(&json!({}))?);
+
+        let res = Parser::parse(r#"const_file("does/not/exist.json")"#, "meta");
+        assert!(matches!(res, Err(crate::parser::Error::ReadFile { .. })));
+
+        let res = Parser::with_file_system(false, || {
+            Parser::parse(r#"const_file("does/not/exist.json")"#, "meta")
         });
-        let expected = json!({"len1": 2, "len2": 3, "len3": 1, "len4": 5});
-        let output = trans.apply(&input)?;
-        assert_eq!(expected, output);
+        assert!(matches!(
+            res,
+            Err(crate::parser::Error::FileSystemDenied(path)) if path == "does/not/exist.json"
+        ));
         Ok(())
     }
 
+    #[cfg(feature = "preserve_order")]
     #[test]
-    fn test_trim() -> Result<(), Box<dyn std::error::Error>> {
+    fn sorted_keys() -> Result<(), Box<dyn std::error::Error>> {
         let actions = Parser::parse_multi(&[
-            Parsable::new("trim(key)", "res1"),
-            Parsable::new("trim_start(key)", "res2"),
-            Parsable::new("trim_end(key)", "res3"),
+            Parsable::new("z", "z"),
+            Parsable::new("nested", "a"),
+            Parsable::new("arr", "m"),
         ])?;
         let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let source =
+            json!({"z": 1, "nested": {"c": 1, "b": {"y": 2, "x": 1}}, "arr": [{"b": 1, "a": 2}]});
+        let destination = trans.apply(&source)?;
+        assert_eq!(
+            r#"{"z":1,"a":{"c":1,"b":{"y":2,"x":1}},"m":[{"b":1,"a":2}]}"#,
+            serde_json::to_string(&destination)?
+        );
 
-        let input = json!({"key": " value "});
-        let expected = json!({"res1": "value", "res2": "value ", "res3": " value"});
-        let output = trans.apply(&input)?;
-        assert_eq!(expected, output);
+        let trans = trans.with_sort_keys(true);
+        let destination = trans.apply(&source)?;
+        assert_eq!(
+            r#"{"a":{"b":{"x":1,"y":2},"c":1},"m":[{"a":2,"b":1}],"z":1}"#,
+            serde_json::to_string(&destination)?
+        );
         Ok(())
     }
 
     #[test]
-    fn test_strip() -> Result<(), Box<dyn std::error::Error>> {
+    fn test_date_add() -> Result<(), Box<dyn std::error::Error>> {
         let actions = Parser::parse_multi(&[
-            Parsable::new(r#"strip_prefix("v", key)"#, "res1"),
-            Parsable::new(r#"strip_suffix("e", key)"#, "res2"),
+            Parsable::new(r#"date_add("-7d", created_at)"#, "week_before"),
+            Parsable::new(r#"date_add("2h30m", created_at)"#, "expires_at"),
+            Parsable::new(r#"date_add("1s", invalid)"#, "invalid"),
         ])?;
         let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let source = json!({"created_at": "2020-03-01T23:00:00.5+01:00", "invalid": "now"});
+        let expected = json!({
+            "week_before": "2020-02-23T22:00:00.500Z",
+            "expires_at": "2020-03-02T00:30:00.500Z"
+        });
+        assert_eq!(expected, trans.apply(&source)?);
 
-        let input = json!({"key": "value"});
-        let expected = json!({"res1": "alue", "res2": "valu"});
-        let output = trans.apply(&input)?;
-        assert_eq!(expected, output);
+        let res = Parser::parse(r#"date_add("1y", created_at)"#, "x");
+        assert!(matches!(res, Err(crate::parser::Error::InvalidDuration(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_diff() -> Result<(), Box<dyn std::error::Error>> {
+        let action = Parser::parse("diff(before, after)", "audit.changes")?;
+        let trans = TransformBuilder::default().add_action(action).build()?;
+        let source = json!({
+            "before": {"name": "Dean", "age": 30, "tags": ["a"], "a/b": 1},
+            "after": {"name": "Joey", "tags": ["a", "b"], "a/b": 1, "email": "joey@example.com"}
+        });
+        let expected = json!({"audit": {"changes": {
+            "added": {"/email": "joey@example.com", "/tags/1": "b"},
+            "removed": {"/age": 30},
+            "changed": {"/name": {"from": "Dean", "to": "Joey"}}
+        }}});
+        assert_eq!(expected, trans.apply(&source)?);
+
+        let expected = json!({"audit": {"changes": {
+            "added": {},
+            "removed": {},
+            "changed": {"": {"from": null, "to": {"name": "Dean"}}}
+        }}});
+        assert_eq!(expected, trans.apply(&json!({"after": {"name": "Dean"}}))?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_chunk() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new("chunk(2, items)", "batches"),
+            Parsable::new("chunk(10, items)", "single"),
+            Parsable::new("chunk(2, empty)", "empty"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let source = json!({"items": [1, 2, 3, 4, 5], "empty": []});
+        let expected = json!({
+            "batches": [[1, 2], [3, 4], [5]],
+            "single": [[1, 2, 3, 4, 5]],
+            "empty": [],
+        });
+        assert_eq!(expected, trans.apply(&source)?);
+
+        assert!(matches!(
+            Parser::parse("chunk(0, items)", "x"),
+            Err(crate::parser::Error::InvalidChunkSize(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new("concat(a.items, b.items, const([1]))", "items"),
+            Parsable::new(
+                "sort(unique(concat(a.tags, b.tags, c.tag, missing)))",
+                "tags",
+            ),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let source = json!({
+            "a": {"items": [3, 2], "tags": ["x", "y"]},
+            "b": {"items": [[4]], "tags": ["y", "z"]},
+            "c": {"tag": "w"},
+        });
+        let expected = json!({"items": [3, 2, [4], 1], "tags": ["w", "x", "y", "z"]});
+        assert_eq!(expected, trans.apply(&source)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_coalesce() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new(r#"coalesce(nickname, name, const("unknown"))"#, "display"),
+            Parsable::new(r#"default(age, const(0))"#, "age"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+
+        let source = json!({"nickname": "Deano", "name": "Dean", "age": 30});
+        let expected = json!({"display": "Deano", "age": 30});
+        assert_eq!(expected, trans.apply(&source)?);
+
+        let source = json!({"nickname": null, "name": "Dean"});
+        let expected = json!({"display": "Dean", "age": 0});
+        assert_eq!(expected, trans.apply(&source)?);
+
+        let source = json!({});
+        let expected = json!({"display": "unknown", "age": 0});
+        assert_eq!(expected, trans.apply(&source)?);
+
+        for name in &["coalesce", "default"] {
+            let res = Parser::parse(&format!("{}()", name), "x");
+            assert!(matches!(
+                res.err().unwrap(),
+                crate::parser::Error::InvalidNumberOfProperties(n) if n == *name
+            ));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_alternatives() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new(r#"first_name | given_name | const("unknown")"#, "name"),
+            Parsable::new(r#"join("|", tags) | ["a|b"]"#, "tags"),
+            Parsable::new("upper(nick | first_name)", "nick"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+
+        let source = json!({"given_name": "Dean", "a|b": "x"});
+        let expected = json!({"name": "Dean", "tags": "x"});
+        assert_eq!(expected, trans.apply(&source)?);
+
+        let source = json!({"first_name": null, "nick": "deano", "tags": ["a", "b"]});
+        let expected = json!({"name": "unknown", "tags": "a|b", "nick": "DEANO"});
+        assert_eq!(expected, trans.apply(&source)?);
+
+        let action = Parser::parse(r#"["a|b"]"#, "key")?;
+        let trans = TransformBuilder::default().add_action(action).build()?;
+        let source = json!({"a": 1, "b": 2, "a|b": 3});
+        assert_eq!(json!({"key": 3}), trans.apply(&source)?);
+
+        for source in &["a|", "|a", "a||b", "missing | | a"] {
+            let res = Parser::parse(source, "x");
+            assert!(matches!(
+                res.err().unwrap(),
+                crate::parser::Error::EmptyAlternative(_)
+            ));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_deep_merge() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new("defaults", "shallow"),
+            Parsable::new("overrides", "shallow{}"),
+            Parsable::new("defaults", "source"),
+            Parsable::new("overrides", "source{*}"),
+            Parsable::new("defaults", "destination"),
+            Parsable::new("overrides", "destination{*<}"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+
+        let source = json!({
+            "defaults": {"theme": {"color": "blue", "size": 12}, "tags": ["a"], "lang": "en"},
+            "overrides": {"theme": {"color": "red", "font": {"family": "mono"}}, "tags": ["b"]}
+        });
+        let expected = json!({
+            "shallow": {"theme": {"color": "red", "font": {"family": "mono"}}, "tags": ["b"], "lang": "en"},
+            "source": {"theme": {"color": "red", "size": 12, "font": {"family": "mono"}}, "tags": ["b"], "lang": "en"},
+            "destination": {"theme": {"color": "blue", "size": 12, "font": {"family": "mono"}}, "tags": ["a"], "lang": "en"}
+        });
+        assert_eq!(expected, trans.apply(&source)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_strategy() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new("a", "keep"),
+            Parsable::new("b", "keep{<}"),
+            Parsable::new("a", "concat"),
+            Parsable::new("b", "concat{*+}"),
+            Parsable::new("a.tags", "tags"),
+            Parsable::new("b.tags", "tags[-<]"),
+            Parsable::new("a.tags", "all_tags"),
+            Parsable::new("b.tags", "all_tags[-+]"),
+            Parsable::new("a", "same"),
+            Parsable::new("a", "same{!}"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+
+        let source = json!({
+            "a": {"name": "Dean", "tags": ["x"], "nested": {"tags": ["y"]}},
+            "b": {"name": "Bob", "tags": ["z", "w"], "nested": {"tags": ["v"]}, "id": 1}
+        });
+        let expected = json!({
+            "keep": {"name": "Dean", "tags": ["x"], "nested": {"tags": ["y"]}, "id": 1},
+            "concat": {"name": "Bob", "tags": ["x", "z", "w"], "nested": {"tags": ["y", "v"]}, "id": 1},
+            "tags": ["x", "w"],
+            "all_tags": ["x", "z", "w"],
+            "same": {"name": "Dean", "tags": ["x"], "nested": {"tags": ["y"]}}
+        });
+        assert_eq!(expected, trans.apply(&source)?);
+
+        let actions = Parser::parse_multi(&[
+            Parsable::new("a", "merged"),
+            Parsable::new("b", "merged{*!}"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let source = json!({"a": {"user": {"id": 1, "name": "Dean"}}, "b": {"user": {"id": 1, "name": "Bob"}}});
+        let res = trans.apply(&source);
+        assert!(matches!(
+            res.err().unwrap(),
+            Error::Setter(crate::actions::setter::Error::MergeConflict { pointer }) if pointer == "/merged/user/name"
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_if_absent() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new("nickname", "nickname"),
+            Parsable::new("phone", "phone"),
+            Parsable::new("first_name", "nickname??"),
+            Parsable::new(r#"const("unknown")"#, "phone??"),
+            Parsable::new(r#"const("en")"#, "settings.lang??"),
+            Parsable::new("tags", "tags"),
+            Parsable::new(r#"const("z")"#, "tags[0]??"),
+            Parsable::new(r#"const("z")"#, "tags[2]??"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+
+        let source =
+            json!({"nickname": "Deano", "first_name": "Dean", "phone": null, "tags": ["a"]});
+        let expected = json!({
+            "nickname": "Deano",
+            "phone": null,
+            "settings": {"lang": "en"},
+            "tags": ["a", null, "z"]
+        });
+        assert_eq!(expected, trans.apply(&source)?);
+
+        let source = json!({"first_name": "Dean"});
+        let expected = json!({
+            "nickname": "Dean",
+            "phone": "unknown",
+            "settings": {"lang": "en"},
+            "tags": ["z", null, "z"]
+        });
+        assert_eq!(expected, trans.apply(&source)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_skip_nulls() -> Result<(), Box<dyn std::error::Error>> {
+        let source = json!({"name": "Dean", "nickname": null, "phone": null});
+        let actions = Parser::parse_multi(&[
+            Parsable::new("name", "name"),
+            Parsable::new("nickname", "nickname").with_skip_nulls(true),
+            Parsable::new_multi("phone", &["phone", "contact.phone"]).with_skip_nulls(true),
+            Parsable::new("email", "email"),
+            Parsable::new("email", "contact.email").with_skip_nulls(true),
+        ])?;
+        let trans = TransformBuilder::default()
+            .add_actions(actions)
+            .null_for_missing(true)
+            .build()?;
+        let expected = json!({"name": "Dean", "email": null});
+        assert_eq!(expected, trans.apply(&source)?);
+
+        let actions = Parser::parse_multi(&[
+            Parsable::new("name", "name"),
+            Parsable::new("nickname", "nickname"),
+            Parsable::new("nickname", "tags[]"),
+        ])?;
+        let trans = TransformBuilder::default()
+            .add_actions(actions)
+            .skip_nulls(true)
+            .build()?;
+        let expected = json!({"name": "Dean"});
+        assert_eq!(expected, trans.apply(&source)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_append_unique_array() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new("existing", "tags"),
+            Parsable::new("a", "tags[?]"),
+            Parsable::new("b", "tags[?]"),
+            Parsable::new("c", "tags[?]"),
+            Parsable::new("c", "tags[?]"),
+            Parsable::new("c", "new[?]"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+
+        let source = json!({"existing": ["x", {"id": 1}], "a": "x", "b": {"id": 1}, "c": 2});
+        let expected = json!({"tags": ["x", {"id": 1}, 2], "new": [2]});
+        assert_eq!(expected, trans.apply(&source)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_insert_array() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new("items", "items"),
+            Parsable::new("first", "items[>0]"),
+            Parsable::new("second", "items[>1].name"),
+            Parsable::new("last", "padded[>2]"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+
+        let source = json!({"items": ["c", "d"], "first": "a", "second": "b", "last": "z"});
+        let expected = json!({
+            "items": ["a", {"name": "b"}, "c", "d"],
+            "padded": [null, null, "z"]
+        });
+        assert_eq!(expected, trans.apply(&source)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_multi_key() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new(r#"user["id","name"]"#, "user"),
+            Parsable::new(r#"keys(user["email","missing"])"#, "keys"),
+            Parsable::new(r#"orders[*]["id","total"]"#, "orders"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+
+        let source = json!({
+            "user": {"id": 1, "name": "Dean", "email": "dean@example.com", "password": "secret"},
+            "orders": [{"id": 2, "total": 10, "notes": "x"}, {"id": 3}]
+        });
+        let expected = json!({
+            "user": {"id": 1, "name": "Dean"},
+            "keys": ["email"],
+            "orders": [{"id": 2, "total": 10}, {"id": 3}]
+        });
+        assert_eq!(expected, trans.apply(&source)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_pointer() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi_from_str(
+            r#"[
+                {"source":"/user/first~1last","destination":"/names/-","pointer":true},
+                {"source":"/user/tags/1","destination":"/tag","pointer":true},
+                {"source":"const(\"x\")","destination":"/a~0b/0","pointer":true},
+                {"source":"user.id","destination":"id"}
+            ]"#,
+        )?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+
+        let source = json!({"user": {"id": 1, "first/last": "Dean Karn", "tags": ["a", "b"]}});
+        let expected = json!({"names": ["Dean Karn"], "tag": "b", "a~b": ["x"], "id": 1});
+        assert_eq!(expected, trans.apply(&source)?);
+
+        let actions = Parser::parse_multi(&[Parsable::new("", "/copy").with_pointer(true)])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let source = json!({"id": 1});
+        assert_eq!(json!({"copy": {"id": 1}}), trans.apply(&source)?);
+
+        let actions = Parser::parse_multi(&[
+            Parsable::new("/codes/404", "/found").with_pointer(true),
+            Parsable::new("/items/0", "/first").with_pointer(true),
+            Parsable::new("/items/0", "/index/0").with_pointer(true),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let source = json!({"codes": {"404": "nf"}, "items": ["a"]});
+        let expected = json!({"found": "nf", "first": "a", "index": ["a"]});
+        assert_eq!(expected, trans.apply(&source)?);
+        let trans = trans.with_strict(true);
+        assert_eq!(expected, trans.apply(&source)?);
+
+        let action = Parser::parse_pointer("/codes/404", "/status/404")?;
+        let mut destination = json!({"status": {"200": "ok"}});
+        action.apply(
+            &source,
+            &mut destination,
+            &crate::context::Context::default(),
+        )?;
+        assert_eq!(json!({"status": {"200": "ok", "404": "nf"}}), destination);
+
+        let res = Parser::parse_pointer("/id", "id");
+        assert!(matches!(
+            res.err().unwrap(),
+            crate::parser::Error::SetterNamespace(_)
+        ));
+        Ok(())
+    }
+
+    #[cfg(feature = "jmespath")]
+    #[test]
+    fn test_jmespath() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new(
+                r#"jmespath("locations[?state=='WA'].name | sort(@)")"#,
+                "wa",
+            ),
+            Parsable::new(r#"jmespath("[?state=='NY'].name", locations)"#, "ny"),
+            Parsable::new(r#"jmespath("missing")"#, "missing"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+
+        let source = json!({"locations": [
+            {"name": "Seattle", "state": "WA"},
+            {"name": "New York", "state": "NY"},
+            {"name": "Bellevue", "state": "WA"}
+        ]});
+        let expected = json!({"wa": ["Bellevue", "Seattle"], "ny": ["New York"]});
+        assert_eq!(expected, trans.apply(&source)?);
+
+        let res = Parser::parse_action(r#"jmespath("locations[?")"#);
+        assert!(matches!(
+            res.err().unwrap(),
+            crate::parser::Error::InvalidJmesPath(_)
+        ));
+        Ok(())
+    }
+
+    #[cfg(feature = "jsonpath")]
+    #[test]
+    fn test_jsonpath() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new(r#"jsonpath("$.store.book[*].author")"#, "authors"),
+            Parsable::new(r#"jsonpath("$['store'].book[1].title")"#, "title"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+
+        let source = json!({"store": {"book": [
+            {"author": "Nigel Rees", "title": "Sayings of the Century"},
+            {"author": "Evelyn Waugh", "title": "Sword of Honour"}
+        ]}});
+        let expected =
+            json!({"authors": ["Nigel Rees", "Evelyn Waugh"], "title": "Sword of Honour"});
+        assert_eq!(expected, trans.apply(&source)?);
+
+        let res = Parser::parse_action(r#"jsonpath("$..author")"#);
+        assert!(matches!(
+            res.err().unwrap(),
+            crate::parser::Error::JsonPath(crate::jsonpath::Error::Unsupported { .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_when() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new("nickname", "nickname").with_condition("exists(nickname)"),
+            Parsable::new("when(verified, email)", "email"),
+            Parsable::new("when(exists(address.street), address)", "address"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+
+        let source = json!({"nickname": null, "email": "dean@example.com", "verified": true, "address": {"street": "26 Here Blvd"}});
+        let expected = json!({"nickname": null, "email": "dean@example.com", "address": {"street": "26 Here Blvd"}});
+        assert_eq!(expected, trans.apply(&source)?);
+
+        let source = json!({"email": "dean@example.com", "address": {"postal": "123456"}});
+        assert_eq!(Value::Null, trans.apply(&source)?);
+
+        assert!(Parser::parse("when(a)", "x").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_logic() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new("and(verified, not(po_box))", "deliverable"),
+            Parsable::new("or(email, phone)", "contactable"),
+            Parsable::new("not(verified)", "unverified"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let source = json!({"verified": true, "po_box": null, "phone": "555"});
+        let expected = json!({"deliverable": true, "contactable": true, "unverified": false});
+        assert_eq!(expected, trans.apply(&source)?);
+
+        let source = json!({"verified": true, "po_box": "PO 12", "email": false});
+        let expected = json!({"deliverable": false, "contactable": false, "unverified": false});
+        assert_eq!(expected, trans.apply(&source)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_compare() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new(
+                r#"and(eq(country, const("CA")), not(po_box))"#,
+                "is_domestic",
+            ),
+            Parsable::new("eq(qty, const(2.0))", "eq_number"),
+            Parsable::new("ne(qty, const(\"2\"))", "ne_type"),
+            Parsable::new("gt(qty, const(1))", "gt"),
+            Parsable::new("gte(qty, const(3))", "gte"),
+            Parsable::new(r#"lt(country, const("US"))"#, "lt_string"),
+            Parsable::new("lte(active, const(true))", "lte_bool"),
+            Parsable::new("lt(qty, country)", "mixed"),
+            Parsable::new("eq(missing, const(null))", "missing"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let source = json!({"country": "CA", "po_box": false, "qty": 2, "active": false});
+        let expected = json!({
+            "is_domestic": true,
+            "eq_number": true,
+            "ne_type": true,
+            "gt": true,
+            "gte": false,
+            "lt_string": true,
+            "lte_bool": true,
+            "mixed": false,
+            "missing": true,
+        });
+        assert_eq!(expected, trans.apply(&source)?);
+
+        assert!(Parser::parse("eq(qty)", "x").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_affix() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new(r#"starts_with(phone, const("+1"))"#, "is_mobile"),
+            Parsable::new(r#"ends_with(email, const("@example.com"))"#, "is_example"),
+            Parsable::new(r#"starts_with(id, const("1"))"#, "id"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let source = json!({"phone": "+15551234", "email": "dean@example.org", "id": 1});
+        let expected = json!({"is_mobile": true, "is_example": false, "id": false});
+        assert_eq!(expected, trans.apply(&source)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_replace() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new(r#"replace("-", "", phone)"#, "phone"),
+            Parsable::new(
+                r#"replace("Mr.", "Mister", join(" ", title, name))"#,
+                "name",
+            ),
+            Parsable::new(r#"replace("a", "b", age)"#, "age"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let source = json!({"phone": "555-123-4567", "title": "Mr.", "name": "Dean", "age": 30});
+        let expected = json!({"phone": "5551234567", "name": "Mister Dean"});
+        assert_eq!(expected, trans.apply(&source)?);
+
+        assert!(Parser::parse(r#"replace("a", phone)"#, "x").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_convert_case() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new(r#"convert_case("camel", field)"#, "camel"),
+            Parsable::new(r#"convert_case("pascal", field)"#, "pascal"),
+            Parsable::new(r#"convert_keys("snake", user)"#, "user"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let source = json!({
+            "field": "first_name",
+            "user": {"firstName": "Dean", "homeAddress": {"streetName": "Main"}, "tags": [{"tagID": 1}]}
+        });
+        let expected = json!({
+            "camel": "firstName",
+            "pascal": "FirstName",
+            "user": {"first_name": "Dean", "home_address": {"street_name": "Main"}, "tags": [{"tag_id": 1}]}
+        });
+        assert_eq!(expected, trans.apply(&source)?);
+
+        let res = Parser::parse(r#"convert_case("title", field)"#, "x");
+        assert!(matches!(res, Err(crate::parser::Error::InvalidCaseType(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_number() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new("to_number(qty)", "qty"),
+            Parsable::new("to_number(price)", "price"),
+            Parsable::new("to_number(count)", "count"),
+            Parsable::new("to_number(invalid)", "invalid"),
+            Parsable::new("to_number(active)", "active"),
+            Parsable::new("sum(to_number(qty), to_number(price))", "total"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let source = json!({
+            "qty": " 42 ",
+            "price": "3.5",
+            "count": 7,
+            "invalid": "12abc",
+            "active": true
+        });
+        let expected = json!({"qty": 42, "price": 3.5, "count": 7, "total": 45.5});
+        assert_eq!(expected, trans.apply(&source)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_string_bool() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new("to_string(id)", "id"),
+            Parsable::new("to_string(price)", "price"),
+            Parsable::new("to_string(active)", "active"),
+            Parsable::new("to_string(tags)", "tags"),
+            Parsable::new("to_bool(flags[0])", "flags[]"),
+            Parsable::new("to_bool(flags[1])", "flags[]"),
+            Parsable::new("to_bool(flags[2])", "flags[]"),
+            Parsable::new("to_bool(flags[3])", "flags[]"),
+            Parsable::new("to_bool(flags[4])", "flags[]"),
+            Parsable::new("to_bool(flags[5])", "flags[]"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let source = json!({
+            "id": 42,
+            "price": 3.5,
+            "active": false,
+            "tags": ["a"],
+            "flags": ["true", "0", 1, 0, true, "yes"]
+        });
+        let expected = json!({
+            "id": "42",
+            "price": "3.5",
+            "active": "false",
+            "flags": [true, false, true, false, true]
+        });
+        assert_eq!(expected, trans.apply(&source)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_truncate() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new(r#"truncate(8, "...", description)"#, "short"),
+            Parsable::new("truncate(4, description)", "cut"),
+            Parsable::new(r#"truncate(20, "...", description)"#, "unchanged"),
+            Parsable::new(r#"truncate(2, "...", description)"#, "suffix"),
+            Parsable::new("truncate(4, id)", "id"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let source = json!({"description": "Crème brûlée", "id": 123456});
+        let expected = json!({
+            "short": "Crème...",
+            "cut": "Crèm",
+            "unchanged": "Crème brûlée",
+            "suffix": ".."
+        });
+        assert_eq!(expected, trans.apply(&source)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_upper_lower() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new("upper(code)", "upper"),
+            Parsable::new("lower(code)", "lower"),
+            Parsable::new("upper(id)", "id"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let source = json!({"code": "Straße-Ab", "id": 1});
+        let expected = json!({"upper": "STRASSE-AB", "lower": "straße-ab"});
+        assert_eq!(expected, trans.apply(&source)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_lookup() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new(r#"lookup("country_names", country_code)"#, "country"),
+            Parsable::new(r#"lookup("statuses", status)"#, "status"),
+            Parsable::new(r#"lookup("country_names", unknown)"#, "unknown"),
+        ])?;
+        let countries: HashMap<String, Value> = vec![
+            ("CA".to_string(), json!("Canada")),
+            ("US".to_string(), json!("United States")),
+        ]
+        .into_iter()
+        .collect();
+        let statuses: HashMap<String, Value> = vec![("1".to_string(), json!({"active": true}))]
+            .into_iter()
+            .collect();
+        let trans = TransformBuilder::default()
+            .add_actions(actions)
+            .add_lookup_table("country_names", countries.clone())
+            .add_lookup_table("statuses", statuses)
+            .build()?;
+        let source = json!({"country_code": "CA", "status": 1, "unknown": "FR"});
+        let expected = json!({"country": "Canada", "status": {"active": true}});
+        assert_eq!(expected, trans.apply(&source)?);
+
+        let serialized = serde_json::to_string(&trans)?;
+        let trans: Transformer = serde_json::from_str(&serialized)?;
+        let trans = trans.with_lookup_table("country_names", countries);
+        let res = trans.apply(&source);
+        assert!(matches!(res, Err(Error::MissingLookupTable(name)) if name == "statuses"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_now() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new("now()", "ingested_at"),
+            Parsable::new(r#"now("unix_ms")"#, "ingested_ms"),
+            Parsable::new(r#"now("unix")"#, "ingested_secs"),
+        ])?;
+        let time = std::time::UNIX_EPOCH + std::time::Duration::from_millis(1_600_000_000_123);
+        let trans = TransformBuilder::default()
+            .add_actions(actions)
+            .clock(crate::context::FixedClock::new(time))
+            .build()?;
+        let expected = json!({
+            "ingested_at": "2020-09-13T12:26:40.123Z",
+            "ingested_ms": 1_600_000_000_123_u64,
+            "ingested_secs": 1_600_000_000
+        });
+        assert_eq!(expected, trans.apply(&json!({}))?);
+
+        let res = Parser::parse(r#"now("iso")"#, "x");
+        assert!(matches!(
+            res,
+            Err(crate::parser::Error::InvalidTimeFormat(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_pad() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new(r#"pad_start(8, "0", invoice_number)"#, "invoice"),
+            Parsable::new(r#"pad_start(8, "0", code)"#, "code"),
+            Parsable::new(r#"pad_end(6, "-=", name)"#, "name"),
+            Parsable::new(r#"pad_start(2, " ", name)"#, "long"),
+            Parsable::new(r#"pad_end(6, "-", active)"#, "active"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let source = json!({"invoice_number": 4521, "code": "A1", "name": "Zoë", "active": true});
+        let expected =
+            json!({"invoice": "00004521", "code": "000000A1", "name": "Zoë-=-", "long": "Zoë"});
+        assert_eq!(expected, trans.apply(&source)?);
+
+        let action = Parser::parse(r#"pad_start(4000000000, " ", name)"#, "name")?;
+        let trans = TransformBuilder::default()
+            .add_action(action)
+            .limits(Limits {
+                max_string_padding: Some(1000),
+                ..Limits::default()
+            })
+            .build()?;
+        assert!(matches!(
+            trans.apply(&source),
+            Err(Error::LimitExceeded {
+                limit: Limit::StringPadding,
+                max: 1000
+            })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_reduce() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new("reduce(items, sum($acc, $item.price), const(0))", "total"),
+            Parsable::new(
+                r#"reduce(items, if(gt($item.price, const(5)), sum($acc, const(1)), $acc), const(0))"#,
+                "expensive",
+            ),
+            Parsable::new(
+                r#"reduce(items, join("-", $acc, $item.sku), const(""))"#,
+                "key",
+            ),
+            Parsable::new("reduce(empty, sum($acc, $item), const(10))", "empty"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let source = json!({
+            "items": [{"sku": "a", "price": 3}, {"sku": "b", "price": 7}, {"sku": "c", "price": 9}],
+            "empty": [],
+        });
+        let expected = json!({"total": 19, "expensive": 2, "key": "a-b-c", "empty": 10});
+        assert_eq!(expected, trans.apply(&source)?);
+
+        assert!(Parser::parse("reduce(items, $acc)", "x").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_regex_extract() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new(r#"regex_extract("\d+", url)"#, "first"),
+            Parsable::new(r#"regex_extract("/users/(\d+)", url, 1)"#, "user_id"),
+            Parsable::new(
+                r#"regex_extract("/orders/(?P<id>\d+)", url, "id")"#,
+                "order_id",
+            ),
+            Parsable::new(r#"regex_extract("/items/(\d+)", url, 1)"#, "item_id"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let source = json!({"url": "https://example.com/v2/users/123/orders/456"});
+        let expected = json!({"first": "2", "user_id": "123", "order_id": "456"});
+        assert_eq!(expected, trans.apply(&source)?);
+
+        let serialized = serde_json::to_string(&trans)?;
+        let trans: Transformer = serde_json::from_str(&serialized)?;
+        assert_eq!(expected, trans.apply(&source)?);
+
+        for spec in &[
+            r#"regex_extract("(\d+)", url, 2)"#,
+            r#"regex_extract("(\d+)", url, "id")"#,
+        ] {
+            let res = Parser::parse(spec, "x");
+            assert!(matches!(
+                res,
+                Err(crate::parser::Error::InvalidCaptureGroup(_))
+            ));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_regex_replace() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new(r#"regex_replace("[^0-9]", "", phone)"#, "phone"),
+            Parsable::new(
+                r#"regex_replace("(?P<last>\w+),\s*(?P<first>\w+)", "${first} $1", name)"#,
+                "name",
+            ),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let source = json!({"phone": "(555) 123-4567", "name": "Karn, Dean"});
+        let expected = json!({"phone": "5551234567", "name": "Dean Karn"});
+        assert_eq!(expected, trans.apply(&source)?);
+
+        let serialized = serde_json::to_string(&trans)?;
+        let trans: Transformer = serde_json::from_str(&serialized)?;
+        assert_eq!(expected, trans.apply(&source)?);
+
+        let res = Parser::parse(r#"regex_replace("(", "", phone)"#, "x");
+        assert!(matches!(res, Err(crate::parser::Error::InvalidRegex(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_enumerate() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new("enumerate(tags)", "tags"),
+            Parsable::new(r#"enumerate(items, "position")"#, "items"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let source = json!({"tags": ["a", "b"], "items": [{"id": 7}, "x"]});
+        let expected = json!({
+            "tags": [{"index": 0, "value": "a"}, {"index": 1, "value": "b"}],
+            "items": [{"id": 7, "position": 0}, {"position": 1, "value": "x"}],
+        });
+        assert_eq!(expected, trans.apply(&source)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_env() -> Result<(), Box<dyn std::error::Error>> {
+        std::env::set_var("PROTEUS_TEST_DEPLOY_REGION", "ca-central-1");
+        let actions = Parser::parse_multi(&[
+            Parsable::new(r#"env("PROTEUS_TEST_DEPLOY_REGION")"#, "region"),
+            Parsable::new(r#"env("PROTEUS_TEST_UNSET", "us-east-1")"#, "default"),
+            Parsable::new(r#"env("PROTEUS_TEST_UNSET")"#, "missing"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let expected = json!({"region": "ca-central-1", "default": "us-east-1"});
+        assert_eq!(expected, trans.apply(&Value::Null)?);
+
+        let trans = trans.with_policy(Policy::sandboxed());
+        assert!(matches!(
+            trans.apply(&Value::Null),
+            Err(Error::PolicyViolation(Capability::Environment))
+        ));
+        let res = Parser::parse("env(DEPLOY_REGION)", "region");
+        assert!(matches!(
+            res,
+            Err(crate::parser::Error::InvalidQuotedValue(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_var() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new("id", "id"),
+            Parsable::new(r#"var("tenant_id")"#, "tenant_id"),
+            Parsable::new(r#"var("unknown")"#, "unknown"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let source = json!({"id": 1});
+        let context: HashMap<String, Value> = vec![("tenant_id".to_string(), json!("acme"))]
+            .into_iter()
+            .collect();
+        let expected = json!({"id": 1, "tenant_id": "acme"});
+        assert_eq!(expected, trans.apply_with_context(&source, &context)?);
+        assert_eq!(json!({"id": 1}), trans.apply(&source)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_epoch() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new("to_epoch(created_at)", "created_secs"),
+            Parsable::new(r#"to_epoch(created_at, "ms")"#, "created_ms"),
+            Parsable::new("from_epoch(updated)", "updated_at"),
+            Parsable::new(r#"from_epoch(updated_ms, "ms")"#, "updated_ms_at"),
+            Parsable::new("from_epoch(fractional)", "fractional_at"),
+            Parsable::new("to_epoch(invalid)", "invalid"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let source = json!({
+            "created_at": "2020-09-13T14:26:40.123+02:00",
+            "updated": 1_600_000_000,
+            "updated_ms": -1,
+            "fractional": 1.5,
+            "invalid": "yesterday"
+        });
+        let expected = json!({
+            "created_secs": 1_600_000_000,
+            "created_ms": 1_600_000_000_123_u64,
+            "updated_at": "2020-09-13T12:26:40Z",
+            "updated_ms_at": "1969-12-31T23:59:59.999Z",
+            "fractional_at": "1970-01-01T00:00:01.500Z"
+        });
+        assert_eq!(expected, trans.apply(&source)?);
+
+        let res = Parser::parse(r#"from_epoch(updated, "us")"#, "x");
+        assert!(matches!(res, Err(crate::parser::Error::InvalidTimeUnit(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_format() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new(
+                r#"format("Hello {first} {last}, {first}!", first_name, last_name)"#,
+                "greeting",
+            ),
+            Parsable::new(
+                r#"format("{} x{} {{{}}}", join(" ", first_name, last_name), qty, nickname)"#,
+                "line",
+            ),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let source = json!({"first_name": "Dean", "last_name": "Karn", "qty": 2});
+        let expected = json!({"greeting": "Hello Dean Karn, Dean!", "line": "Dean Karn x2 {}"});
+        assert_eq!(expected, trans.apply(&source)?);
+
+        for spec in &[
+            r#"format("{} {}", first_name)"#,
+            r#"format("{3}", first_name)"#,
+            r#"format("{first", first_name)"#,
+        ] {
+            let res = Parser::parse(spec, "x");
+            assert!(matches!(res, Err(crate::parser::Error::InvalidFormat(_))));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_if() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new("if(active, const(true), const(false))", "enabled"),
+            Parsable::new(
+                r#"if(nickname, join(" ", const("aka"), nickname), if(name, name, const("unknown")))"#,
+                "display",
+            ),
+            Parsable::new("if(admin, const(\"admin\"))", "role"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+
+        let source = json!({"active": "yes", "nickname": "Deano", "admin": false});
+        let expected = json!({"enabled": true, "display": "aka Deano"});
+        assert_eq!(expected, trans.apply(&source)?);
+
+        let source = json!({"active": null, "name": "Dean", "admin": true});
+        let expected = json!({"enabled": false, "display": "Dean", "role": "admin"});
+        assert_eq!(expected, trans.apply(&source)?);
+
+        let source = json!({});
+        let expected = json!({"enabled": false, "display": "unknown"});
+        assert_eq!(expected, trans.apply(&source)?);
+
+        for syntax in &["if(a)", "if(a, b, c, d)"] {
+            assert!(Parser::parse(syntax, "x").is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_switch() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new(
+                r#"switch(country, const("CA") -> const("Canada"), const("US") -> const("United States"), const("Unknown"))"#,
+                "country",
+            ),
+            Parsable::new(
+                r#"switch(code, const(1) -> join(", ", const("a->b"), name), default_code -> const("default"))"#,
+                "code",
+            ),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+
+        let source = json!({"country": "CA", "code": 1, "name": "Dean"});
+        let expected = json!({"country": "Canada", "code": "a->b, Dean"});
+        assert_eq!(expected, trans.apply(&source)?);
+
+        let source = json!({"country": "US", "code": 2, "default_code": 2});
+        let expected = json!({"country": "United States", "code": "default"});
+        assert_eq!(expected, trans.apply(&source)?);
+
+        let source = json!({"country": "MX", "code": 3});
+        let expected = json!({"country": "Unknown"});
+        assert_eq!(expected, trans.apply(&source)?);
+
+        for syntax in &[
+            "switch(a)",
+            "switch(a, b, c -> d)",
+            "switch(a, b -> c -> d)",
+        ] {
+            assert!(Parser::parse(syntax, "x").is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_lineage() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new("user_id", "id"),
+            Parsable::new(r#"join(" ", first_name, trim(last_name))"#, "name"),
+            Parsable::new("email", "contact.email").with_condition("verified"),
+            Parsable::new_multi("country", &["country", "address.country"]),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let lineage = trans.lineage();
+        let expected = json!([
+            {"sources": ["user_id"], "destination": "id", "actions": ["Setter", "Getter"]},
+            {"sources": ["first_name", "last_name"], "destination": "name", "actions": ["Setter", "Join", "Getter", "Trim"]},
+            {"sources": ["verified", "email"], "destination": "contact.email", "actions": ["When", "Getter", "Setter"]},
+            {"sources": ["country"], "destination": "country", "actions": ["MultiSetter", "Getter"]},
+            {"sources": ["country"], "destination": "address.country", "actions": ["MultiSetter", "Getter"]}
+        ]);
+        assert_eq!(expected, serde_json::to_value(&lineage)?);
+        Ok(())
+    }
+
+    #[cfg(feature = "arbitrary_precision")]
+    #[test]
+    fn arbitrary_precision() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new("id", "id"),
+            Parsable::new("sum(price, tax)", "total"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let res = trans.apply_from_str(
+            r#"{"id": 123456789012345678901234567890, "price": 0.1, "tax": 0.2}"#,
+        )?;
+        assert_eq!(
+            r#"{"id":123456789012345678901234567890,"total":0.3}"#,
+            serde_json::to_string(&res)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn null_for_missing() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new("name", "name"),
+            Parsable::new("nickname", "nickname"),
+            Parsable::new("email", "email"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let source = json!({"name": "Dean", "nickname": null});
+        assert_eq!(
+            json!({"name": "Dean", "nickname": null}),
+            trans.apply(&source)?
+        );
+
+        let trans = trans.with_null_for_missing(true);
+        assert_eq!(
+            json!({"name": "Dean", "nickname": null, "email": null}),
+            trans.apply(&source)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn null_for_missing_merges() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new("profile", "profile"),
+            Parsable::new("extra", "profile{}"),
+            Parsable::new("extra", "profile{*}"),
+            Parsable::new("tags", "tags"),
+            Parsable::new("more_tags", "tags[+]"),
+            Parsable::new("more_tags", "tags[-]"),
+            Parsable::new("user", "users[=id]"),
+            Parsable::new("user", "users[=id+]"),
+            Parsable::new("names", "people[*].name"),
+            Parsable::new_multi("extra", &["extra", "profile{}"]),
+        ])?;
+        let trans = TransformBuilder::default()
+            .add_actions(actions)
+            .null_for_missing(true)
+            .build()?;
+        let source = json!({"profile": {"name": "Dean"}, "tags": ["a"]});
+        let expected = json!({
+            "profile": {"name": "Dean"},
+            "tags": ["a"],
+            "extra": null
+        });
+        assert_eq!(expected, trans.apply(&source)?);
+        Ok(())
+    }
+
+    #[test]
+    fn strict() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new("strict(name)", "name"),
+            Parsable::new("coalesce(nickname, name)", "nickname"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        assert_eq!(
+            json!({"name": "Dean", "nickname": "Dean"}),
+            trans.apply(&json!({"name": "Dean"}))?
+        );
+        let res = trans.apply(&json!({"nickname": "Deano"}));
+        assert!(matches!(
+            res,
+            Err(Error::Getter(GetterError::MissingPath { at, .. })) if at == "name"
+        ));
+
+        let trans = trans.with_strict(true);
+        let res = trans.apply(&json!({"name": "Dean"}));
+        assert_eq!(json!({"name": "Dean", "nickname": "Dean"}), res?);
+        let res = trans.apply(&json!({"name": "Dean", "nickname": ["Deano"]}));
+        assert_eq!(json!({"name": "Dean", "nickname": ["Deano"]}), res?);
+
+        let actions = Parser::parse_multi(&[
+            Parsable::new("name", "name"),
+            Parsable::new("exists(nick)", "has_nick"),
+            Parsable::new("nick|name", "alias"),
+            Parsable::new(r#"default(nick, const("none"))"#, "default"),
+            Parsable::new("nick", "nick").with_condition("exists(nick)"),
+            Parsable::new("name", "verified").with_condition("verified"),
+            Parsable::new(r#"when(nick, const(true))"#, "when"),
+            Parsable::new(r#"if(nick, const("nick"), const("name"))"#, "if"),
+        ])?;
+        let trans = TransformBuilder::default()
+            .add_actions(actions)
+            .strict(true)
+            .build()?;
+        let expected = json!({
+            "name": "Dean",
+            "has_nick": false,
+            "alias": "Dean",
+            "default": "none",
+            "if": "name"
+        });
+        assert_eq!(expected, trans.apply(&json!({"name": "Dean"}))?);
+        let res = trans.apply(&json!({"nick": "Deano"}));
+        assert!(matches!(
+            res,
+            Err(Error::Getter(GetterError::MissingPath { at, .. })) if at == "name"
+        ));
+
+        let action = Parser::parse("when(exists(nick), nick.first)", "nick")?;
+        let trans = TransformBuilder::default()
+            .add_action(action)
+            .strict(true)
+            .build()?;
+        let res = trans.apply(&json!({"nick": {}}));
+        assert!(matches!(
+            res,
+            Err(Error::Getter(GetterError::MissingPath { at, .. })) if at == "nick.first"
+        ));
+
+        let action = Parser::parse("strict(profile[0].name)", "name")?;
+        let trans = TransformBuilder::default().add_action(action).build()?;
+        let err = trans
+            .apply(&json!({"profile": {"name": "Dean"}}))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid source type at 'profile[0]' of getter namespace 'profile[0].name', expected an Array but found an Object."
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn stream() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[Parsable::new("name", "user.name")])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+
+        let input = r#"[{"name": "Dean"}, {"name": "Joey"}, {}]"#;
+        let mut output = Vec::new();
+        trans.apply_stream(input.as_bytes(), &mut output)?;
+        assert_eq!(
+            r#"[{"user":{"name":"Dean"}},{"user":{"name":"Joey"}},null]"#,
+            String::from_utf8(output)?
+        );
+
+        let mut output = Vec::new();
+        trans.apply_stream("[]".as_bytes(), &mut output)?;
+        assert_eq!("[]", String::from_utf8(output)?);
+
+        let res = trans.apply_stream(r#"{"name": "Dean"}"#.as_bytes(), Vec::new());
+        assert!(matches!(res, Err(Error::JSONError(_))));
+
+        let trans = TransformBuilder::default()
+            .add_actions(Parser::parse_multi(&[Parsable::new("name", "[2]")])?)
+            .limits(Limits {
+                max_array_padding: Some(1),
+                ..Limits::default()
+            })
+            .build()?;
+        let res = trans.apply_stream(r#"[{"name": "Dean"}]"#.as_bytes(), Vec::new());
+        assert!(matches!(res, Err(Error::LimitExceeded { .. })));
+        Ok(())
+    }
+
+    #[test]
+    fn to_map() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[Parsable::new("name", "user.name")])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let map = trans.apply_to_map(&json!({"name": "Dean Karn"}))?;
+        assert_eq!(Some(&json!({"name": "Dean Karn"})), map.get("user"));
+        assert!(trans.apply_to_map(&json!({}))?.is_empty());
+
+        let actions = Parser::parse_multi(&[Parsable::new("name", "[]")])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let res = trans.apply_to_map(&json!({"name": "Dean Karn"}));
+        assert!(matches!(res, Err(Error::NonObjectRoot(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn to_string() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[Parsable::new("name", "user.name")])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let source = json!({"name": "Dean Karn"});
+        assert_eq!(
+            r#"{"user":{"name":"Dean Karn"}}"#,
+            trans.apply_to_string(&source)?
+        );
+        assert_eq!(
+            "{\n  \"user\": {\n    \"name\": \"Dean Karn\"\n  }\n}",
+            trans.apply_to_string_pretty(&source)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn canonical() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new("price", "price"),
+            Parsable::new("name", "item.name"),
+            Parsable::new("id", "id"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let source = json!({"id": 1, "name": "Widget", "price": 10.0});
+        assert_eq!(
+            r#"{"id":1,"item":{"name":"Widget"},"price":10}"#,
+            trans.apply_canonical_string(&source)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn limits() -> Result<(), Box<dyn std::error::Error>> {
+        let limits = Limits {
+            max_array_padding: Some(10),
+            ..Limits::default()
+        };
+        let action = Parser::parse("const(1)", "items[100000000]")?;
+        let trans = TransformBuilder::default()
+            .add_action(action)
+            .limits(limits)
+            .build()?;
+        assert!(matches!(
+            trans.apply(&Value::Null),
+            Err(Error::LimitExceeded {
+                limit: Limit::ArrayPadding,
+                max: 10
+            })
+        ));
+
+        let limits = Limits {
+            max_destination_nodes: Some(4),
+            ..Limits::default()
+        };
+        let actions =
+            Parser::parse_multi(&[Parsable::new("", "copy1"), Parsable::new("", "copy2")])?;
+        let trans = TransformBuilder::default()
+            .add_actions(actions)
+            .limits(limits)
+            .build()?;
+        assert!(trans.apply(&json!([1])).is_ok());
+        assert!(matches!(
+            trans.apply(&json!([1, 2])),
+            Err(Error::LimitExceeded {
+                limit: Limit::DestinationNodes,
+                max: 4
+            })
+        ));
+
+        let actions = Parser::parse_multi(&[
+            Parsable::new("", "copy1"),
+            Parsable::new("", "copy1??"),
+            Parsable::new("[0]", "first??"),
+        ])?;
+        let trans = TransformBuilder::default()
+            .add_actions(actions)
+            .limits(limits)
+            .build()?;
+        assert_eq!(
+            json!({"copy1": [1, 2], "first": 1}),
+            trans.apply(&json!([1, 2]))?
+        );
+        assert!(matches!(
+            trans.apply(&json!([1, 2, 3])),
+            Err(Error::LimitExceeded {
+                limit: Limit::DestinationNodes,
+                max: 4
+            })
+        ));
+
+        let limits = Limits {
+            max_action_evaluations: Some(4),
+            ..Limits::default()
+        };
+        let action = Parser::parse(r#"join(" ", first_name, last_name)"#, "full_name")?;
+        let trans = TransformBuilder::default()
+            .add_action(action)
+            .limits(limits)
+            .build()?;
+        assert!(trans.apply(&Value::Null).is_ok());
+        let action = Parser::parse(
+            r#"join(" ", const("Mr."), first_name, last_name)"#,
+            "full_name",
+        )?;
+        let trans = TransformBuilder::default()
+            .add_action(action)
+            .limits(limits)
+            .build()?;
+        assert!(trans.apply(&Value::Null).is_err());
+
+        let limits = Limits {
+            max_namespace_depth: Some(2),
+            ..Limits::default()
+        };
+        let action = Parser::parse("person.name", "person.full_name")?;
+        let trans = TransformBuilder::default()
+            .add_action(action)
+            .limits(limits)
+            .build()?;
+        assert!(trans.apply(&Value::Null).is_ok());
+        let action = Parser::parse("person.name", "person.name.full")?;
+        let trans = TransformBuilder::default()
+            .add_action(action)
+            .limits(limits)
+            .build()?;
+        assert!(matches!(
+            trans.apply(&Value::Null),
+            Err(Error::LimitExceeded {
+                limit: Limit::NamespaceDepth,
+                max: 2
+            })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn policy() -> Result<(), Box<dyn std::error::Error>> {
+        let action = Box::new(Setter::new(
+            Namespace::parse("env")?,
+            Box::new(EnvironmentAction),
+        ));
+        let trans = TransformBuilder::default().add_action(action).build()?;
+        assert_eq!(json!({"env": "value"}), trans.apply(&Value::Null)?);
+
+        let serialized = serde_json::to_string(&trans)?;
+        let trans: Transformer = serde_json::from_str(&serialized)?;
+        let trans = trans.with_policy(Policy::sandboxed());
+        assert!(matches!(
+            trans.apply(&Value::Null),
+            Err(Error::PolicyViolation(Capability::Environment))
+        ));
+
+        let action = Box::new(Setter::new(
+            Namespace::parse("env")?,
+            Box::new(EnvironmentAction),
+        ));
+        let trans = TransformBuilder::default()
+            .add_action(action)
+            .policy(Policy {
+                allow_environment: false,
+                ..Policy::default()
+            })
+            .build()?;
+        assert!(trans.apply(&Value::Null).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn transformer_serialization() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new("person.name", "person[0]"),
+            Parsable::new("person.metadata", "person[0]"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let res = serde_json::to_string(&trans)?;
+        assert_eq!(res, "{\"actions\":[{\"type\":\"Setter\",\"namespace\":[{\"Object\":{\"id\":\"person\"}},{\"Array\":{\"index\":0}}],\"child\":{\"type\":\"Getter\",\"namespace\":[{\"Object\":{\"id\":\"person\"}},{\"Object\":{\"id\":\"name\"}}]}},{\"type\":\"Setter\",\"namespace\":[{\"Object\":{\"id\":\"person\"}},{\"Array\":{\"index\":0}}],\"child\":{\"type\":\"Getter\",\"namespace\":[{\"Object\":{\"id\":\"person\"}},{\"Object\":{\"id\":\"metadata\"}}]}}]}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_and_get_top_level() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[Parsable::new("", "")])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let input = json!({
+            "existing_key":"my_val1",
+            "my_array":["idx_0_value"]
+        });
+        let expected = json!({"existing_key":"my_val1","my_array":["idx_0_value"]});
+        let output = trans.apply(&input)?;
+        assert_eq!(expected, output);
+        Ok(())
+    }
+
+    #[test]
+    fn test_seq() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new(r#"seq("orders")"#, "order"),
+            Parsable::new(r#"seq("rows")"#, "row"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        assert_eq!(json!({"order": 1, "row": 1}), trans.apply(&Value::Null)?);
+        assert_eq!(json!({"order": 2, "row": 2}), trans.apply(&Value::Null)?);
+
+        let trans = std::sync::Arc::new(trans);
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let trans = trans.clone();
+                std::thread::spawn(move || trans.apply(&Value::Null))
+            })
+            .collect();
+        let mut orders = Vec::new();
+        for h in handles {
+            orders.push(h.join().unwrap()?["order"].as_u64().unwrap());
+        }
+        orders.sort_unstable();
+        assert_eq!(vec![3, 4, 5, 6], orders);
+        Ok(())
+    }
+
+    #[test]
+    fn test_stringify() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new("stringify(nested)", "payload"),
+            Parsable::new("stringify(nested.name)", "name"),
+            Parsable::new("stringify(missing)", "missing"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let source = json!({"nested": {"name": "Dean \"DK\" Karn", "tags": [1, null]}});
+        let expected = json!({
+            "payload": r#"{"name":"Dean \"DK\" Karn","tags":[1,null]}"#,
+            "name": r#""Dean \"DK\" Karn""#
+        });
+        assert_eq!(expected, trans.apply(&source)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_substring() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new("substring(0, 10, timestamp)", "date"),
+            Parsable::new("substring(1, 3, name)", "middle"),
+            Parsable::new("substring(2, 100, name)", "clamped"),
+            Parsable::new("substring(5, 2, name)", "empty"),
+            Parsable::new("substring(0, 2, id)", "id"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let source = json!({"timestamp": "2020-01-01T00:00:00Z", "name": "Zoë!", "id": 1});
+        let expected = json!({"date": "2020-01-01", "middle": "oë", "clamped": "ë!", "empty": ""});
+        assert_eq!(expected, trans.apply(&source)?);
+
+        let res = Parser::parse("substring(-1, 2, name)", "x");
+        assert!(matches!(res, Err(crate::parser::Error::ValueParseError(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_aggregate() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new("min(scores)", "min"),
+            Parsable::new("max(scores, bonus, const(0))", "max"),
+            Parsable::new("avg(scores)", "avg"),
+            Parsable::new("avg(const([2, 4]))", "avg_int"),
+            Parsable::new("max(name)", "none"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let source = json!({"scores": [7, "n/a", 2.5, 10], "bonus": 12, "name": "Dean"});
+        let expected = json!({"min": 2.5, "max": 12, "avg": 6.5, "avg_int": 3});
+        assert_eq!(expected, trans.apply(&source)?);
+
+        let action = Parser::parse("avg(values)", "avg")?;
+        let trans = TransformBuilder::default().add_action(action).build()?;
+        let source = json!({"values": [i64::MAX, i64::MAX, i64::MAX]});
+        let avg = trans.apply(&source)?["avg"].as_f64();
+        assert_eq!(Some(i64::MAX as f64), avg);
+
+        #[cfg(not(feature = "arbitrary_precision"))]
+        {
+            let source = json!({"values": [1, f64::MAX, f64::MAX]});
+            assert_eq!(Value::Null, trans.apply(&source)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_arithmetic() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new("sub(total, discounts)", "net"),
+            Parsable::new("mul(qty, price, const(100))", "cents"),
+            Parsable::new("div(total, qty)", "unit"),
+            Parsable::new("div(mul(qty, const(100)), const(8))", "percent"),
+            Parsable::new("mod(qty, const(3))", "remainder"),
+            Parsable::new("div(total, const(0))", "invalid"),
+            Parsable::new("sub(name)", "none"),
+            Parsable::new("product(qty, prices)", "product"),
+            Parsable::new("product(qty, const(0.5))", "half"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let source = json!({
+            "total": 100,
+            "discounts": [5, 10],
+            "qty": 4,
+            "price": 3,
+            "prices": [2, 5],
+            "name": "Dean"
+        });
+        let expected = json!({
+            "net": 85,
+            "cents": 1200,
+            "unit": 25,
+            "percent": 50,
+            "remainder": 1,
+            "product": 40,
+            "half": 2.0
+        });
+        assert_eq!(expected, trans.apply(&source)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_round() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new("round(2, div(total_cents, const(3)))", "total"),
+            Parsable::new("round(price)", "rounded"),
+            Parsable::new("floor(1, price)", "floor"),
+            Parsable::new("ceil(price)", "ceil"),
+            Parsable::new("round(2, qty)", "qty"),
+            Parsable::new("round(name)", "name"),
+            Parsable::new("round(2, const(1e308))", "large"),
+            Parsable::new("ceil(3, const(1.7976931348623157e308))", "max"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let source = json!({"total_cents": 1000, "price": 2.46, "qty": 3, "name": "Dean"});
+        let expected = json!({
+            "total": 333.33,
+            "rounded": 2,
+            "floor": 2.4,
+            "ceil": 3,
+            "qty": 3,
+            "large": 1e308,
+            "max": f64::MAX
+        });
+        assert_eq!(expected, trans.apply(&source)?);
+
+        assert!(Parser::parse("round(15, price)", "price").is_ok());
+        for precision in &["16", "4294967295", "-1", "1.5"] {
+            let res = Parser::parse(&format!("round({}, price)", precision), "price");
+            assert!(
+                matches!(res, Err(crate::parser::Error::InvalidPrecision(_))),
+                "{}",
+                precision
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_sum() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new(r#"sum(const(1.1), arr, len(obj))"#, "sum"),
+            Parsable::new("sum(len(arr))", "sum2"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+
+        let input = json!({
+            "arr": [1, 2, 3],
+            "obj": {"key":"value"}
+        });
+        let expected = json!({"sum":8.1, "sum2": 3});
+        let output = trans.apply(&input)?;
+        assert_eq!(expected, output);
+
+        let actions = Parser::parse_multi(&[Parsable::new("sum()", "sum")])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+
+        let input = json!([1, 2, 3]);
+        let expected = json!({"sum":6});
+        let output = trans.apply(&input)?;
+        assert_eq!(expected, output);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_members() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new("keys(prices)", "currencies"),
+            Parsable::new("len(keys(prices))", "count"),
+            Parsable::new("keys(name)", "name"),
+            Parsable::new("values(prices)", "amounts"),
+            Parsable::new("values(name)", "values"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let source = json!({"prices": {"CAD": 10, "USD": 8}, "name": "widget"});
+        let expected = json!({"currencies": ["CAD", "USD"], "count": 2, "amounts": [10, 8]});
+        assert_eq!(expected, trans.apply(&source)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_select_keys() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new(r#"pick(user, "id", "name", "missing")"#, "user"),
+            Parsable::new(r#"pick(user.name, "id")"#, "name"),
+            Parsable::new(r#"omit(user, "password", "missing")"#, "safe_user"),
+            Parsable::new(
+                r#"omit(pick(user, "id", "password"), "password")"#,
+                "id_only",
+            ),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let source = json!({"user": {"id": 1, "name": "Dean", "password": "secret"}});
+        let expected = json!({
+            "user": {"id": 1, "name": "Dean"},
+            "safe_user": {"id": 1, "name": "Dean"},
+            "id_only": {"id": 1},
+        });
+        assert_eq!(expected, trans.apply(&source)?);
+
+        assert!(Parser::parse("pick(user)", "x").is_err());
+        assert!(Parser::parse("pick(user, id)", "x").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_rename_keys() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new(
+                r#"rename_keys(user, {"fname": "first_name", "lname": "last_name"})"#,
+                "user",
+            ),
+            Parsable::new(r#"rename_keys(user.id, {"id": "user_id"})"#, "id"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let source = json!({"user": {"id": 1, "fname": "Dean", "lname": "Karn"}});
+        let expected = json!({"user": {"id": 1, "first_name": "Dean", "last_name": "Karn"}});
+        assert_eq!(expected, trans.apply(&source)?);
+
+        assert!(Parser::parse(r#"rename_keys(user, {"id": 1})"#, "x").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_prune() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new("prune(profile)", "all"),
+            Parsable::new(r#"prune(profile, "nulls")"#, "nulls"),
+            Parsable::new("prune(profile.middle_name)", "middle_name"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let source = json!({"profile": {
+            "name": "Dean",
+            "middle_name": null,
+            "nickname": "",
+            "tags": [null, "a", {"b": null}],
+            "address": {"street": null, "unit": []},
+        }});
+        let expected = json!({
+            "all": {"name": "Dean", "tags": ["a"]},
+            "nulls": {"name": "Dean", "nickname": "", "tags": ["a", {}], "address": {"unit": []}},
+        });
+        assert_eq!(expected, trans.apply(&source)?);
+
+        assert!(matches!(
+            Parser::parse(r#"prune(profile, "zeros")"#, "x"),
+            Err(crate::parser::Error::InvalidPruneOption(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_element() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new("first(results)", "first"),
+            Parsable::new("last(results)", "last"),
+            Parsable::new("last(sort(results))", "max"),
+            Parsable::new("first(empty)", "empty"),
+            Parsable::new("last(name)", "name"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let source = json!({"results": [2, 3, 1], "empty": [], "name": "Dean"});
+        let expected = json!({"first": 2, "last": 1, "max": 3});
+        assert_eq!(expected, trans.apply(&source)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_flatten() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new("flatten(groups)", "one"),
+            Parsable::new("flatten(groups, 2)", "two"),
+            Parsable::new("flatten(map(orders, items))", "items"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let source = json!({
+            "groups": [[1, 2], [3, [4, [5]]], 6],
+            "orders": [{"items": ["a", "b"]}, {"items": ["c"]}],
+        });
+        let expected = json!({
+            "one": [1, 2, 3, [4, [5]], 6],
+            "two": [1, 2, 3, 4, [5], 6],
+            "items": ["a", "b", "c"],
+        });
+        assert_eq!(expected, trans.apply(&source)?);
+
+        assert!(Parser::parse("flatten(groups, -1)", "x").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_flatten_keys() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new("flatten_keys(profile)", "flat"),
+            Parsable::new(r#"flatten_keys(profile, "_")"#, "underscore"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let source = json!({"profile": {"a": {"b": 1, "c": {"d": [1, 2]}}, "e": {}, "f": null}});
+        let expected = json!({
+            "flat": {"a.b": 1, "a.c.d": [1, 2], "e": {}, "f": null},
+            "underscore": {"a_b": 1, "a_c_d": [1, 2], "e": {}, "f": null},
+        });
+        assert_eq!(expected, trans.apply(&source)?);
+
+        assert!(Parser::parse(r#"flatten_keys(profile, "")"#, "x").is_err());
+
+        let actions = Parser::parse_multi(&[
+            Parsable::new("unflatten_keys(row)", "nested"),
+            Parsable::new("unflatten_keys(flatten_keys(profile))", "profile"),
+            Parsable::new(r#"unflatten_keys(row, "_")"#, "underscore"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let source = json!({
+            "row": {"a.b": 1, "a.c": 2, "d": 3, "e_f": 4},
+            "profile": {"a": {"b": 1, "c": {"d": [1, 2]}}, "e": {}},
+        });
+        let expected = json!({
+            "nested": {"a": {"b": 1, "c": 2}, "d": 3, "e_f": 4},
+            "profile": {"a": {"b": 1, "c": {"d": [1, 2]}}, "e": {}},
+            "underscore": {"a.b": 1, "a.c": 2, "d": 3, "e": {"f": 4}},
+        });
+        assert_eq!(expected, trans.apply(&source)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_map() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new(
+                r#"map(addresses, join(" ", number, street) -> line, postal_code -> code, index() -> position)"#,
+                "addresses",
+            ),
+            Parsable::new("map(tags, upper())", "tags"),
+            Parsable::new("map(name, upper())", "name"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let source = json!({
+            "name": "Dean",
+            "tags": ["a", "b"],
+            "addresses": [
+                {"number": "26", "street": "Here Blvd", "postal_code": "A1A"},
+                {"number": "1", "street": "There St"},
+            ],
+        });
+        let expected = json!({
+            "tags": ["A", "B"],
+            "addresses": [
+                {"line": "26 Here Blvd", "code": "A1A", "position": 0},
+                {"line": "1 There St", "position": 1},
+            ],
+        });
+        assert_eq!(expected, trans.apply(&source)?);
+
+        assert!(Parser::parse("map(tags)", "x").is_err());
+        assert!(matches!(
+            Parser::parse("map(tags, a -> b -> c)", "x"),
+            Err(crate::parser::Error::InvalidMapping(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_filter() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new("filter(items, gt(price, const(100)))", "expensive"),
+            Parsable::new("map(filter(items, not(price)), name)", "free"),
+            Parsable::new("filter(tags, ne(index(), const(0)))", "tags"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let source = json!({
+            "items": [
+                {"name": "a", "price": 150},
+                {"name": "b", "price": 50},
+                {"name": "c", "price": 101.5},
+                {"name": "d"},
+            ],
+            "tags": ["x", "y", "z"],
+        });
+        let expected = json!({
+            "expensive": [{"name": "a", "price": 150}, {"name": "c", "price": 101.5}],
+            "free": ["d"],
+            "tags": ["y", "z"],
+        });
+        assert_eq!(expected, trans.apply(&source)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_slice() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new("slice(1, 3, results)", "page"),
+            Parsable::new("slice(4, 100, results)", "clamped"),
+            Parsable::new("slice(3, 1, results)", "empty"),
+            Parsable::new("take(2, results)", "first"),
+            Parsable::new("skip(3, results)", "rest"),
+            Parsable::new("take(2, name)", "name"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let source = json!({"results": [1, 2, 3, 4, 5], "name": "Dean"});
+        let expected = json!({
+            "page": [2, 3],
+            "clamped": [5],
+            "empty": [],
+            "first": [1, 2],
+            "rest": [4, 5],
+        });
+        assert_eq!(expected, trans.apply(&source)?);
+
+        assert!(Parser::parse("take(-1, results)", "x").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_sort() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new("sort(mixed)", "mixed"),
+            Parsable::new(r#"sort(tags, "desc")"#, "tags"),
+            Parsable::new(r#"map(sort_by(results, score, "desc"), name)"#, "ranked"),
+            Parsable::new("map(sort_by(results, len(name)), name)", "by_length"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let source = json!({
+            "mixed": ["b", 10, null, true, 2.5, "a", [1], false],
+            "tags": ["x", "z", "y"],
+            "results": [
+                {"name": "ccc", "score": 5},
+                {"name": "a", "score": 7.5},
+                {"name": "bb"},
+                {"name": "dd", "score": 5},
+            ],
+        });
+        let expected = json!({
+            "mixed": [null, false, true, 2.5, 10, "a", "b", [1]],
+            "tags": ["z", "y", "x"],
+            "ranked": ["a", "ccc", "dd", "bb"],
+            "by_length": ["a", "bb", "dd", "ccc"],
+        });
+        assert_eq!(expected, trans.apply(&source)?);
+
+        assert!(matches!(
+            Parser::parse(r#"sort(tags, "up")"#, "x"),
+            Err(crate::parser::Error::InvalidSortOrder(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_reverse() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new("reverse(events)", "events"),
+            Parsable::new("reverse(sort(tags))", "tags"),
+            Parsable::new("reverse(name)", "name"),
+            Parsable::new("reverse(id)", "id"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let source = json!({"events": [1, 2, 3], "tags": ["b", "a", "c"], "name": "Zoë", "id": 1});
+        let expected = json!({"events": [3, 2, 1], "tags": ["c", "b", "a"], "name": "ëoZ"});
+        assert_eq!(expected, trans.apply(&source)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_unique() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new("unique(tags)", "tags"),
+            Parsable::new("map(unique_by(users, email), id)", "users"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let source = json!({
+            "tags": ["b", "a", "b", 1, "1", {"x": 1}, {"x": 1}],
+            "users": [
+                {"id": 1, "email": "a@example.com"},
+                {"id": 2},
+                {"id": 3, "email": "a@example.com"},
+                {"id": 4, "email": "b@example.com"},
+                {"id": 5, "email": null},
+            ],
+        });
+        let expected = json!({"tags": ["b", "a", 1, "1", {"x": 1}], "users": [1, 2, 4]});
+        assert_eq!(expected, trans.apply(&source)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_group_by() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new("group_by(orders, customer_id)", "by_customer"),
+            Parsable::new("group_by(orders, gt(total, const(10)))", "large"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let source = json!({"orders": [
+            {"id": 1, "customer_id": "a", "total": 5},
+            {"id": 2, "customer_id": 7, "total": 20},
+            {"id": 3, "customer_id": "a", "total": 15},
+            {"id": 4, "total": 1},
+        ]});
+        let expected = json!({
+            "by_customer": {
+                "a": [
+                    {"id": 1, "customer_id": "a", "total": 5},
+                    {"id": 3, "customer_id": "a", "total": 15},
+                ],
+                "7": [{"id": 2, "customer_id": 7, "total": 20}],
+            },
+            "large": {
+                "false": [
+                    {"id": 1, "customer_id": "a", "total": 5},
+                    {"id": 4, "total": 1},
+                ],
+                "true": [
+                    {"id": 2, "customer_id": 7, "total": 20},
+                    {"id": 3, "customer_id": "a", "total": 15},
+                ],
+            },
+        });
+        assert_eq!(expected, trans.apply(&source)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_index_of() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new(r#"index_of(priorities, const("high"))"#, "high"),
+            Parsable::new("index_of(priorities, level)", "level"),
+            Parsable::new("index_of(numbers, const(2.0))", "number"),
+            Parsable::new(r#"index_of(priorities, const("none"))"#, "none"),
+            Parsable::new(r#"index_of(level, const("low"))"#, "invalid"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let source = json!({
+            "priorities": ["low", "medium", "high", "high"],
+            "level": "medium",
+            "numbers": [1, 2, 3],
+        });
+        let expected = json!({"high": 2, "level": 1, "number": 1, "none": null});
+        assert_eq!(expected, trans.apply(&source)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_len() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new("len()", "len1"),
+            Parsable::new("len(arr)", "len2"),
+            Parsable::new("len(obj)", "len3"),
+            Parsable::new("len(obj.key)", "len4"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+
+        let input = json!({
+            "arr": [1, 2, 3],
+            "obj": {"key":"value"}
+        });
+        let expected = json!({"len1": 2, "len2": 3, "len3": 1, "len4": 5});
+        let output = trans.apply(&input)?;
+        assert_eq!(expected, output);
+        Ok(())
+    }
+
+    #[test]
+    fn test_len_chars() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new("len(name)", "bytes"),
+            Parsable::new("len_chars(name)", "chars"),
+            Parsable::new("len_chars(arr)", "arr"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let input = json!({"name": "Zoë", "arr": [1, 2]});
+        let expected = json!({"bytes": 4, "chars": 3, "arr": 2});
+        assert_eq!(expected, trans.apply(&input)?);
+        Ok(())
+    }
+
+    #[cfg(feature = "graphemes")]
+    #[test]
+    fn test_len_graphemes() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new("len_chars(name)", "chars"),
+            Parsable::new("len_graphemes(name)", "graphemes"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let input = json!({"name": "Zoe\u{301} 👍🏽"});
+        let expected = json!({"chars": 7, "graphemes": 5});
+        assert_eq!(expected, trans.apply(&input)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_trim() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new("trim(key)", "res1"),
+            Parsable::new("trim_start(key)", "res2"),
+            Parsable::new("trim_end(key)", "res3"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+
+        let input = json!({"key": " value "});
+        let expected = json!({"res1": "value", "res2": "value ", "res3": " value"});
+        let output = trans.apply(&input)?;
+        assert_eq!(expected, output);
+        Ok(())
+    }
+
+    #[test]
+    fn test_strip() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new(r#"strip_prefix("v", key)"#, "res1"),
+            Parsable::new(r#"strip_suffix("e", key)"#, "res2"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+
+        let input = json!({"key": "value"});
+        let expected = json!({"res1": "alue", "res2": "valu"});
+        let output = trans.apply(&input)?;
+        assert_eq!(expected, output);
+        Ok(())
+    }
+
+    #[cfg(feature = "base64")]
+    #[test]
+    fn test_base64() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new("base64_encode(blob)", "encoded"),
+            Parsable::new("base64_decode(payload)", "decoded"),
+            Parsable::new("base64_decode(base64_encode(blob))", "round_trip"),
+            Parsable::new("base64_decode(invalid)", "invalid"),
+            Parsable::new("base64_encode(id)", "id"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let source = json!({
+            "blob": "héllo?",
+            "payload": "eyJpZCI6MX0=",
+            "invalid": "not base64!",
+            "id": 1
+        });
+        let expected = json!({
+            "encoded": "aMOpbGxvPw==",
+            "decoded": r#"{"id":1}"#,
+            "round_trip": "héllo?"
+        });
+        assert_eq!(expected, trans.apply(&source)?);
+        Ok(())
+    }
+
+    #[cfg(feature = "hash")]
+    #[test]
+    fn test_hash() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new("sha256(email)", "sha256"),
+            Parsable::new("md5(email)", "md5"),
+            Parsable::new("sha256(id)", "id"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let source = json!({"email": "abc", "id": 1});
+        let expected = json!({
+            "sha256": "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+            "md5": "900150983cd24fb0d6963f7d28e17f72"
+        });
+        assert_eq!(expected, trans.apply(&source)?);
+        Ok(())
+    }
+
+    #[cfg(feature = "hash")]
+    #[test]
+    fn test_hmac() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[Parsable::new(r#"hmac("pii", email)"#, "email")])?;
+        let trans = TransformBuilder::default()
+            .add_actions(actions)
+            .key("pii", "key")
+            .build()?;
+        let source = json!({"email": "The quick brown fox jumps over the lazy dog"});
+        let expected = json!({
+            "email": "f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8"
+        });
+        assert_eq!(expected, trans.apply(&source)?);
+        assert!(format!("{:?}", trans).contains(r#"keys: {"pii"}"#));
+
+        let serialized = serde_json::to_string(&trans)?;
+        let trans: Transformer = serde_json::from_str(&serialized)?;
+        let res = trans.apply(&source);
+        assert!(matches!(res, Err(Error::MissingKey(id)) if id == "pii"));
+
+        let trans = trans.with_key("pii", "key");
+        assert_eq!(expected, trans.apply(&source)?);
+        Ok(())
+    }
+
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn test_uuid() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new("uuid()", "trace_id"),
+            Parsable::new("uuid()", "span_id"),
+        ])?;
+        let trans = TransformBuilder::default()
+            .add_actions(actions)
+            .random_source(crate::context::SeededRandom::new(7))
+            .build()?;
+        let output = trans.apply(&json!({}))?;
+        let trace_id = output["trace_id"].as_str().unwrap();
+        let span_id = output["span_id"].as_str().unwrap();
+        assert_eq!(36, trace_id.len());
+        assert_eq!(Some('4'), trace_id.chars().nth(14));
+        assert_ne!(trace_id, span_id);
+        assert_ne!(output, trans.apply(&json!({}))?);
+
+        let actions = Parser::parse_multi(&[Parsable::new("uuid()", "trace_id")])?;
+        let seeded = TransformBuilder::default()
+            .add_actions(actions)
+            .random_source(crate::context::SeededRandom::new(7))
+            .build()?;
+        assert_eq!(output["trace_id"], seeded.apply(&json!({}))?["trace_id"]);
+        Ok(())
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_random() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new("random_int(1, 6)", "roll"),
+            Parsable::new(r#"random_choice(const(["a", "b", "c"]))"#, "choice"),
+            Parsable::new("random_choice(const([]))", "empty"),
+        ])?;
+        let trans = TransformBuilder::default()
+            .add_actions(actions)
+            .random_source(crate::context::SeededRandom::new(7))
+            .build()?;
+        let mut outputs = Vec::new();
+        for _ in 0..50 {
+            let output = trans.apply(&Value::Null)?;
+            let roll = output["roll"].as_i64().unwrap();
+            assert!((1..=6).contains(&roll));
+            assert!(["a", "b", "c"].contains(&output["choice"].as_str().unwrap()));
+            assert!(output.get("empty").is_none());
+            outputs.push(output);
+        }
+        assert!(outputs.iter().any(|o| o != &outputs[0]));
+
+        let actions = Parser::parse_multi(&[
+            Parsable::new("random_int(1, 6)", "roll"),
+            Parsable::new(r#"random_choice(const(["a", "b", "c"]))"#, "choice"),
+        ])?;
+        let seeded = TransformBuilder::default()
+            .add_actions(actions)
+            .random_source(crate::context::SeededRandom::new(7))
+            .build()?;
+        assert_eq!(outputs[0], seeded.apply(&Value::Null)?);
+
+        let res = Parser::parse("random_int(6, 1)", "roll");
+        assert!(matches!(res, Err(crate::parser::Error::InvalidRange(_))));
+        Ok(())
+    }
+
+    #[cfg(feature = "inflector")]
+    #[test]
+    fn test_inflect() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new("pluralize(word)", "plural"),
+            Parsable::new("singularize(words)", "singular"),
+            Parsable::new(r#"join(" ", one, pluralize(const("item"), one))"#, "label1"),
+            Parsable::new(
+                r#"join(" ", three, pluralize(const("item"), three))"#,
+                "label3",
+            ),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+
+        let input = json!({"word": "child", "words": "boxes", "one": 1, "three": 3});
+        let expected = json!({"plural": "children", "singular": "box", "label1": "1 item", "label3": "3 items"});
+        let output = trans.apply(&input)?;
+        assert_eq!(expected, output);
+        Ok(())
+    }
+
+    #[cfg(feature = "i18n")]
+    #[test]
+    fn test_localize() -> Result<(), Box<dyn std::error::Error>> {
+        let actions = Parser::parse_multi(&[
+            Parsable::new(r#"format_currency("CAD", "en-CA", amount)"#, "cad"),
+            Parsable::new(r#"format_currency("EUR", amount)"#, "eur"),
+            Parsable::new("format_number(amount)", "number"),
+            Parsable::new(r#"format_number("en-US", amount)"#, "us"),
+            Parsable::new("format_date(created)", "date"),
+            Parsable::new("format_date(amount)", "invalid"),
+        ])?;
+        let trans = TransformBuilder::default()
+            .add_actions(actions)
+            .locale("de-DE")
+            .build()?;
+        let input = json!({"amount": 1234.5, "created": "2020-01-05T10:00:00Z"});
+        let expected = json!({
+            "cad": "$1,234.50",
+            "eur": "1.234,50\u{a0}€",
+            "number": "1.234,5",
+            "us": "1,234.5",
+            "date": "05.01.2020"
+        });
+        assert_eq!(expected, trans.apply(&input)?);
+
+        let mut context = HashMap::new();
+        context.insert(crate::i18n::LOCALE_VAR.to_owned(), json!("fr-CA"));
+        let output = trans.apply_with_context(&input, &context)?;
+        assert_eq!(output["eur"], "1\u{a0}234,50\u{a0}€");
+        assert_eq!(output["date"], "2020-01-05");
+
+        context.insert(crate::i18n::LOCALE_VAR.to_owned(), json!("xx"));
+        let res = trans.apply_with_context(&input, &context);
+        assert!(matches!(res, Err(Error::UnknownLocale(l)) if l == "xx"));
+
+        let res = TransformBuilder::default().locale("xx").build();
+        assert!(matches!(res, Err(Error::UnknownLocale(_))));
+        assert!(Parser::parse(r#"format_number("xx", amount)"#, "n").is_err());
+        assert!(Parser::parse(r#"format_currency("cad", amount)"#, "n").is_err());
+        Ok(())
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn test_tracing_spans() -> Result<(), Box<dyn std::error::Error>> {
+        use std::sync::{Arc, Mutex};
+        use tracing::field::{Field, Visit};
+        use tracing::span::{Attributes, Id, Record};
+        use tracing::{Event, Metadata, Subscriber};
+
+        struct Fields<'a>(&'a mut String);
+
+        impl Visit for Fields<'_> {
+            fn record_str(&mut self, field: &Field, value: &str) {
+                self.0.push_str(&format!(" {}={}", field.name(), value));
+            }
+
+            fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+                self.0.push_str(&format!(" {}={:?}", field.name(), value));
+            }
+        }
+
+        struct Capture(Arc<Mutex<Vec<String>>>);
+
+        impl Subscriber for Capture {
+            fn enabled(&self, _: &Metadata<'_>) -> bool {
+                true
+            }
+
+            fn new_span(&self, span: &Attributes<'_>) -> Id {
+                let mut spans = self.0.lock().unwrap();
+                let mut s = span.metadata().name().to_owned();
+                span.record(&mut Fields(&mut s));
+                spans.push(s);
+                Id::from_u64(spans.len() as u64)
+            }
+
+            fn record(&self, span: &Id, values: &Record<'_>) {
+                let mut spans = self.0.lock().unwrap();
+                values.record(&mut Fields(&mut spans[span.into_u64() as usize - 1]));
+            }
+
+            fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+            fn event(&self, _: &Event<'_>) {}
+
+            fn enter(&self, _: &Id) {}
+
+            fn exit(&self, _: &Id) {}
+        }
+
+        let actions = Parser::parse_multi(&[
+            Parsable::new(r#"join(" ", first_name, last_name)"#, "full_name"),
+            Parsable::new("age", "age").with_condition("exists(age)"),
+        ])?;
+        let trans = TransformBuilder::default().add_actions(actions).build()?;
+        let spans = Arc::new(Mutex::new(Vec::new()));
+        tracing::subscriber::with_default(Capture(spans.clone()), || {
+            trans.apply(&json!({"first_name": "Dean", "last_name": "Karn"}))
+        })?;
+
+        let expected = vec![
+            "apply actions=2",
+            "action name=Setter source=first_name, last_name destination=full_name",
+            "action name=When source=age destination=age",
+        ];
+        assert_eq!(expected, *spans.lock().unwrap());
         Ok(())
     }
 }